@@ -0,0 +1,230 @@
+//! Families of birds of any arity
+//!
+//! A bird of a family is named after what the family does and the number of inputs of the function it returns, from 1 up to 12.
+//! The names are spelled out so that they are not mistaken for the birds of the crate root, where [b1](crate::b1) is the Blackbird and [w1](crate::w1) the Converse Warbler.
+//! The birds take their inputs as values, the way combinatory logic does, rather than composing functions with them.
+//!
+//! * `compose_n(a, b)` returns a(b(x1, ..., xn)), composing after n inputs.
+//! * `share_n(a, b)` returns a(x1, ..., xn, b(x1, ..., xn)), sharing the n inputs with b.
+//! * `swap_n(a)` returns a(x1, ..., xn, xn-1), swapping the last two inputs.
+//! * `duplicate_n(a)` returns a(x1, ..., xn, xn), duplicating the last input.
+//!
+//! | n | `compose_n` | `share_n` | `swap_n` | `duplicate_n` |
+//! |---|-------------|-----------|----------|---------------|
+//! | 1 | [B](crate::b) | [S](crate::s) | | [W](crate::w) |
+//! | 2 | [B₁](crate::b1), the Blackbird | | [C](crate::c) | [W*](crate::w_star) |
+//! | 3 | [B₂](crate::b2), the Bunting | | [C*](crate::c_star) | [W**](crate::w_star_star) |
+//! | 4 | | | [C**](crate::c_star_star) | |
+//!
+//! `swap_n` starts at 2, since a function of one input has no two inputs to swap.
+//!
+//! Inputs used twice must be [Clone](Clone).
+//!
+//! ```rust
+//! use birbs::arity::{compose_3, duplicate_2, share_2, swap_3};
+//!
+//! let sum = |x: u32, y: u32, z: u32| x + 10*y + 100*z;
+//!
+//! assert_eq!(compose_3(|x: u32| x*2, sum)(1, 2, 3), 642);
+//! assert_eq!(share_2(sum, |x: u32, y: u32| x + y)(1, 2), 321);
+//! assert_eq!(swap_3(sum)(1, 2, 3), 231);
+//! assert_eq!(duplicate_2(sum)(1, 2), 221);
+//! ```
+
+/// Defines each Bⁿ, with the inputs before it and one more
+macro_rules! b {
+    ([$($x:ident: $X:ident),*]) => {};
+    ([$($x:ident: $X:ident),*] ($name:ident $y:ident $Y:ident) $($rest:tt)*) => {
+        /// Bⁿ, composing after the inputs
+        /// 
+        #[doc = concat!("returns a(b(", stringify!($($x, )* $y), "))")]
+        pub fn $name<A, B, $($X, )* $Y, O, R>(
+            a: A,
+            b: B
+        ) -> impl Fn($($X, )* $Y) -> R
+        where
+            A: Fn(O) -> R,
+            B: Fn($($X, )* $Y) -> O
+        {
+            move |$($x, )* $y| a(b($($x, )* $y))
+        }
+
+        b! { [$($x: $X, )* $y: $Y] $($rest)* }
+    };
+}
+
+/// Defines each Sⁿ, with the inputs before it and one more
+macro_rules! s {
+    ([$($x:ident: $X:ident),*]) => {};
+    ([$($x:ident: $X:ident),*] ($name:ident $y:ident $Y:ident) $($rest:tt)*) => {
+        /// Sⁿ, passing the inputs to a, and to b
+        /// 
+        #[doc = concat!("returns a(", stringify!($($x, )* $y), ", b(", stringify!($($x, )* $y), "))")]
+        pub fn $name<A, B, $($X, )* $Y, O, R>(
+            a: A,
+            b: B
+        ) -> impl Fn($($X, )* $Y) -> R
+        where
+            A: Fn($($X, )* $Y, O) -> R,
+            B: Fn($($X, )* $Y) -> O,
+            $($X: Clone, )*
+            $Y: Clone
+        {
+            move |$($x, )* $y| {
+                let o = b($($x.clone(), )* $y.clone());
+                a($($x, )* $y, o)
+            }
+        }
+
+        s! { [$($x: $X, )* $y: $Y] $($rest)* }
+    };
+}
+
+/// Defines each Cⁿ, with the inputs before the last, the last, and one more
+macro_rules! c {
+    ([$($x:ident: $X:ident),*] [$last:ident: $Last:ident]) => {};
+    ([$($x:ident: $X:ident),*] [$last:ident: $Last:ident] ($name:ident $y:ident $Y:ident) $($rest:tt)*) => {
+        /// Cⁿ, swapping the last two inputs
+        /// 
+        #[doc = concat!("returns a(", stringify!($($x, )* $y, $last), ")")]
+        pub fn $name<A, $($X, )* $Last, $Y, R>(
+            a: A
+        ) -> impl Fn($($X, )* $Last, $Y) -> R
+        where
+            A: Fn($($X, )* $Y, $Last) -> R
+        {
+            move |$($x, )* $last, $y| a($($x, )* $y, $last)
+        }
+
+        c! { [$($x: $X, )* $last: $Last] [$y: $Y] $($rest)* }
+    };
+}
+
+/// Defines each Wⁿ, with the inputs before it and one more
+macro_rules! w {
+    ([$($x:ident: $X:ident),*]) => {};
+    ([$($x:ident: $X:ident),*] ($name:ident $y:ident $Y:ident) $($rest:tt)*) => {
+        /// Wⁿ, duplicating the last input
+        /// 
+        #[doc = concat!("returns a(", stringify!($($x, )* $y, $y), ")")]
+        pub fn $name<A, $($X, )* $Y, R>(
+            a: A
+        ) -> impl Fn($($X, )* $Y) -> R
+        where
+            A: Fn($($X, )* $Y, $Y) -> R,
+            $Y: Clone
+        {
+            move |$($x, )* $y| a($($x, )* $y.clone(), $y)
+        }
+
+        w! { [$($x: $X, )* $y: $Y] $($rest)* }
+    };
+}
+
+b! {
+    []
+    (compose_1 x1 X1) (compose_2 x2 X2) (compose_3 x3 X3) (compose_4 x4 X4) (compose_5 x5 X5) (compose_6 x6 X6)
+    (compose_7 x7 X7) (compose_8 x8 X8) (compose_9 x9 X9) (compose_10 x10 X10) (compose_11 x11 X11) (compose_12 x12 X12)
+}
+
+s! {
+    []
+    (share_1 x1 X1) (share_2 x2 X2) (share_3 x3 X3) (share_4 x4 X4) (share_5 x5 X5) (share_6 x6 X6)
+    (share_7 x7 X7) (share_8 x8 X8) (share_9 x9 X9) (share_10 x10 X10) (share_11 x11 X11) (share_12 x12 X12)
+}
+
+// swap_n starts at 2, since a function of one input has no two inputs to swap
+c! {
+    [] [x1: X1]
+    (swap_2 x2 X2) (swap_3 x3 X3) (swap_4 x4 X4) (swap_5 x5 X5) (swap_6 x6 X6)
+    (swap_7 x7 X7) (swap_8 x8 X8) (swap_9 x9 X9) (swap_10 x10 X10) (swap_11 x11 X11) (swap_12 x12 X12)
+}
+
+w! {
+    []
+    (duplicate_1 x1 X1) (duplicate_2 x2 X2) (duplicate_3 x3 X3) (duplicate_4 x4 X4) (duplicate_5 x5 X5) (duplicate_6 x6 X6)
+    (duplicate_7 x7 X7) (duplicate_8 x8 X8) (duplicate_9 x9 X9) (duplicate_10 x10 X10) (duplicate_11 x11 X11) (duplicate_12 x12 X12)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The values as the digits of a number in base 100, so that any two orders of the values differ
+    fn digits(values: &[u128]) -> u128
+    {
+        values.iter()
+            .fold(0, |number, value| number*100 + value)
+    }
+
+    /// Defines a test of each bird, with the inputs before it and one more
+    /// 
+    /// The inputs are 1, 2, 3 and so on, and the functions write their arguments as [digits], so a test fails if a bird passes them in the wrong order.
+    macro_rules! tests {
+        ([$($x:ident),*]) => {};
+        ([$($x:ident),*] ($test:ident $b:ident $s:ident $w:ident $($c:ident)?; $y:ident) $($rest:tt)*) => {
+            #[test]
+            fn $test()
+            {
+                let inputs = std::array::from_fn(|i| i as u128 + 1);
+                let [$($x, )* $y] = inputs;
+                let arguments = ($($x, )* $y,);
+
+                let f = |$($x: u128, )* $y: u128| digits(&[$($x, )* $y]);
+                let g = |$($x: u128, )* $y: u128, z: u128| digits(&[$($x, )* $y, z]);
+                let sum = |$($x: u128, )* $y: u128| ($($x + )* $y) % 100;
+
+                assert_eq!($b(|o: u128| o + 1, f).call(arguments), digits(&inputs) + 1);
+                assert_eq!($s(g, sum).call(arguments), digits(&[$($x, )* $y, sum($($x, )* $y)]));
+                assert_eq!($w(g).call(arguments), digits(&[$($x, )* $y, $y]));
+                $(
+                    let mut swapped = inputs;
+                    swapped.swap(inputs.len() - 2, inputs.len() - 1);
+
+                    assert_eq!($c(f).call(arguments), digits(&swapped));
+                )?
+            }
+
+            tests! { [$($x, )* $y] $($rest)* }
+        };
+    }
+
+    tests! {
+        []
+        (test_1 compose_1 share_1 duplicate_1; x1)
+        (test_2 compose_2 share_2 duplicate_2 swap_2; x2)
+        (test_3 compose_3 share_3 duplicate_3 swap_3; x3)
+        (test_4 compose_4 share_4 duplicate_4 swap_4; x4)
+        (test_5 compose_5 share_5 duplicate_5 swap_5; x5)
+        (test_6 compose_6 share_6 duplicate_6 swap_6; x6)
+        (test_7 compose_7 share_7 duplicate_7 swap_7; x7)
+        (test_8 compose_8 share_8 duplicate_8 swap_8; x8)
+        (test_9 compose_9 share_9 duplicate_9 swap_9; x9)
+        (test_10 compose_10 share_10 duplicate_10 swap_10; x10)
+        (test_11 compose_11 share_11 duplicate_11 swap_11; x11)
+        (test_12 compose_12 share_12 duplicate_12 swap_12; x12)
+    }
+
+    #[test]
+    fn test_birds()
+    {
+        let a = |x: u8| x.wrapping_mul(3);
+        let f = |x: u8, y: u8| x.wrapping_mul(3) ^ y;
+        let g = |x: u8, y: u8, z: u8| x.wrapping_mul(3) ^ y.rotate_left(1) ^ z;
+        let i = |x: u8| x;
+
+        for x in 0..=255
+        {
+            for y in (0..=255).step_by(15)
+            {
+                assert_eq!(compose_1(a, i)(x), crate::b(a, i, i)(x));
+                assert_eq!(compose_2(a, f)(x, y), crate::b1(a, f, i, i)(x, y));
+                assert_eq!(compose_3(a, g)(x, y, x), crate::b2(a, g, i, i, i)(x, y, x));
+                assert_eq!(share_1(f, a)(x), crate::s(f, a, i)(x, x));
+                assert_eq!(duplicate_1(f)(x), crate::w(f, i)(x, x));
+                assert_eq!(swap_2(f)(x, y), f(y, x));
+                assert_eq!(duplicate_2(g)(x, y), g(x, y, y));
+            }
+        }
+    }
+}
@@ -0,0 +1,393 @@
+//! Arrow combinators
+//!
+//! The birds compose functions returning a single value. These combinators build functions which return or take pairs,
+//! or either of two values, out of such functions, and are functions themselves, so they can be passed to any bird.
+//!
+//! * [fanout](fanout) calls two functions with the same arguments, returning both outputs.
+//! * [split](split) calls two functions, each with one value of a pair.
+//! * [first](first) and [second](second) call a function with one value of a pair, keeping the other.
+//! * [left](left) and [right](right) call a function with the value on one side of an [Either](Either), passing the other side on.
+//!
+//! ```rust
+//! use birbs::arrow::{fanout, split};
+//!
+//! let mean = |x: Vec<f32>| x.iter().sum::<f32>()/x.len() as f32;
+//! let max = |x: Vec<f32>| x.into_iter().fold(f32::MIN, f32::max);
+//!
+//! // x ↦ (mean(x) as u8, max(x) as u8)
+//! let f = birbs::b(split(|x: f32| x as u8, |y: f32| y as u8), fanout(mean, max), |x: &[f32]| x.to_vec());
+//!
+//! assert_eq!(f(&[1.0, 2.0, 6.0]), (3, 6));
+//! ```
+
+use std::marker::Tuple;
+
+/// One of two values
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Either<L, R>
+{
+    /// The value on the left
+    Left(L),
+    /// The value on the right
+    Right(R)
+}
+
+/// x ↦ (f(x), g(x))
+///
+/// The arguments are cloned for `f`, and moved into `g`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Fanout<F, G>(pub F, pub G);
+
+/// (x, y) ↦ (f(x), g(y))
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Split<F, G>(pub F, pub G);
+
+/// (x, y) ↦ (f(x), y)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct First<F>(pub F);
+
+/// (x, y) ↦ (x, f(y))
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Second<F>(pub F);
+
+/// Left(x) ↦ Left(f(x)), Right(y) ↦ Right(y)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Left<F>(pub F);
+
+/// Left(x) ↦ Left(x), Right(y) ↦ Right(f(y))
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Right<F>(pub F);
+
+/// x ↦ (f(x), g(x))
+pub const fn fanout<F, G>(f: F, g: G) -> Fanout<F, G>
+{
+    Fanout(f, g)
+}
+
+/// (x, y) ↦ (f(x), g(y))
+pub const fn split<F, G>(f: F, g: G) -> Split<F, G>
+{
+    Split(f, g)
+}
+
+/// (x, y) ↦ (f(x), y)
+pub const fn first<F>(f: F) -> First<F>
+{
+    First(f)
+}
+
+/// (x, y) ↦ (x, f(y))
+pub const fn second<F>(f: F) -> Second<F>
+{
+    Second(f)
+}
+
+/// Left(x) ↦ Left(f(x)), Right(y) ↦ Right(y)
+pub const fn left<F>(f: F) -> Left<F>
+{
+    Left(f)
+}
+
+/// Left(x) ↦ Left(x), Right(y) ↦ Right(f(y))
+pub const fn right<F>(f: F) -> Right<F>
+{
+    Right(f)
+}
+
+impl<F, G, X> FnOnce<X> for Fanout<F, G>
+where
+    F: FnOnce<X>,
+    G: FnOnce<X>,
+    X: Tuple + Clone
+{
+    type Output = (F::Output, G::Output);
+
+    extern "rust-call" fn call_once(self, args: X) -> Self::Output
+    {
+        (self.0.call_once(args.clone()), self.1.call_once(args))
+    }
+}
+
+impl<F, G, X> FnMut<X> for Fanout<F, G>
+where
+    F: FnMut<X>,
+    G: FnMut<X>,
+    X: Tuple + Clone
+{
+    extern "rust-call" fn call_mut(&mut self, args: X) -> Self::Output
+    {
+        (self.0.call_mut(args.clone()), self.1.call_mut(args))
+    }
+}
+
+impl<F, G, X> Fn<X> for Fanout<F, G>
+where
+    F: Fn<X>,
+    G: Fn<X>,
+    X: Tuple + Clone
+{
+    extern "rust-call" fn call(&self, args: X) -> Self::Output
+    {
+        (self.0.call(args.clone()), self.1.call(args))
+    }
+}
+
+impl<F, G, X, Y> FnOnce<((X, Y),)> for Split<F, G>
+where
+    F: FnOnce<(X,)>,
+    G: FnOnce<(Y,)>
+{
+    type Output = (F::Output, G::Output);
+
+    extern "rust-call" fn call_once(self, ((x, y),): ((X, Y),)) -> Self::Output
+    {
+        (self.0.call_once((x,)), self.1.call_once((y,)))
+    }
+}
+
+impl<F, G, X, Y> FnMut<((X, Y),)> for Split<F, G>
+where
+    F: FnMut<(X,)>,
+    G: FnMut<(Y,)>
+{
+    extern "rust-call" fn call_mut(&mut self, ((x, y),): ((X, Y),)) -> Self::Output
+    {
+        (self.0.call_mut((x,)), self.1.call_mut((y,)))
+    }
+}
+
+impl<F, G, X, Y> Fn<((X, Y),)> for Split<F, G>
+where
+    F: Fn<(X,)>,
+    G: Fn<(Y,)>
+{
+    extern "rust-call" fn call(&self, ((x, y),): ((X, Y),)) -> Self::Output
+    {
+        (self.0.call((x,)), self.1.call((y,)))
+    }
+}
+
+impl<F, X, Y> FnOnce<((X, Y),)> for First<F>
+where
+    F: FnOnce<(X,)>
+{
+    type Output = (F::Output, Y);
+
+    extern "rust-call" fn call_once(self, ((x, y),): ((X, Y),)) -> Self::Output
+    {
+        (self.0.call_once((x,)), y)
+    }
+}
+
+impl<F, X, Y> FnMut<((X, Y),)> for First<F>
+where
+    F: FnMut<(X,)>
+{
+    extern "rust-call" fn call_mut(&mut self, ((x, y),): ((X, Y),)) -> Self::Output
+    {
+        (self.0.call_mut((x,)), y)
+    }
+}
+
+impl<F, X, Y> Fn<((X, Y),)> for First<F>
+where
+    F: Fn<(X,)>
+{
+    extern "rust-call" fn call(&self, ((x, y),): ((X, Y),)) -> Self::Output
+    {
+        (self.0.call((x,)), y)
+    }
+}
+
+impl<F, X, Y> FnOnce<((X, Y),)> for Second<F>
+where
+    F: FnOnce<(Y,)>
+{
+    type Output = (X, F::Output);
+
+    extern "rust-call" fn call_once(self, ((x, y),): ((X, Y),)) -> Self::Output
+    {
+        (x, self.0.call_once((y,)))
+    }
+}
+
+impl<F, X, Y> FnMut<((X, Y),)> for Second<F>
+where
+    F: FnMut<(Y,)>
+{
+    extern "rust-call" fn call_mut(&mut self, ((x, y),): ((X, Y),)) -> Self::Output
+    {
+        (x, self.0.call_mut((y,)))
+    }
+}
+
+impl<F, X, Y> Fn<((X, Y),)> for Second<F>
+where
+    F: Fn<(Y,)>
+{
+    extern "rust-call" fn call(&self, ((x, y),): ((X, Y),)) -> Self::Output
+    {
+        (x, self.0.call((y,)))
+    }
+}
+
+impl<F, X, Y> FnOnce<(Either<X, Y>,)> for Left<F>
+where
+    F: FnOnce<(X,)>
+{
+    type Output = Either<F::Output, Y>;
+
+    extern "rust-call" fn call_once(self, (either,): (Either<X, Y>,)) -> Self::Output
+    {
+        match either
+        {
+            Either::Left(x) => Either::Left(self.0.call_once((x,))),
+            Either::Right(y) => Either::Right(y)
+        }
+    }
+}
+
+impl<F, X, Y> FnMut<(Either<X, Y>,)> for Left<F>
+where
+    F: FnMut<(X,)>
+{
+    extern "rust-call" fn call_mut(&mut self, (either,): (Either<X, Y>,)) -> Self::Output
+    {
+        match either
+        {
+            Either::Left(x) => Either::Left(self.0.call_mut((x,))),
+            Either::Right(y) => Either::Right(y)
+        }
+    }
+}
+
+impl<F, X, Y> Fn<(Either<X, Y>,)> for Left<F>
+where
+    F: Fn<(X,)>
+{
+    extern "rust-call" fn call(&self, (either,): (Either<X, Y>,)) -> Self::Output
+    {
+        match either
+        {
+            Either::Left(x) => Either::Left(self.0.call((x,))),
+            Either::Right(y) => Either::Right(y)
+        }
+    }
+}
+
+impl<F, X, Y> FnOnce<(Either<X, Y>,)> for Right<F>
+where
+    F: FnOnce<(Y,)>
+{
+    type Output = Either<X, F::Output>;
+
+    extern "rust-call" fn call_once(self, (either,): (Either<X, Y>,)) -> Self::Output
+    {
+        match either
+        {
+            Either::Left(x) => Either::Left(x),
+            Either::Right(y) => Either::Right(self.0.call_once((y,)))
+        }
+    }
+}
+
+impl<F, X, Y> FnMut<(Either<X, Y>,)> for Right<F>
+where
+    F: FnMut<(Y,)>
+{
+    extern "rust-call" fn call_mut(&mut self, (either,): (Either<X, Y>,)) -> Self::Output
+    {
+        match either
+        {
+            Either::Left(x) => Either::Left(x),
+            Either::Right(y) => Either::Right(self.0.call_mut((y,)))
+        }
+    }
+}
+
+impl<F, X, Y> Fn<(Either<X, Y>,)> for Right<F>
+where
+    F: Fn<(Y,)>
+{
+    extern "rust-call" fn call(&self, (either,): (Either<X, Y>,)) -> Self::Output
+    {
+        match either
+        {
+            Either::Left(x) => Either::Left(x),
+            Either::Right(y) => Either::Right(self.0.call((y,)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pairs()
+    {
+        let f = |x: u8| x as u16*3;
+        let g = |x: u8| x.count_ones();
+
+        for i in 0..=255
+        {
+            assert_eq!(fanout(f, g)(i), (f(i), g(i)));
+            assert_eq!(split(f, g)((i, !i)), (f(i), g(!i)));
+            assert_eq!(first(f)((i, "y")), (f(i), "y"));
+            assert_eq!(second(g)(("x", i)), ("x", g(i)));
+        }
+
+        let add = |x: u8, y: u8| x.wrapping_add(y);
+        let sub = |x: u8, y: u8| x.wrapping_sub(y);
+
+        assert_eq!(fanout(add, sub)(3, 2), (5, 1));
+    }
+
+    #[test]
+    fn test_either()
+    {
+        let f = |x: u8| x as u16*3;
+        let g = |x: &str| x.len();
+
+        assert_eq!(left(f)(Either::<u8, &str>::Left(2)), Either::Left(6));
+        assert_eq!(left(f)(Either::<u8, &str>::Right("y")), Either::Right("y"));
+        assert_eq!(right(g)(Either::<u8, &str>::Left(2)), Either::Left(2));
+        assert_eq!(right(g)(Either::<u8, &str>::Right("y")), Either::Right(1));
+    }
+
+    #[test]
+    fn test_laws()
+    {
+        let f = |x: u8| x.wrapping_mul(3);
+        let g = |x: u8| x.rotate_left(2);
+        let h = |x: u8| x ^ 0x55;
+
+        for i in 0..=255
+        {
+            // split(f, g) = first(f) ∘ second(g)
+            assert_eq!(split(f, g)((i, h(i))), first(f)(second(g)((i, h(i)))));
+            // split(f, g) ∘ fanout(h, h) = fanout(f ∘ h, g ∘ h)
+            assert_eq!(split(f, g)(fanout(h, h)(i)), fanout(|x| f(h(x)), |x| g(h(x)))(i));
+        }
+    }
+
+    #[test]
+    fn test_birds()
+    {
+        let f = |x: u8| x as u16*3;
+        let g = |x: u8| x.count_ones() as u16;
+        let add = |(x, y): (u16, u16)| x + y;
+        let h = |x: u8| x/2;
+
+        // add ∘ (fanout(f, g) ∘ h)
+        let bluebird = crate::b(add, fanout(f, g), h);
+        // a(h(x), fanout(f, id)(h(y)))
+        let starling = crate::s(|x: u8, (y, z): (u16, u8)| x as u16 + y + z as u16, fanout(f, |x: u8| x), h);
+
+        for i in 0..=255
+        {
+            assert_eq!(bluebird(i), f(h(i)) + g(h(i)));
+            assert_eq!(starling(i, i), h(i) as u16 + f(h(i)) + h(i) as u16);
+        }
+    }
+}
@@ -0,0 +1,1289 @@
+//! Asynchronous birds
+//!
+//! Each function returns a future, as an `async fn` or an async closure does, and a bird composes them into an async closure.
+//! The birds read their λ-body by the [first of the possible interpretations](crate#interpretations).
+//!
+//! The arguments of a call are independent of one another, so they are awaited concurrently, with [join](std::future::join).
+//! Nothing here depends on a runtime; any executor can drive the futures.
+//!
+//! ```rust
+//! # fn block_on<F: std::future::Future>(future: F) -> F::Output
+//! # {
+//! #     let mut future = std::pin::pin!(future);
+//! #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+//! #     loop
+//! #     {
+//! #         if let std::task::Poll::Ready(output) = future.as_mut().poll(&mut cx)
+//! #         {
+//! #             return output
+//! #         }
+//! #     }
+//! # }
+//! use birbs::asynchronous::async_s;
+//!
+//! async fn double(x: u8) -> u8
+//! {
+//!     x*2
+//! }
+//!
+//! // c(1) and b(c(2)) are awaited concurrently, then a
+//! let f = async_s(async |x: u8, y: u8| x + y, double, async |x: u8| x + 1);
+//!
+//! assert_eq!(block_on(f(1, 2)), 8);
+//! ```
+//!
+//! Y, Θ and Ω are [missing](crate#interpretations), since they never return.
+
+use std::future::join;
+
+/// B = Bluebird
+/// 
+/// λabc.a(bc)
+/// 
+/// returns a(b(c(x1)))
+pub fn async_b<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl AsyncFn(X1) -> R
+where
+    A: AsyncFn(O2) -> R,
+    B: AsyncFn(O1) -> O2,
+    C: AsyncFn(X1) -> O1
+{
+    async move |x1| {
+        a(b(c(x1).await).await).await
+    }
+}
+
+/// B¹ = Blackbird
+/// 
+/// λabcd.a(bcd)
+/// 
+/// returns a(b(c(x1), d(x2)))
+pub fn async_b1<A, B, C, D, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl AsyncFn(X1, X2) -> R
+where
+    A: AsyncFn(O3) -> R,
+    B: AsyncFn(O1, O2) -> O3,
+    C: AsyncFn(X1) -> O1,
+    D: AsyncFn(X2) -> O2
+{
+    async move |x1, x2| {
+        let y = {
+            let (y0, y1) = join!(
+                c(x1),
+                d(x2)
+            ).await;
+            b(y0, y1).await
+        };
+        a(y).await
+    }
+}
+
+/// B² = Bunting
+/// 
+/// λabcde.a(bcde)
+/// 
+/// returns a(b(c(x1), d(x2), e(x3)))
+pub fn async_b2<A, B, C, D, E, X1, X2, X3, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl AsyncFn(X1, X2, X3) -> R
+where
+    A: AsyncFn(O4) -> R,
+    B: AsyncFn(O1, O2, O3) -> O4,
+    C: AsyncFn(X1) -> O1,
+    D: AsyncFn(X2) -> O2,
+    E: AsyncFn(X3) -> O3
+{
+    async move |x1, x2, x3| {
+        let y = {
+            let (y0, y1, y2) = join!(
+                c(x1),
+                d(x2),
+                e(x3)
+            ).await;
+            b(y0, y1, y2).await
+        };
+        a(y).await
+    }
+}
+
+/// B³ = Becard
+/// 
+/// λabcd.a(b(cd))
+/// 
+/// returns a(b(c(d(x1))))
+pub fn async_b3<A, B, C, D, X1, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl AsyncFn(X1) -> R
+where
+    A: AsyncFn(O3) -> R,
+    B: AsyncFn(O2) -> O3,
+    C: AsyncFn(O1) -> O2,
+    D: AsyncFn(X1) -> O1
+{
+    async move |x1| {
+        a(b(c(d(x1).await).await).await).await
+    }
+}
+
+/// C = Cardinal
+/// 
+/// λabc.acb
+/// 
+/// returns a(c(x1), b(x2))
+pub fn async_c<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl AsyncFn(X1, X2) -> R
+where
+    A: AsyncFn(O1, O2) -> R,
+    B: AsyncFn(X2) -> O2,
+    C: AsyncFn(X1) -> O1
+{
+    async move |x1, x2| {
+        let (y0, y1) = join!(
+            c(x1),
+            b(x2)
+        ).await;
+        a(y0, y1).await
+    }
+}
+
+/// D = Dove
+/// 
+/// λabcd.ab(cd)
+/// 
+/// returns a(b(x1), c(d(x2)))
+pub fn async_d<A, B, C, D, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl AsyncFn(X1, X2) -> R
+where
+    A: AsyncFn(O1, O3) -> R,
+    B: AsyncFn(X1) -> O1,
+    C: AsyncFn(O2) -> O3,
+    D: AsyncFn(X2) -> O2
+{
+    async move |x1, x2| {
+        let (y0, y1) = join!(
+            b(x1),
+            async {
+                c(d(x2).await).await
+            }
+        ).await;
+        a(y0, y1).await
+    }
+}
+
+/// D¹ = Dickcissel
+/// 
+/// λabcde.abc(de)
+/// 
+/// returns a(b(x1), c(x2), d(e(x3)))
+pub fn async_d1<A, B, C, D, E, X1, X2, X3, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl AsyncFn(X1, X2, X3) -> R
+where
+    A: AsyncFn(O1, O2, O4) -> R,
+    B: AsyncFn(X1) -> O1,
+    C: AsyncFn(X2) -> O2,
+    D: AsyncFn(O3) -> O4,
+    E: AsyncFn(X3) -> O3
+{
+    async move |x1, x2, x3| {
+        let (y0, y1, y2) = join!(
+            b(x1),
+            c(x2),
+            async {
+                d(e(x3).await).await
+            }
+        ).await;
+        a(y0, y1, y2).await
+    }
+}
+
+/// D² = Dovekies
+/// 
+/// λabcde.a(bc)(de)
+/// 
+/// returns a(b(c(x1)), d(e(x2)))
+pub fn async_d2<A, B, C, D, E, X1, X2, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl AsyncFn(X1, X2) -> R
+where
+    A: AsyncFn(O2, O4) -> R,
+    B: AsyncFn(O1) -> O2,
+    C: AsyncFn(X1) -> O1,
+    D: AsyncFn(O3) -> O4,
+    E: AsyncFn(X2) -> O3
+{
+    async move |x1, x2| {
+        let (y0, y1) = join!(
+            async {
+                b(c(x1).await).await
+            },
+            async {
+                d(e(x2).await).await
+            }
+        ).await;
+        a(y0, y1).await
+    }
+}
+
+/// E = Eagle
+/// 
+/// λabcde.ab(cde)
+/// 
+/// returns a(b(x1), c(d(x2), e(x3)))
+pub fn async_e<A, B, C, D, E, X1, X2, X3, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl AsyncFn(X1, X2, X3) -> R
+where
+    A: AsyncFn(O1, O4) -> R,
+    B: AsyncFn(X1) -> O1,
+    C: AsyncFn(O2, O3) -> O4,
+    D: AsyncFn(X2) -> O2,
+    E: AsyncFn(X3) -> O3
+{
+    async move |x1, x2, x3| {
+        let (y0, y1) = join!(
+            b(x1),
+            async {
+                let (y0, y1) = join!(
+                    d(x2),
+                    e(x3)
+                ).await;
+                c(y0, y1).await
+            }
+        ).await;
+        a(y0, y1).await
+    }
+}
+
+/// Ê = Bald Eagle
+/// 
+/// λabcdefg.a(bcd)(efg)
+/// 
+/// returns a(b(c(x1), d(x2)), e(f(x3), g(x4)))
+pub fn async_ê<A, B, C, D, E, F, G, X1, X2, X3, X4, O1, O2, O3, O4, O5, O6, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E,
+    f: F,
+    g: G
+) -> impl AsyncFn(X1, X2, X3, X4) -> R
+where
+    A: AsyncFn(O3, O6) -> R,
+    B: AsyncFn(O1, O2) -> O3,
+    C: AsyncFn(X1) -> O1,
+    D: AsyncFn(X2) -> O2,
+    E: AsyncFn(O4, O5) -> O6,
+    F: AsyncFn(X3) -> O4,
+    G: AsyncFn(X4) -> O5
+{
+    async move |x1, x2, x3, x4| {
+        let (y0, y1) = join!(
+            async {
+                let (y0, y1) = join!(
+                    c(x1),
+                    d(x2)
+                ).await;
+                b(y0, y1).await
+            },
+            async {
+                let (y0, y1) = join!(
+                    f(x3),
+                    g(x4)
+                ).await;
+                e(y0, y1).await
+            }
+        ).await;
+        a(y0, y1).await
+    }
+}
+
+/// F = Finch
+/// 
+/// λabc.cba
+/// 
+/// returns c(b(x1), a(x2))
+pub fn async_f<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl AsyncFn(X1, X2) -> R
+where
+    A: AsyncFn(X2) -> O2,
+    B: AsyncFn(X1) -> O1,
+    C: AsyncFn(O1, O2) -> R
+{
+    async move |x1, x2| {
+        let (y0, y1) = join!(
+            b(x1),
+            a(x2)
+        ).await;
+        c(y0, y1).await
+    }
+}
+
+/// G = Goldfinch
+/// 
+/// λabcd.ad(bc)
+/// 
+/// returns a(d(x1), b(c(x2)))
+pub fn async_g<A, B, C, D, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl AsyncFn(X1, X2) -> R
+where
+    A: AsyncFn(O1, O3) -> R,
+    B: AsyncFn(O2) -> O3,
+    C: AsyncFn(X2) -> O2,
+    D: AsyncFn(X1) -> O1
+{
+    async move |x1, x2| {
+        let (y0, y1) = join!(
+            d(x1),
+            async {
+                b(c(x2).await).await
+            }
+        ).await;
+        a(y0, y1).await
+    }
+}
+
+/// H = Hummingbird
+/// 
+/// λabc.abcb
+/// 
+/// returns a(b(x1), c(x2), b(x3))
+pub fn async_h<A, B, C, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl AsyncFn(X1, X2, X3) -> R
+where
+    A: AsyncFn(O1, O2, O3) -> R,
+    B: AsyncFn(X1) -> O1 + AsyncFn(X3) -> O3,
+    C: AsyncFn(X2) -> O2
+{
+    async move |x1, x2, x3| {
+        let (y0, y1, y2) = join!(
+            b(x1),
+            c(x2),
+            b(x3)
+        ).await;
+        a(y0, y1, y2).await
+    }
+}
+
+/// I = Identity Bird aka Idiot
+/// 
+/// λa.a
+/// 
+/// returns a(x1)
+pub fn async_i<A, X1, R>(
+    a: A
+) -> impl AsyncFn(X1) -> R
+where
+    A: AsyncFn(X1) -> R
+{
+    async move |x1| {
+        a(x1).await
+    }
+}
+
+/// J = Jay
+/// 
+/// λabcd.ab(adc)
+/// 
+/// returns a(b(x1), a(d(x2), c(x3)))
+pub fn async_j<A, B, C, D, X1, X2, X3, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl AsyncFn(X1, X2, X3) -> R
+where
+    A: AsyncFn(O2, O3) -> O4 + AsyncFn(O1, O4) -> R,
+    B: AsyncFn(X1) -> O1,
+    C: AsyncFn(X3) -> O3,
+    D: AsyncFn(X2) -> O2
+{
+    async move |x1, x2, x3| {
+        let (y0, y1) = join!(
+            b(x1),
+            async {
+                let (y0, y1) = join!(
+                    d(x2),
+                    c(x3)
+                ).await;
+                a(y0, y1).await
+            }
+        ).await;
+        a(y0, y1).await
+    }
+}
+
+/// K = Kestrel (True)
+/// 
+/// λab.a
+/// 
+/// returns a(x1)
+#[allow(unused)]
+pub fn async_k<A, B, X1, R>(
+    a: A,
+    b: B
+) -> impl AsyncFn(X1) -> R
+where
+    A: AsyncFn(X1) -> R
+{
+    async move |x1| {
+        a(x1).await
+    }
+}
+
+/// L = Lark
+/// 
+/// λab.a(bb)
+/// 
+/// returns a(b(b(x1)))
+pub fn async_l<A, B, X1, O1, O2, R>(
+    a: A,
+    b: B
+) -> impl AsyncFn(X1) -> R
+where
+    A: AsyncFn(O2) -> R,
+    B: AsyncFn(X1) -> O1 + AsyncFn(O1) -> O2
+{
+    async move |x1| {
+        a(b(b(x1).await).await).await
+    }
+}
+
+/// M = Mockingbird
+/// 
+/// λa.aa
+/// 
+/// returns a(a(x1))
+pub fn async_m<A, X1, O1, R>(
+    a: A
+) -> impl AsyncFn(X1) -> R
+where
+    A: AsyncFn(X1) -> O1 + AsyncFn(O1) -> R
+{
+    async move |x1| {
+        a(a(x1).await).await
+    }
+}
+
+/// M² = Double Mockingbird
+/// 
+/// λab.ab(ab)
+/// 
+/// returns a(b(x1), a(b(x2)))
+pub fn async_m2<A, B, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B
+) -> impl AsyncFn(X1, X2) -> R
+where
+    A: AsyncFn(O2) -> O3 + AsyncFn(O1, O3) -> R,
+    B: AsyncFn(X1) -> O1 + AsyncFn(X2) -> O2
+{
+    async move |x1, x2| {
+        let (y0, y1) = join!(
+            b(x1),
+            async {
+                a(b(x2).await).await
+            }
+        ).await;
+        a(y0, y1).await
+    }
+}
+
+/// O = Owl
+/// 
+/// λab.b(ab)
+/// 
+/// returns b(a(b(x1)))
+pub fn async_o<A, B, X1, O1, O2, R>(
+    a: A,
+    b: B
+) -> impl AsyncFn(X1) -> R
+where
+    A: AsyncFn(O1) -> O2,
+    B: AsyncFn(X1) -> O1 + AsyncFn(O2) -> R
+{
+    async move |x1| {
+        b(a(b(x1).await).await).await
+    }
+}
+
+/// Q = Queer Bird
+/// 
+/// λabc.b(ac)
+/// 
+/// returns b(a(c(x1)))
+pub fn async_q<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl AsyncFn(X1) -> R
+where
+    A: AsyncFn(O1) -> O2,
+    B: AsyncFn(O2) -> R,
+    C: AsyncFn(X1) -> O1
+{
+    async move |x1| {
+        b(a(c(x1).await).await).await
+    }
+}
+
+/// Q¹ = Quixotic Bird
+/// 
+/// λabc.a(cb)
+/// 
+/// returns a(c(b(x1)))
+pub fn async_q1<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl AsyncFn(X1) -> R
+where
+    A: AsyncFn(O2) -> R,
+    B: AsyncFn(X1) -> O1,
+    C: AsyncFn(O1) -> O2
+{
+    async move |x1| {
+        a(c(b(x1).await).await).await
+    }
+}
+
+/// Q² = Quizzical Bird
+/// 
+/// λabc.b(ca)
+/// 
+/// returns b(c(a(x1)))
+pub fn async_q2<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl AsyncFn(X1) -> R
+where
+    A: AsyncFn(X1) -> O1,
+    B: AsyncFn(O2) -> R,
+    C: AsyncFn(O1) -> O2
+{
+    async move |x1| {
+        b(c(a(x1).await).await).await
+    }
+}
+
+/// Q³ = Quirky Bird
+/// 
+/// λabc.c(ab)
+/// 
+/// returns c(a(b(x1)))
+pub fn async_q3<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl AsyncFn(X1) -> R
+where
+    A: AsyncFn(O1) -> O2,
+    B: AsyncFn(X1) -> O1,
+    C: AsyncFn(O2) -> R
+{
+    async move |x1| {
+        c(a(b(x1).await).await).await
+    }
+}
+
+/// Q⁴ = Quacky Bird
+/// 
+/// λabc.c(ba)
+/// 
+/// returns c(b(a(x1)))
+pub fn async_q4<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl AsyncFn(X1) -> R
+where
+    A: AsyncFn(X1) -> O1,
+    B: AsyncFn(O1) -> O2,
+    C: AsyncFn(O2) -> R
+{
+    async move |x1| {
+        c(b(a(x1).await).await).await
+    }
+}
+
+/// R = Robin
+/// 
+/// λabc.bca
+/// 
+/// returns b(c(x1), a(x2))
+pub fn async_r<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl AsyncFn(X1, X2) -> R
+where
+    A: AsyncFn(X2) -> O2,
+    B: AsyncFn(O1, O2) -> R,
+    C: AsyncFn(X1) -> O1
+{
+    async move |x1, x2| {
+        let (y0, y1) = join!(
+            c(x1),
+            a(x2)
+        ).await;
+        b(y0, y1).await
+    }
+}
+
+/// S = Starling
+/// 
+/// λabc.ac(bc)
+/// 
+/// returns a(c(x1), b(c(x2)))
+pub fn async_s<A, B, C, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl AsyncFn(X1, X2) -> R
+where
+    A: AsyncFn(O1, O3) -> R,
+    B: AsyncFn(O2) -> O3,
+    C: AsyncFn(X1) -> O1 + AsyncFn(X2) -> O2
+{
+    async move |x1, x2| {
+        let (y0, y1) = join!(
+            c(x1),
+            async {
+                b(c(x2).await).await
+            }
+        ).await;
+        a(y0, y1).await
+    }
+}
+
+/// T = Thrush
+/// 
+/// λab.ba
+/// 
+/// returns b(a(x1))
+pub fn async_t<A, B, X1, O1, R>(
+    a: A,
+    b: B
+) -> impl AsyncFn(X1) -> R
+where
+    A: AsyncFn(X1) -> O1,
+    B: AsyncFn(O1) -> R
+{
+    async move |x1| {
+        b(a(x1).await).await
+    }
+}
+
+/// U = Turing
+/// 
+/// λab.b(aab)
+/// 
+/// returns b(a(a(x1), b(x2)))
+pub fn async_u<A, B, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B
+) -> impl AsyncFn(X1, X2) -> R
+where
+    A: AsyncFn(X1) -> O1 + AsyncFn(O1, O2) -> O3,
+    B: AsyncFn(X2) -> O2 + AsyncFn(O3) -> R
+{
+    async move |x1, x2| {
+        let y = {
+            let (y0, y1) = join!(
+                a(x1),
+                b(x2)
+            ).await;
+            a(y0, y1).await
+        };
+        b(y).await
+    }
+}
+
+/// V = Vireo aka Pairing
+/// 
+/// λabc.cab
+/// 
+/// returns c(a(x1), b(x2))
+pub fn async_v<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl AsyncFn(X1, X2) -> R
+where
+    A: AsyncFn(X1) -> O1,
+    B: AsyncFn(X2) -> O2,
+    C: AsyncFn(O1, O2) -> R
+{
+    async move |x1, x2| {
+        let (y0, y1) = join!(
+            a(x1),
+            b(x2)
+        ).await;
+        c(y0, y1).await
+    }
+}
+
+/// W = Warbler
+/// 
+/// λab.abb
+/// 
+/// returns a(b(x1), b(x2))
+pub fn async_w<A, B, X1, X2, O1, O2, R>(
+    a: A,
+    b: B
+) -> impl AsyncFn(X1, X2) -> R
+where
+    A: AsyncFn(O1, O2) -> R,
+    B: AsyncFn(X1) -> O1 + AsyncFn(X2) -> O2
+{
+    async move |x1, x2| {
+        let (y0, y1) = join!(
+            b(x1),
+            b(x2)
+        ).await;
+        a(y0, y1).await
+    }
+}
+
+/// W¹ = Converse Warbler
+/// 
+/// λab.baa
+/// 
+/// returns b(a(x1), a(x2))
+pub fn async_w1<A, B, X1, X2, O1, O2, R>(
+    a: A,
+    b: B
+) -> impl AsyncFn(X1, X2) -> R
+where
+    A: AsyncFn(X1) -> O1 + AsyncFn(X2) -> O2,
+    B: AsyncFn(O1, O2) -> R
+{
+    async move |x1, x2| {
+        let (y0, y1) = join!(
+            a(x1),
+            a(x2)
+        ).await;
+        b(y0, y1).await
+    }
+}
+
+/// I* = Identity Bird Once Removed
+/// 
+/// λab.ab
+/// 
+/// returns a(b(x1))
+pub fn async_i_star<A, B, X1, O1, R>(
+    a: A,
+    b: B
+) -> impl AsyncFn(X1) -> R
+where
+    A: AsyncFn(O1) -> R,
+    B: AsyncFn(X1) -> O1
+{
+    async move |x1| {
+        a(b(x1).await).await
+    }
+}
+
+/// W* = Warbled Once Removed
+/// 
+/// λabc.abcc
+/// 
+/// returns a(b(x1), c(x2), c(x3))
+pub fn async_w_star<A, B, C, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl AsyncFn(X1, X2, X3) -> R
+where
+    A: AsyncFn(O1, O2, O3) -> R,
+    B: AsyncFn(X1) -> O1,
+    C: AsyncFn(X2) -> O2 + AsyncFn(X3) -> O3
+{
+    async move |x1, x2, x3| {
+        let (y0, y1, y2) = join!(
+            b(x1),
+            c(x2),
+            c(x3)
+        ).await;
+        a(y0, y1, y2).await
+    }
+}
+
+/// C* = Cardinal Once Removed
+/// 
+/// λabcd.abdc
+/// 
+/// returns a(b(x1), d(x2), c(x3))
+pub fn async_c_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl AsyncFn(X1, X2, X3) -> R
+where
+    A: AsyncFn(O1, O2, O3) -> R,
+    B: AsyncFn(X1) -> O1,
+    C: AsyncFn(X3) -> O3,
+    D: AsyncFn(X2) -> O2
+{
+    async move |x1, x2, x3| {
+        let (y0, y1, y2) = join!(
+            b(x1),
+            d(x2),
+            c(x3)
+        ).await;
+        a(y0, y1, y2).await
+    }
+}
+
+/// R* = Robin Once Removed
+/// 
+/// λabcd.acdb
+/// 
+/// returns a(c(x1), d(x2), b(x3))
+pub fn async_r_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl AsyncFn(X1, X2, X3) -> R
+where
+    A: AsyncFn(O1, O2, O3) -> R,
+    B: AsyncFn(X3) -> O3,
+    C: AsyncFn(X1) -> O1,
+    D: AsyncFn(X2) -> O2
+{
+    async move |x1, x2, x3| {
+        let (y0, y1, y2) = join!(
+            c(x1),
+            d(x2),
+            b(x3)
+        ).await;
+        a(y0, y1, y2).await
+    }
+}
+
+/// F* = Finch Once Removed
+/// 
+/// λabcd.adcb
+/// 
+/// returns a(d(x1), c(x2), b(x3))
+pub fn async_f_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl AsyncFn(X1, X2, X3) -> R
+where
+    A: AsyncFn(O1, O2, O3) -> R,
+    B: AsyncFn(X3) -> O3,
+    C: AsyncFn(X2) -> O2,
+    D: AsyncFn(X1) -> O1
+{
+    async move |x1, x2, x3| {
+        let (y0, y1, y2) = join!(
+            d(x1),
+            c(x2),
+            b(x3)
+        ).await;
+        a(y0, y1, y2).await
+    }
+}
+
+/// V* = Vireo Once Removed
+/// 
+/// λabcd.acbd
+/// 
+/// returns a(c(x1), b(x2), d(x3))
+pub fn async_v_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl AsyncFn(X1, X2, X3) -> R
+where
+    A: AsyncFn(O1, O2, O3) -> R,
+    B: AsyncFn(X2) -> O2,
+    C: AsyncFn(X1) -> O1,
+    D: AsyncFn(X3) -> O3
+{
+    async move |x1, x2, x3| {
+        let (y0, y1, y2) = join!(
+            c(x1),
+            b(x2),
+            d(x3)
+        ).await;
+        a(y0, y1, y2).await
+    }
+}
+
+/// I** = Identity Bird Twice Removed
+/// 
+/// λabc.abc
+/// 
+/// returns a(b(x1), c(x2))
+pub fn async_i_star_star<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl AsyncFn(X1, X2) -> R
+where
+    A: AsyncFn(O1, O2) -> R,
+    B: AsyncFn(X1) -> O1,
+    C: AsyncFn(X2) -> O2
+{
+    async move |x1, x2| {
+        let (y0, y1) = join!(
+            b(x1),
+            c(x2)
+        ).await;
+        a(y0, y1).await
+    }
+}
+
+/// W** = Warbler Twice Removed
+/// 
+/// λabcd.abcdd
+/// 
+/// returns a(b(x1), c(x2), d(x3), d(x4))
+pub fn async_w_star_star<A, B, C, D, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl AsyncFn(X1, X2, X3, X4) -> R
+where
+    A: AsyncFn(O1, O2, O3, O4) -> R,
+    B: AsyncFn(X1) -> O1,
+    C: AsyncFn(X2) -> O2,
+    D: AsyncFn(X3) -> O3 + AsyncFn(X4) -> O4
+{
+    async move |x1, x2, x3, x4| {
+        let (y0, y1, y2, y3) = join!(
+            b(x1),
+            c(x2),
+            d(x3),
+            d(x4)
+        ).await;
+        a(y0, y1, y2, y3).await
+    }
+}
+
+/// C** = Cardinal Twice Removed
+/// 
+/// λabcde.abced
+/// 
+/// returns a(b(x1), c(x2), e(x3), d(x4))
+pub fn async_c_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl AsyncFn(X1, X2, X3, X4) -> R
+where
+    A: AsyncFn(O1, O2, O3, O4) -> R,
+    B: AsyncFn(X1) -> O1,
+    C: AsyncFn(X2) -> O2,
+    D: AsyncFn(X4) -> O4,
+    E: AsyncFn(X3) -> O3
+{
+    async move |x1, x2, x3, x4| {
+        let (y0, y1, y2, y3) = join!(
+            b(x1),
+            c(x2),
+            e(x3),
+            d(x4)
+        ).await;
+        a(y0, y1, y2, y3).await
+    }
+}
+
+/// R** = Robin Twice Removed
+/// 
+/// λabcde.abdec
+/// 
+/// returns a(b(x1), d(x2), e(x3), c(x4))
+pub fn async_r_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl AsyncFn(X1, X2, X3, X4) -> R
+where
+    A: AsyncFn(O1, O2, O3, O4) -> R,
+    B: AsyncFn(X1) -> O1,
+    C: AsyncFn(X4) -> O4,
+    D: AsyncFn(X2) -> O2,
+    E: AsyncFn(X3) -> O3
+{
+    async move |x1, x2, x3, x4| {
+        let (y0, y1, y2, y3) = join!(
+            b(x1),
+            d(x2),
+            e(x3),
+            c(x4)
+        ).await;
+        a(y0, y1, y2, y3).await
+    }
+}
+
+/// F** = Finch Twice Removed
+/// 
+/// λabcde.abedc
+/// 
+/// returns a(b(x1), e(x2), d(x3), c(x4))
+pub fn async_f_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl AsyncFn(X1, X2, X3, X4) -> R
+where
+    A: AsyncFn(O1, O2, O3, O4) -> R,
+    B: AsyncFn(X1) -> O1,
+    C: AsyncFn(X4) -> O4,
+    D: AsyncFn(X3) -> O3,
+    E: AsyncFn(X2) -> O2
+{
+    async move |x1, x2, x3, x4| {
+        let (y0, y1, y2, y3) = join!(
+            b(x1),
+            e(x2),
+            d(x3),
+            c(x4)
+        ).await;
+        a(y0, y1, y2, y3).await
+    }
+}
+
+/// V** = Vireo Twice Removed
+/// 
+/// λabcde.abecd
+/// 
+/// returns a(b(x1), e(x2), c(x3), d(x4))
+pub fn async_v_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl AsyncFn(X1, X2, X3, X4) -> R
+where
+    A: AsyncFn(O1, O2, O3, O4) -> R,
+    B: AsyncFn(X1) -> O1,
+    C: AsyncFn(X3) -> O3,
+    D: AsyncFn(X4) -> O4,
+    E: AsyncFn(X2) -> O2
+{
+    async move |x1, x2, x3, x4| {
+        let (y0, y1, y2, y3) = join!(
+            b(x1),
+            e(x2),
+            c(x3),
+            d(x4)
+        ).await;
+        a(y0, y1, y2, y3).await
+    }
+}
+
+/// KI = Kite (False)
+/// 
+/// λab.b
+/// 
+/// returns b(x1)
+#[allow(unused)]
+pub fn async_ki<A, B, X1, R>(
+    a: A,
+    b: B
+) -> impl AsyncFn(X1) -> R
+where
+    B: AsyncFn(X1) -> R
+{
+    async move |x1| {
+        b(x1).await
+    }
+}
+
+/// KM = Constant Mocker
+/// 
+/// λab.bb
+/// 
+/// returns b(b(x1))
+#[allow(unused)]
+pub fn async_km<A, B, X1, O1, R>(
+    a: A,
+    b: B
+) -> impl AsyncFn(X1) -> R
+where
+    B: AsyncFn(X1) -> O1 + AsyncFn(O1) -> R
+{
+    async move |x1| {
+        b(b(x1).await).await
+    }
+}
+
+/// C(KM) = Crossed Constant Mocker
+/// 
+/// λab.aa
+/// 
+/// returns a(a(x1))
+#[allow(unused)]
+pub fn async_ckm<A, B, X1, O1, R>(
+    a: A,
+    b: B
+) -> impl AsyncFn(X1) -> R
+where
+    A: AsyncFn(X1) -> O1 + AsyncFn(O1) -> R
+{
+    async move |x1| {
+        a(a(x1).await).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::future::Future;
+    use std::pin::{pin, Pin};
+    use std::task::{Context, Poll, Waker};
+
+    use super::*;
+
+    /// Polls the future until it is ready, which is enough for futures which wake themselves
+    fn block_on<F: Future>(future: F) -> F::Output
+    {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop
+        {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx)
+            {
+                return output
+            }
+        }
+    }
+
+    /// Returns [Pending](Poll::Pending) once, so that other futures get a turn
+    struct Yield(bool);
+
+    impl Future for Yield
+    {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()>
+        {
+            if self.0
+            {
+                return Poll::Ready(())
+            }
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn test_birds()
+    {
+        let a = |x: u8, y: u8| x.wrapping_mul(3).wrapping_add(y);
+        let b = |x: u8| x.wrapping_mul(5);
+        let c = |x: u8| x.wrapping_add(7);
+        let d = |x: u8| x.rotate_left(1);
+
+        let async_a = async |x: u8, y: u8| a(x, y);
+        let async_b = async |x: u8| b(x);
+        let async_c = async |x: u8| c(x);
+        let async_d = async |x: u8| d(x);
+
+        let bluebird = super::async_b(async_b, async_c, async_d);
+        let starling = async_s(async_a, async_b, async_c);
+        let goldfinch = async_g(async_a, async_b, async_c, async_d);
+        let dovekies = async_d2(async_a, async_b, async_c, async_d, async_c);
+        let jay = async_j(async_a, async_b, async_c, async_d);
+        let bald_eagle = async_ê(async_a, async_a, async_b, async_c, async_a, async_d, async_b);
+
+        for i in 0..=255
+        {
+            assert_eq!(block_on(bluebird(i)), b(c(d(i))));
+            for j in (0..=255).step_by(15)
+            {
+                assert_eq!(block_on(starling(i, j)), a(c(i), b(c(j))));
+                assert_eq!(block_on(goldfinch(i, j)), a(d(i), b(c(j))));
+                assert_eq!(block_on(dovekies(i, j)), a(b(c(i)), d(c(j))));
+                assert_eq!(block_on(jay(i, j, i)), a(b(i), a(d(j), c(i))));
+                assert_eq!(block_on(bald_eagle(i, j, j, i)), a(a(b(i), c(j)), a(d(j), b(i))));
+            }
+        }
+    }
+
+    #[test]
+    fn test_concurrent()
+    {
+        let log = RefCell::new(vec![]);
+        let step = |name: &'static str| {
+            let log = &log;
+            async move |x: u8| {
+                log.borrow_mut().push(format!("{name}({x})"));
+                Yield(false).await;
+                log.borrow_mut().push(format!("{name} = {x}"));
+                x
+            }
+        };
+        let add = async |x: u8, y: u8| x + y;
+
+        let starling = async_s(add, step("b"), step("c"));
+
+        assert_eq!(block_on(starling(1, 2)), 3);
+        // both calls of c start before either of them ends
+        assert_eq!(log.take(), ["c(1)", "c(2)", "c = 1", "c = 2", "b(2)", "b = 2"]);
+    }
+}
@@ -0,0 +1,951 @@
+//! Birds over any category
+//!
+//! The birds in the crate root compose closures with [Compose](currycompose::Compose). Here, a [Category](Category) says what its
+//! morphisms are, and how to compose them, and the birds of the [Birds](Birds) trait compose the morphisms of any category.
+//! A morphism goes from one object to one other, so only the birds returning a composition are here, and a bird reads its
+//! composition as an associative chain of its morphisms, each taking the output of the next, such as `a ∘ c ∘ (b ∘ c)`
+//! for S, which is a(c(b(c(x)))): the last of the [possible interpretations](crate#interpretations) of each bird in the crate root.
+//!
+//! The categories provided are:
+//! * [Function](Function), where the morphisms are functions,
+//! * [Kleisli](Kleisli), where the morphisms are functions returning a [Monad](Monad), such as [Option](Option), [Result](Result) or [Vec](Vec),
+//!   which [bind](Monad::bind) the output of one function into the next.
+//!
+//! ```rust
+//! use birbs::category::{arrow, Birds, Function, Kleisli, Optional};
+//!
+//! // c ∘ (a ∘ b)
+//! let f = Function::q3(arrow(|x: u32| x*2), arrow(|x: &str| x.len() as u32), arrow(|x: u32| x + 1));
+//!
+//! assert_eq!(f("four"), 9);
+//!
+//! let f = Kleisli::<Optional>::q3(arrow(|x: u32| x.checked_mul(2)), arrow(|x: &str| x.parse().ok()), arrow(|x: u32| x.checked_sub(1)));
+//!
+//! assert_eq!(f("4"), Some(7));
+//! assert_eq!(f("four"), None);
+//! ```
+//!
+//! Any other morphisms are composed by implementing [Category](Category) for them.
+
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+/// Objects, and morphisms between them, which compose associatively, and have an identity
+/// 
+/// The objects are `'static` types, so that a morphism may be a boxed closure.
+pub trait Category
+{
+    /// The morphisms from `A` to `B`
+    type Hom<A, B>;
+
+    /// The morphism from `A` to `A` which composes to the other morphism, on either side
+    fn identity<A: 'static>() -> Self::Hom<A, A>;
+
+    /// g ∘ f
+    fn compose<A: 'static, B: 'static, C: 'static>(g: Self::Hom<B, C>, f: Self::Hom<A, B>) -> Self::Hom<A, C>;
+}
+
+/// A shared function, the type of the morphisms of [Function](Function) and [Kleisli](Kleisli)
+pub type Arrow<A, B> = Rc<dyn Fn(A) -> B>;
+
+/// Shares a function as an [Arrow](Arrow)
+pub fn arrow<A, B>(function: impl Fn(A) -> B + 'static) -> Arrow<A, B>
+{
+    Rc::new(function)
+}
+
+/// The category of functions
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Function;
+
+impl Category for Function
+{
+    type Hom<A, B> = Arrow<A, B>;
+
+    fn identity<A: 'static>() -> Arrow<A, A>
+    {
+        arrow(|x| x)
+    }
+
+    fn compose<A: 'static, B: 'static, C: 'static>(g: Arrow<B, C>, f: Arrow<A, B>) -> Arrow<A, C>
+    {
+        arrow(move |x| g(f(x)))
+    }
+}
+
+/// A type wrapping values, into which a value is put by [pure](Monad::pure), and out of which a value is bound by [bind](Monad::bind)
+pub trait Monad
+{
+    /// The type wrapping a `T`
+    type Of<T>;
+
+    /// Wraps a value
+    fn pure<T>(value: T) -> Self::Of<T>;
+
+    /// Calls the function with the values which are wrapped, joining the outputs
+    fn bind<T, U>(value: Self::Of<T>, function: impl Fn(T) -> Self::Of<U>) -> Self::Of<U>;
+}
+
+/// [Option](Option), as a [Monad](Monad) of a value which may be missing
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Optional;
+
+impl Monad for Optional
+{
+    type Of<T> = Option<T>;
+
+    fn pure<T>(value: T) -> Option<T>
+    {
+        Some(value)
+    }
+
+    fn bind<T, U>(value: Option<T>, function: impl Fn(T) -> Option<U>) -> Option<U>
+    {
+        value.and_then(function)
+    }
+}
+
+/// [Result](Result), as a [Monad](Monad) of a value which may be an error of type `E`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Fallible<E>(PhantomData<E>);
+
+impl<E> Monad for Fallible<E>
+{
+    type Of<T> = Result<T, E>;
+
+    fn pure<T>(value: T) -> Result<T, E>
+    {
+        Ok(value)
+    }
+
+    fn bind<T, U>(value: Result<T, E>, function: impl Fn(T) -> Result<U, E>) -> Result<U, E>
+    {
+        value.and_then(function)
+    }
+}
+
+/// [Vec](Vec), as a [Monad](Monad) of any number of values
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Many;
+
+impl Monad for Many
+{
+    type Of<T> = Vec<T>;
+
+    fn pure<T>(value: T) -> Vec<T>
+    {
+        vec![value]
+    }
+
+    fn bind<T, U>(value: Vec<T>, function: impl Fn(T) -> Vec<U>) -> Vec<U>
+    {
+        value.into_iter()
+            .flat_map(function)
+            .collect()
+    }
+}
+
+/// The Kleisli category of a [Monad](Monad), where a morphism from `A` to `B` is a function from `A` to the monad of `B`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Kleisli<M>(PhantomData<M>);
+
+impl<M: Monad + 'static> Category for Kleisli<M>
+{
+    type Hom<A, B> = Arrow<A, M::Of<B>>;
+
+    fn identity<A: 'static>() -> Self::Hom<A, A>
+    {
+        arrow(M::pure)
+    }
+
+    fn compose<A: 'static, B: 'static, C: 'static>(g: Self::Hom<B, C>, f: Self::Hom<A, B>) -> Self::Hom<A, C>
+    {
+        arrow(move |x| M::bind(f(x), &*g))
+    }
+}
+
+/// The birds, composing the morphisms of a [Category](Category)
+/// 
+/// Morphisms which are used more than once must be [Clone](Clone).
+pub trait Birds: Category
+{
+    /// B = Bluebird
+    /// 
+    /// λabc.a(bc)
+    /// 
+    /// returns a ∘ (b ∘ c)
+    fn b<T0: 'static, T1: 'static, T2: 'static, T3: 'static>(
+        a: Self::Hom<T2, T3>,
+        b: Self::Hom<T1, T2>,
+        c: Self::Hom<T0, T1>
+    ) -> Self::Hom<T0, T3>
+    {
+        Self::compose(a, Self::compose(b, c))
+    }
+
+    /// B¹ = Blackbird
+    /// 
+    /// λabcd.a(bcd)
+    /// 
+    /// returns a ∘ (b ∘ c ∘ d)
+    fn b1<T0: 'static, T1: 'static, T2: 'static, T3: 'static, T4: 'static>(
+        a: Self::Hom<T3, T4>,
+        b: Self::Hom<T2, T3>,
+        c: Self::Hom<T1, T2>,
+        d: Self::Hom<T0, T1>
+    ) -> Self::Hom<T0, T4>
+    {
+        Self::compose(a, Self::compose(Self::compose(b, c), d))
+    }
+
+    /// B² = Bunting
+    /// 
+    /// λabcde.a(bcde)
+    /// 
+    /// returns a ∘ (b ∘ c ∘ d ∘ e)
+    fn b2<T0: 'static, T1: 'static, T2: 'static, T3: 'static, T4: 'static, T5: 'static>(
+        a: Self::Hom<T4, T5>,
+        b: Self::Hom<T3, T4>,
+        c: Self::Hom<T2, T3>,
+        d: Self::Hom<T1, T2>,
+        e: Self::Hom<T0, T1>
+    ) -> Self::Hom<T0, T5>
+    {
+        Self::compose(a, Self::compose(Self::compose(Self::compose(b, c), d), e))
+    }
+
+    /// B³ = Becard
+    /// 
+    /// λabcd.a(b(cd))
+    /// 
+    /// returns a ∘ (b ∘ (c ∘ d))
+    fn b3<T0: 'static, T1: 'static, T2: 'static, T3: 'static, T4: 'static>(
+        a: Self::Hom<T3, T4>,
+        b: Self::Hom<T2, T3>,
+        c: Self::Hom<T1, T2>,
+        d: Self::Hom<T0, T1>
+    ) -> Self::Hom<T0, T4>
+    {
+        Self::compose(a, Self::compose(b, Self::compose(c, d)))
+    }
+
+    /// C = Cardinal
+    /// 
+    /// λabc.acb
+    /// 
+    /// returns a ∘ c ∘ b
+    fn c<T0: 'static, T1: 'static, T2: 'static, T3: 'static>(
+        a: Self::Hom<T2, T3>,
+        b: Self::Hom<T0, T1>,
+        c: Self::Hom<T1, T2>
+    ) -> Self::Hom<T0, T3>
+    {
+        Self::compose(Self::compose(a, c), b)
+    }
+
+    /// D = Dove
+    /// 
+    /// λabcd.ab(cd)
+    /// 
+    /// returns a ∘ b ∘ (c ∘ d)
+    fn d<T0: 'static, T1: 'static, T2: 'static, T3: 'static, T4: 'static>(
+        a: Self::Hom<T3, T4>,
+        b: Self::Hom<T2, T3>,
+        c: Self::Hom<T1, T2>,
+        d: Self::Hom<T0, T1>
+    ) -> Self::Hom<T0, T4>
+    {
+        Self::compose(Self::compose(a, b), Self::compose(c, d))
+    }
+
+    /// D¹ = Dickcissel
+    /// 
+    /// λabcde.abc(de)
+    /// 
+    /// returns a ∘ b ∘ c ∘ (d ∘ e)
+    fn d1<T0: 'static, T1: 'static, T2: 'static, T3: 'static, T4: 'static, T5: 'static>(
+        a: Self::Hom<T4, T5>,
+        b: Self::Hom<T3, T4>,
+        c: Self::Hom<T2, T3>,
+        d: Self::Hom<T1, T2>,
+        e: Self::Hom<T0, T1>
+    ) -> Self::Hom<T0, T5>
+    {
+        Self::compose(Self::compose(Self::compose(a, b), c), Self::compose(d, e))
+    }
+
+    /// D² = Dovekies
+    /// 
+    /// λabcde.a(bc)(de)
+    /// 
+    /// returns a ∘ (b ∘ c) ∘ (d ∘ e)
+    fn d2<T0: 'static, T1: 'static, T2: 'static, T3: 'static, T4: 'static, T5: 'static>(
+        a: Self::Hom<T4, T5>,
+        b: Self::Hom<T3, T4>,
+        c: Self::Hom<T2, T3>,
+        d: Self::Hom<T1, T2>,
+        e: Self::Hom<T0, T1>
+    ) -> Self::Hom<T0, T5>
+    {
+        Self::compose(Self::compose(a, Self::compose(b, c)), Self::compose(d, e))
+    }
+
+    /// E = Eagle
+    /// 
+    /// λabcde.ab(cde)
+    /// 
+    /// returns a ∘ b ∘ (c ∘ d ∘ e)
+    fn e<T0: 'static, T1: 'static, T2: 'static, T3: 'static, T4: 'static, T5: 'static>(
+        a: Self::Hom<T4, T5>,
+        b: Self::Hom<T3, T4>,
+        c: Self::Hom<T2, T3>,
+        d: Self::Hom<T1, T2>,
+        e: Self::Hom<T0, T1>
+    ) -> Self::Hom<T0, T5>
+    {
+        Self::compose(Self::compose(a, b), Self::compose(Self::compose(c, d), e))
+    }
+
+    /// Ê = Bald Eagle
+    /// 
+    /// λabcdefg.a(bcd)(efg)
+    /// 
+    /// returns a ∘ (b ∘ c ∘ d) ∘ (e ∘ f ∘ g)
+    fn ê<T0: 'static, T1: 'static, T2: 'static, T3: 'static, T4: 'static, T5: 'static, T6: 'static, T7: 'static>(
+        a: Self::Hom<T6, T7>,
+        b: Self::Hom<T5, T6>,
+        c: Self::Hom<T4, T5>,
+        d: Self::Hom<T3, T4>,
+        e: Self::Hom<T2, T3>,
+        f: Self::Hom<T1, T2>,
+        g: Self::Hom<T0, T1>
+    ) -> Self::Hom<T0, T7>
+    {
+        Self::compose(Self::compose(a, Self::compose(Self::compose(b, c), d)), Self::compose(Self::compose(e, f), g))
+    }
+
+    /// F = Finch
+    /// 
+    /// λabc.cba
+    /// 
+    /// returns c ∘ b ∘ a
+    fn f<T0: 'static, T1: 'static, T2: 'static, T3: 'static>(
+        a: Self::Hom<T0, T1>,
+        b: Self::Hom<T1, T2>,
+        c: Self::Hom<T2, T3>
+    ) -> Self::Hom<T0, T3>
+    {
+        Self::compose(Self::compose(c, b), a)
+    }
+
+    /// G = Goldfinch
+    /// 
+    /// λabcd.ad(bc)
+    /// 
+    /// returns a ∘ d ∘ (b ∘ c)
+    fn g<T0: 'static, T1: 'static, T2: 'static, T3: 'static, T4: 'static>(
+        a: Self::Hom<T3, T4>,
+        b: Self::Hom<T1, T2>,
+        c: Self::Hom<T0, T1>,
+        d: Self::Hom<T2, T3>
+    ) -> Self::Hom<T0, T4>
+    {
+        Self::compose(Self::compose(a, d), Self::compose(b, c))
+    }
+
+    /// H = Hummingbird
+    /// 
+    /// λabc.abcb
+    /// 
+    /// returns a ∘ b ∘ c ∘ b
+    fn h<T0: 'static, T1: 'static, T2: 'static>(
+        a: Self::Hom<T1, T2>,
+        b: Self::Hom<T0, T1>,
+        c: Self::Hom<T1, T0>
+    ) -> Self::Hom<T0, T2>
+    where
+        Self::Hom<T0, T1>: Clone
+    {
+        Self::compose(Self::compose(Self::compose(a, b.clone()), c), b)
+    }
+
+    /// J = Jay
+    /// 
+    /// λabcd.ab(adc)
+    /// 
+    /// returns a ∘ b ∘ (a ∘ d ∘ c)
+    fn j<T0: 'static, T1: 'static, T2: 'static, T3: 'static>(
+        a: Self::Hom<T2, T3>,
+        b: Self::Hom<T3, T2>,
+        c: Self::Hom<T0, T1>,
+        d: Self::Hom<T1, T2>
+    ) -> Self::Hom<T0, T3>
+    where
+        Self::Hom<T2, T3>: Clone
+    {
+        Self::compose(Self::compose(a.clone(), b), Self::compose(Self::compose(a, d), c))
+    }
+
+    /// L = Lark
+    /// 
+    /// λab.a(bb)
+    /// 
+    /// returns a ∘ (b ∘ b)
+    fn l<T0: 'static, T1: 'static>(
+        a: Self::Hom<T0, T1>,
+        b: Self::Hom<T0, T0>
+    ) -> Self::Hom<T0, T1>
+    where
+        Self::Hom<T0, T0>: Clone
+    {
+        Self::compose(a, Self::compose(b.clone(), b))
+    }
+
+    /// M = Mockingbird
+    /// 
+    /// λa.aa
+    /// 
+    /// returns a ∘ a
+    fn m<T0: 'static>(
+        a: Self::Hom<T0, T0>
+    ) -> Self::Hom<T0, T0>
+    where
+        Self::Hom<T0, T0>: Clone
+    {
+        Self::compose(a.clone(), a)
+    }
+
+    /// M² = Double Mockingbird
+    /// 
+    /// λab.ab(ab)
+    /// 
+    /// returns a ∘ b ∘ (a ∘ b)
+    fn m2<T0: 'static, T1: 'static>(
+        a: Self::Hom<T1, T0>,
+        b: Self::Hom<T0, T1>
+    ) -> Self::Hom<T0, T0>
+    where
+        Self::Hom<T1, T0>: Clone,
+        Self::Hom<T0, T1>: Clone
+    {
+        Self::compose(Self::compose(a.clone(), b.clone()), Self::compose(a, b))
+    }
+
+    /// O = Owl
+    /// 
+    /// λab.b(ab)
+    /// 
+    /// returns b ∘ (a ∘ b)
+    fn o<T0: 'static, T1: 'static>(
+        a: Self::Hom<T1, T0>,
+        b: Self::Hom<T0, T1>
+    ) -> Self::Hom<T0, T1>
+    where
+        Self::Hom<T0, T1>: Clone
+    {
+        Self::compose(b.clone(), Self::compose(a, b))
+    }
+
+    /// Q = Queer Bird
+    /// 
+    /// λabc.b(ac)
+    /// 
+    /// returns b ∘ (a ∘ c)
+    fn q<T0: 'static, T1: 'static, T2: 'static, T3: 'static>(
+        a: Self::Hom<T1, T2>,
+        b: Self::Hom<T2, T3>,
+        c: Self::Hom<T0, T1>
+    ) -> Self::Hom<T0, T3>
+    {
+        Self::compose(b, Self::compose(a, c))
+    }
+
+    /// Q¹ = Quixotic Bird
+    /// 
+    /// λabc.a(cb)
+    /// 
+    /// returns a ∘ (c ∘ b)
+    fn q1<T0: 'static, T1: 'static, T2: 'static, T3: 'static>(
+        a: Self::Hom<T2, T3>,
+        b: Self::Hom<T0, T1>,
+        c: Self::Hom<T1, T2>
+    ) -> Self::Hom<T0, T3>
+    {
+        Self::compose(a, Self::compose(c, b))
+    }
+
+    /// Q² = Quizzical Bird
+    /// 
+    /// λabc.b(ca)
+    /// 
+    /// returns b ∘ (c ∘ a)
+    fn q2<T0: 'static, T1: 'static, T2: 'static, T3: 'static>(
+        a: Self::Hom<T0, T1>,
+        b: Self::Hom<T2, T3>,
+        c: Self::Hom<T1, T2>
+    ) -> Self::Hom<T0, T3>
+    {
+        Self::compose(b, Self::compose(c, a))
+    }
+
+    /// Q³ = Quirky Bird
+    /// 
+    /// λabc.c(ab)
+    /// 
+    /// returns c ∘ (a ∘ b)
+    fn q3<T0: 'static, T1: 'static, T2: 'static, T3: 'static>(
+        a: Self::Hom<T1, T2>,
+        b: Self::Hom<T0, T1>,
+        c: Self::Hom<T2, T3>
+    ) -> Self::Hom<T0, T3>
+    {
+        Self::compose(c, Self::compose(a, b))
+    }
+
+    /// Q⁴ = Quacky Bird
+    /// 
+    /// λabc.c(ba)
+    /// 
+    /// returns c ∘ (b ∘ a)
+    fn q4<T0: 'static, T1: 'static, T2: 'static, T3: 'static>(
+        a: Self::Hom<T0, T1>,
+        b: Self::Hom<T1, T2>,
+        c: Self::Hom<T2, T3>
+    ) -> Self::Hom<T0, T3>
+    {
+        Self::compose(c, Self::compose(b, a))
+    }
+
+    /// R = Robin
+    /// 
+    /// λabc.bca
+    /// 
+    /// returns b ∘ c ∘ a
+    fn r<T0: 'static, T1: 'static, T2: 'static, T3: 'static>(
+        a: Self::Hom<T0, T1>,
+        b: Self::Hom<T2, T3>,
+        c: Self::Hom<T1, T2>
+    ) -> Self::Hom<T0, T3>
+    {
+        Self::compose(Self::compose(b, c), a)
+    }
+
+    /// S = Starling
+    /// 
+    /// λabc.ac(bc)
+    /// 
+    /// returns a ∘ c ∘ (b ∘ c)
+    fn s<T0: 'static, T1: 'static, T2: 'static>(
+        a: Self::Hom<T1, T2>,
+        b: Self::Hom<T1, T0>,
+        c: Self::Hom<T0, T1>
+    ) -> Self::Hom<T0, T2>
+    where
+        Self::Hom<T0, T1>: Clone
+    {
+        Self::compose(Self::compose(a, c.clone()), Self::compose(b, c))
+    }
+
+    /// T = Thrush
+    /// 
+    /// λab.ba
+    /// 
+    /// returns b ∘ a
+    fn t<T0: 'static, T1: 'static, T2: 'static>(
+        a: Self::Hom<T0, T1>,
+        b: Self::Hom<T1, T2>
+    ) -> Self::Hom<T0, T2>
+    {
+        Self::compose(b, a)
+    }
+
+    /// U = Turing
+    /// 
+    /// λab.b(aab)
+    /// 
+    /// returns b ∘ (a ∘ a ∘ b)
+    fn u<T0: 'static>(
+        a: Self::Hom<T0, T0>,
+        b: Self::Hom<T0, T0>
+    ) -> Self::Hom<T0, T0>
+    where
+        Self::Hom<T0, T0>: Clone,
+        Self::Hom<T0, T0>: Clone
+    {
+        Self::compose(b.clone(), Self::compose(Self::compose(a.clone(), a), b))
+    }
+
+    /// V = Vireo aka Pairing
+    /// 
+    /// λabc.cab
+    /// 
+    /// returns c ∘ a ∘ b
+    fn v<T0: 'static, T1: 'static, T2: 'static, T3: 'static>(
+        a: Self::Hom<T1, T2>,
+        b: Self::Hom<T0, T1>,
+        c: Self::Hom<T2, T3>
+    ) -> Self::Hom<T0, T3>
+    {
+        Self::compose(Self::compose(c, a), b)
+    }
+
+    /// W = Warbler
+    /// 
+    /// λab.abb
+    /// 
+    /// returns a ∘ b ∘ b
+    fn w<T0: 'static, T1: 'static>(
+        a: Self::Hom<T0, T1>,
+        b: Self::Hom<T0, T0>
+    ) -> Self::Hom<T0, T1>
+    where
+        Self::Hom<T0, T0>: Clone
+    {
+        Self::compose(Self::compose(a, b.clone()), b)
+    }
+
+    /// W¹ = Converse Warbler
+    /// 
+    /// λab.baa
+    /// 
+    /// returns b ∘ a ∘ a
+    fn w1<T0: 'static, T1: 'static>(
+        a: Self::Hom<T0, T0>,
+        b: Self::Hom<T0, T1>
+    ) -> Self::Hom<T0, T1>
+    where
+        Self::Hom<T0, T0>: Clone
+    {
+        Self::compose(Self::compose(b, a.clone()), a)
+    }
+
+    /// I* = Identity Bird Once Removed
+    /// 
+    /// λab.ab
+    /// 
+    /// returns a ∘ b
+    fn i_star<T0: 'static, T1: 'static, T2: 'static>(
+        a: Self::Hom<T1, T2>,
+        b: Self::Hom<T0, T1>
+    ) -> Self::Hom<T0, T2>
+    {
+        Self::compose(a, b)
+    }
+
+    /// W* = Warbled Once Removed
+    /// 
+    /// λabc.abcc
+    /// 
+    /// returns a ∘ b ∘ c ∘ c
+    fn w_star<T0: 'static, T1: 'static, T2: 'static>(
+        a: Self::Hom<T1, T2>,
+        b: Self::Hom<T0, T1>,
+        c: Self::Hom<T0, T0>
+    ) -> Self::Hom<T0, T2>
+    where
+        Self::Hom<T0, T0>: Clone
+    {
+        Self::compose(Self::compose(Self::compose(a, b), c.clone()), c)
+    }
+
+    /// C* = Cardinal Once Removed
+    /// 
+    /// λabcd.abdc
+    /// 
+    /// returns a ∘ b ∘ d ∘ c
+    fn c_star<T0: 'static, T1: 'static, T2: 'static, T3: 'static, T4: 'static>(
+        a: Self::Hom<T3, T4>,
+        b: Self::Hom<T2, T3>,
+        c: Self::Hom<T0, T1>,
+        d: Self::Hom<T1, T2>
+    ) -> Self::Hom<T0, T4>
+    {
+        Self::compose(Self::compose(Self::compose(a, b), d), c)
+    }
+
+    /// R* = Robin Once Removed
+    /// 
+    /// λabcd.acdb
+    /// 
+    /// returns a ∘ c ∘ d ∘ b
+    fn r_star<T0: 'static, T1: 'static, T2: 'static, T3: 'static, T4: 'static>(
+        a: Self::Hom<T3, T4>,
+        b: Self::Hom<T0, T1>,
+        c: Self::Hom<T2, T3>,
+        d: Self::Hom<T1, T2>
+    ) -> Self::Hom<T0, T4>
+    {
+        Self::compose(Self::compose(Self::compose(a, c), d), b)
+    }
+
+    /// F* = Finch Once Removed
+    /// 
+    /// λabcd.adcb
+    /// 
+    /// returns a ∘ d ∘ c ∘ b
+    fn f_star<T0: 'static, T1: 'static, T2: 'static, T3: 'static, T4: 'static>(
+        a: Self::Hom<T3, T4>,
+        b: Self::Hom<T0, T1>,
+        c: Self::Hom<T1, T2>,
+        d: Self::Hom<T2, T3>
+    ) -> Self::Hom<T0, T4>
+    {
+        Self::compose(Self::compose(Self::compose(a, d), c), b)
+    }
+
+    /// V* = Vireo Once Removed
+    /// 
+    /// λabcd.acbd
+    /// 
+    /// returns a ∘ c ∘ b ∘ d
+    fn v_star<T0: 'static, T1: 'static, T2: 'static, T3: 'static, T4: 'static>(
+        a: Self::Hom<T3, T4>,
+        b: Self::Hom<T1, T2>,
+        c: Self::Hom<T2, T3>,
+        d: Self::Hom<T0, T1>
+    ) -> Self::Hom<T0, T4>
+    {
+        Self::compose(Self::compose(Self::compose(a, c), b), d)
+    }
+
+    /// I** = Identity Bird Twice Removed
+    /// 
+    /// λabc.abc
+    /// 
+    /// returns a ∘ b ∘ c
+    fn i_star_star<T0: 'static, T1: 'static, T2: 'static, T3: 'static>(
+        a: Self::Hom<T2, T3>,
+        b: Self::Hom<T1, T2>,
+        c: Self::Hom<T0, T1>
+    ) -> Self::Hom<T0, T3>
+    {
+        Self::compose(Self::compose(a, b), c)
+    }
+
+    /// W** = Warbler Twice Removed
+    /// 
+    /// λabcd.abcdd
+    /// 
+    /// returns a ∘ b ∘ c ∘ d ∘ d
+    fn w_star_star<T0: 'static, T1: 'static, T2: 'static, T3: 'static>(
+        a: Self::Hom<T2, T3>,
+        b: Self::Hom<T1, T2>,
+        c: Self::Hom<T0, T1>,
+        d: Self::Hom<T0, T0>
+    ) -> Self::Hom<T0, T3>
+    where
+        Self::Hom<T0, T0>: Clone
+    {
+        Self::compose(Self::compose(Self::compose(Self::compose(a, b), c), d.clone()), d)
+    }
+
+    /// C** = Cardinal Twice Removed
+    /// 
+    /// λabcde.abced
+    /// 
+    /// returns a ∘ b ∘ c ∘ e ∘ d
+    fn c_star_star<T0: 'static, T1: 'static, T2: 'static, T3: 'static, T4: 'static, T5: 'static>(
+        a: Self::Hom<T4, T5>,
+        b: Self::Hom<T3, T4>,
+        c: Self::Hom<T2, T3>,
+        d: Self::Hom<T0, T1>,
+        e: Self::Hom<T1, T2>
+    ) -> Self::Hom<T0, T5>
+    {
+        Self::compose(Self::compose(Self::compose(Self::compose(a, b), c), e), d)
+    }
+
+    /// R** = Robin Twice Removed
+    /// 
+    /// λabcde.abdec
+    /// 
+    /// returns a ∘ b ∘ d ∘ e ∘ c
+    fn r_star_star<T0: 'static, T1: 'static, T2: 'static, T3: 'static, T4: 'static, T5: 'static>(
+        a: Self::Hom<T4, T5>,
+        b: Self::Hom<T3, T4>,
+        c: Self::Hom<T0, T1>,
+        d: Self::Hom<T2, T3>,
+        e: Self::Hom<T1, T2>
+    ) -> Self::Hom<T0, T5>
+    {
+        Self::compose(Self::compose(Self::compose(Self::compose(a, b), d), e), c)
+    }
+
+    /// F** = Finch Twice Removed
+    /// 
+    /// λabcde.abedc
+    /// 
+    /// returns a ∘ b ∘ e ∘ d ∘ c
+    fn f_star_star<T0: 'static, T1: 'static, T2: 'static, T3: 'static, T4: 'static, T5: 'static>(
+        a: Self::Hom<T4, T5>,
+        b: Self::Hom<T3, T4>,
+        c: Self::Hom<T0, T1>,
+        d: Self::Hom<T1, T2>,
+        e: Self::Hom<T2, T3>
+    ) -> Self::Hom<T0, T5>
+    {
+        Self::compose(Self::compose(Self::compose(Self::compose(a, b), e), d), c)
+    }
+
+    /// V** = Vireo Twice Removed
+    /// 
+    /// λabcde.abecd
+    /// 
+    /// returns a ∘ b ∘ e ∘ c ∘ d
+    fn v_star_star<T0: 'static, T1: 'static, T2: 'static, T3: 'static, T4: 'static, T5: 'static>(
+        a: Self::Hom<T4, T5>,
+        b: Self::Hom<T3, T4>,
+        c: Self::Hom<T1, T2>,
+        d: Self::Hom<T0, T1>,
+        e: Self::Hom<T2, T3>
+    ) -> Self::Hom<T0, T5>
+    {
+        Self::compose(Self::compose(Self::compose(Self::compose(a, b), e), c), d)
+    }
+
+    /// KM = Constant Mocker
+    /// 
+    /// λab.bb
+    /// 
+    /// returns b ∘ b
+    #[allow(unused)]
+    fn km<T0: 'static, U0: 'static, U1: 'static>(
+        a: Self::Hom<U0, U1>,
+        b: Self::Hom<T0, T0>
+    ) -> Self::Hom<T0, T0>
+    where
+        Self::Hom<T0, T0>: Clone
+    {
+        Self::compose(b.clone(), b)
+    }
+
+    /// C(KM) = Crossed Constant Mocker
+    /// 
+    /// λab.aa
+    /// 
+    /// returns a ∘ a
+    #[allow(unused)]
+    fn ckm<T0: 'static, U0: 'static, U1: 'static>(
+        a: Self::Hom<T0, T0>,
+        b: Self::Hom<U0, U1>
+    ) -> Self::Hom<T0, T0>
+    where
+        Self::Hom<T0, T0>: Clone
+    {
+        Self::compose(a.clone(), a)
+    }
+}
+
+impl<C: Category> Birds for C {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A function with the cost of calling it, where the cost of a composition is the sum of the costs
+    struct Costed;
+
+    struct Priced<A, B>
+    {
+        cost: u32,
+        function: Arrow<A, B>
+    }
+
+    impl<A, B> Clone for Priced<A, B>
+    {
+        fn clone(&self) -> Self
+        {
+            Priced {
+                cost: self.cost,
+                function: self.function.clone()
+            }
+        }
+    }
+
+    impl Category for Costed
+    {
+        type Hom<A, B> = Priced<A, B>;
+
+        fn identity<A: 'static>() -> Priced<A, A>
+        {
+            Priced {
+                cost: 0,
+                function: Function::identity()
+            }
+        }
+
+        fn compose<A: 'static, B: 'static, C: 'static>(g: Priced<B, C>, f: Priced<A, B>) -> Priced<A, C>
+        {
+            Priced {
+                cost: g.cost + f.cost,
+                function: Function::compose(g.function, f.function)
+            }
+        }
+    }
+
+    #[test]
+    fn test_function()
+    {
+        let a = |x: u8| x.wrapping_mul(3);
+        let b = |x: u8| x.rotate_left(1);
+        let c = |x: u8| x ^ 0x55;
+
+        let bluebird = Function::b(arrow(a), arrow(b), arrow(c));
+        let starling = Function::s(arrow(a), arrow(b), arrow(c));
+        let jay = Function::j(arrow(a), arrow(b), arrow(c), arrow(c));
+        let identity = Function::compose(Function::identity(), arrow(a));
+
+        for i in 0..=255
+        {
+            assert_eq!(bluebird(i), a(b(c(i))));
+            assert_eq!(starling(i), a(c(b(c(i)))));
+            assert_eq!(jay(i), a(b(a(c(c(i))))));
+            assert_eq!(identity(i), a(i));
+            assert_eq!(bluebird(i), crate::b(a, b, c)(i));
+        }
+    }
+
+    #[test]
+    fn test_kleisli()
+    {
+        let half = |x: u32| if x.is_multiple_of(2) {Some(x/2)} else {None};
+
+        let warbler = Kleisli::<Optional>::w(arrow(|x: u32| x.checked_sub(1)), arrow(half));
+
+        assert_eq!(warbler(12), Some(2));
+        assert_eq!(warbler(6), None);
+
+        let parse = |x: &str| x.parse::<u32>().map_err(|error| error.to_string());
+        let positive = |x: u32| if x > 0 {Ok(x)} else {Err("zero".to_string())};
+
+        let thrush = Kleisli::<Fallible<String>>::t(arrow(parse), arrow(positive));
+
+        assert_eq!(thrush("3"), Ok(3));
+        assert_eq!(thrush("0"), Err("zero".to_string()));
+        assert_eq!(thrush("x"), Err("invalid digit found in string".to_string()));
+
+        let divisors = |x: u32| (1..=x).filter(|d| x.is_multiple_of(*d)).collect::<Vec<_>>();
+
+        let mockingbird = Kleisli::<Many>::m(arrow(divisors));
+
+        assert_eq!(mockingbird(4), [1, 1, 2, 1, 2, 4]);
+        assert_eq!(Kleisli::<Many>::compose(Kleisli::<Many>::identity(), arrow(divisors))(6), [1, 2, 3, 6]);
+    }
+
+    #[test]
+    fn test_morphisms()
+    {
+        let priced = |cost, function: fn(u8) -> u8| Priced {
+            cost,
+            function: arrow(function)
+        };
+
+        let warbler = Costed::w(priced(1, |x| x.wrapping_mul(3)), priced(10, |x| x.rotate_left(1)));
+        let eagle = Costed::ê(priced(1, |x| x), priced(2, |x| x), priced(3, |x| x), priced(4, |x| x), priced(5, |x| x), priced(6, |x| x), priced(7, |x| x));
+
+        assert_eq!(warbler.cost, 21);
+        assert_eq!((warbler.function)(5), 5u8.rotate_left(2).wrapping_mul(3));
+        assert_eq!(eagle.cost, 28);
+        assert_eq!(Costed::compose(Costed::identity(), eagle).cost, 28);
+    }
+}
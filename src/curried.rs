@@ -0,0 +1,698 @@
+//! First-class birds
+//!
+//! The birds in the crate root are generic functions, so they can't be passed to one another.
+//! Here each bird is a zero-sized value, which takes its arguments one at a time, just like a combinator.
+//! Birds can then be applied to birds, so classic identities such as D = BB or B¹ = BBB hold in rust.
+//!
+//! ```rust
+//! #![feature(unboxed_closures)]
+//!
+//! use birbs::curried::*;
+//!
+//! let a = |x: u8| x + 1;
+//! let b = |x: u8| move |y: u8| x*y;
+//!
+//! // B¹ = BBB
+//! let blackbird = B(B)(B);
+//!
+//! assert_eq!(blackbird(a)(b)(2)(3), a(b(2)(3)));
+//! ```
+//!
+//! A bird, fully or partially applied, is [Fn](Fn) whenever its arguments are [Clone](Clone),
+//! and otherwise only [FnOnce](FnOnce). Birds which duplicate an argument, such as S or W, require it to be [Clone](Clone).
+//!
+//! Y, Θ and Ω are missing, since a function applied to itself forever would have an infinite type.
+
+use std::marker::Tuple;
+
+/// Builds a tuple of the items, nested to the left, such as `((((), a), b), c)`.
+///
+/// The arguments of a partially applied bird are kept like this, so that applying it to another only takes a single pair.
+macro_rules! nest {
+    (@ $acc:tt) => {
+        $acc
+    };
+    (@ $acc:tt $x:ident $($rest:ident)*) => {
+        nest!(@ ($acc, $x) $($rest)*)
+    };
+    ($($x:ident)*) => {
+        nest!(@ () $($x)*)
+    };
+}
+
+/// Implements the application of a bird to each of its arguments, one at a time.
+///
+/// Every argument but the last is only stored, while the last one rewrites the bird.
+macro_rules! chain {
+    (
+        $bird:ident; [$($have:ident)*]; $last:ident;
+        [$($gen:ident),* $(,)?] [$out:ty] {$($bound:tt)*} [$($arg:ident)*] {$body:expr}
+    ) => {
+        impl<$($gen),*> FnOnce<($last,)> for Applied<$bird, nest!($($have)*)>
+        where
+            $($bound)*
+        {
+            type Output = $out;
+
+            #[allow(unused_variables)]
+            extern "rust-call" fn call_once(self, (last,): ($last,)) -> Self::Output
+            {
+                let nest!($($arg)*) = (self.1, last);
+                $body
+            }
+        }
+    };
+    ($bird:ident; [$($have:ident)*]; $next:ident $($rest:ident)+; $($spec:tt)*) => {
+        impl<$($have,)* $next> FnOnce<($next,)> for Applied<$bird, nest!($($have)*)>
+        {
+            type Output = Applied<$bird, nest!($($have)* $next)>;
+
+            extern "rust-call" fn call_once(self, (next,): ($next,)) -> Self::Output
+            {
+                Applied(self.0, (self.1, next))
+            }
+        }
+
+        chain!($bird; [$($have)* $next]; $($rest)+; $($spec)*);
+    };
+}
+
+macro_rules! birds {
+    (
+        $(
+            $(#[$meta:meta])*
+            $bird:ident<$($extra:ident),*>($($arg:ident: $ty:ident),+) -> $out:ty
+            where {$($bound:tt)*} => $body:expr;
+        )*
+    ) => {
+        $(
+            $(#[$meta])*
+            #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+            pub struct $bird;
+
+            impl<X: Tuple> FnOnce<X> for $bird
+            where
+                Applied<$bird, ()>: FnOnce<X>
+            {
+                type Output = <Applied<$bird, ()> as FnOnce<X>>::Output;
+
+                extern "rust-call" fn call_once(self, args: X) -> Self::Output
+                {
+                    Applied(self, ()).call_once(args)
+                }
+            }
+
+            impl<X: Tuple> FnMut<X> for $bird
+            where
+                Applied<$bird, ()>: FnMut<X>
+            {
+                extern "rust-call" fn call_mut(&mut self, args: X) -> Self::Output
+                {
+                    Applied(*self, ()).call_mut(args)
+                }
+            }
+
+            impl<X: Tuple> Fn<X> for $bird
+            where
+                Applied<$bird, ()>: Fn<X>
+            {
+                extern "rust-call" fn call(&self, args: X) -> Self::Output
+                {
+                    Applied(*self, ()).call(args)
+                }
+            }
+
+            chain!($bird; []; $($ty)+; [$($ty,)* $($extra),*] [$out] {$($bound)*} [$($arg)*] {$body});
+        )*
+    };
+}
+
+/// A bird applied to some, but not yet all, of its arguments
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Applied<Bird, Args>(Bird, Args);
+
+impl<Bird, Args, X: Tuple> FnMut<X> for Applied<Bird, Args>
+where
+    Self: FnOnce<X> + Clone
+{
+    extern "rust-call" fn call_mut(&mut self, args: X) -> Self::Output
+    {
+        self.clone().call_once(args)
+    }
+}
+
+impl<Bird, Args, X: Tuple> Fn<X> for Applied<Bird, Args>
+where
+    Self: FnOnce<X> + Clone
+{
+    extern "rust-call" fn call(&self, args: X) -> Self::Output
+    {
+        self.clone().call_once(args)
+    }
+}
+
+birds! {
+    /// B = Bluebird
+    ///
+    /// λabc.a(bc)
+    B<Bc, Out>(a: Fa, b: Fb, c: Fc) -> Out
+    where {
+        Fb: FnOnce(Fc) -> Bc,
+        Fa: FnOnce(Bc) -> Out
+    } => a(b(c));
+
+    /// B¹ = Blackbird
+    ///
+    /// λabcd.a(bcd)
+    B1<Bc, Bcd, Out>(a: Fa, b: Fb, c: Fc, d: Fd) -> Out
+    where {
+        Fb: FnOnce(Fc) -> Bc,
+        Bc: FnOnce(Fd) -> Bcd,
+        Fa: FnOnce(Bcd) -> Out
+    } => a(b(c)(d));
+
+    /// B² = Bunting
+    ///
+    /// λabcde.a(bcde)
+    B2<Bc, Bcd, Bcde, Out>(a: Fa, b: Fb, c: Fc, d: Fd, e: Fe) -> Out
+    where {
+        Fb: FnOnce(Fc) -> Bc,
+        Bc: FnOnce(Fd) -> Bcd,
+        Bcd: FnOnce(Fe) -> Bcde,
+        Fa: FnOnce(Bcde) -> Out
+    } => a(b(c)(d)(e));
+
+    /// B³ = Becard
+    ///
+    /// λabcd.a(b(cd))
+    B3<Cd, Bcd, Out>(a: Fa, b: Fb, c: Fc, d: Fd) -> Out
+    where {
+        Fc: FnOnce(Fd) -> Cd,
+        Fb: FnOnce(Cd) -> Bcd,
+        Fa: FnOnce(Bcd) -> Out
+    } => a(b(c(d)));
+
+    /// C = Cardinal
+    ///
+    /// λabc.acb
+    C<Ac, Out>(a: Fa, b: Fb, c: Fc) -> Out
+    where {
+        Fa: FnOnce(Fc) -> Ac,
+        Ac: FnOnce(Fb) -> Out
+    } => a(c)(b);
+
+    /// D = Dove
+    ///
+    /// λabcd.ab(cd)
+    D<Ab, Cd, Out>(a: Fa, b: Fb, c: Fc, d: Fd) -> Out
+    where {
+        Fa: FnOnce(Fb) -> Ab,
+        Fc: FnOnce(Fd) -> Cd,
+        Ab: FnOnce(Cd) -> Out
+    } => a(b)(c(d));
+
+    /// D¹ = Dickcissel
+    ///
+    /// λabcde.abc(de)
+    D1<Ab, Abc, De, Out>(a: Fa, b: Fb, c: Fc, d: Fd, e: Fe) -> Out
+    where {
+        Fa: FnOnce(Fb) -> Ab,
+        Ab: FnOnce(Fc) -> Abc,
+        Fd: FnOnce(Fe) -> De,
+        Abc: FnOnce(De) -> Out
+    } => a(b)(c)(d(e));
+
+    /// D² = Dovekies
+    ///
+    /// λabcde.a(bc)(de)
+    D2<Bc, De, Abc, Out>(a: Fa, b: Fb, c: Fc, d: Fd, e: Fe) -> Out
+    where {
+        Fb: FnOnce(Fc) -> Bc,
+        Fd: FnOnce(Fe) -> De,
+        Fa: FnOnce(Bc) -> Abc,
+        Abc: FnOnce(De) -> Out
+    } => a(b(c))(d(e));
+
+    /// E = Eagle
+    ///
+    /// λabcde.ab(cde)
+    E<Ab, Cd, Cde, Out>(a: Fa, b: Fb, c: Fc, d: Fd, e: Fe) -> Out
+    where {
+        Fa: FnOnce(Fb) -> Ab,
+        Fc: FnOnce(Fd) -> Cd,
+        Cd: FnOnce(Fe) -> Cde,
+        Ab: FnOnce(Cde) -> Out
+    } => a(b)(c(d)(e));
+
+    /// Ê = Bald Eagle
+    ///
+    /// λabcdefg.a(bcd)(efg)
+    Ê<Bc, Bcd, Ef, Efg, Abcd, Out>(a: Fa, b: Fb, c: Fc, d: Fd, e: Fe, f: Ff, g: Fg) -> Out
+    where {
+        Fb: FnOnce(Fc) -> Bc,
+        Bc: FnOnce(Fd) -> Bcd,
+        Fe: FnOnce(Ff) -> Ef,
+        Ef: FnOnce(Fg) -> Efg,
+        Fa: FnOnce(Bcd) -> Abcd,
+        Abcd: FnOnce(Efg) -> Out
+    } => a(b(c)(d))(e(f)(g));
+
+    /// F = Finch
+    ///
+    /// λabc.cba
+    F<Cb, Out>(a: Fa, b: Fb, c: Fc) -> Out
+    where {
+        Fc: FnOnce(Fb) -> Cb,
+        Cb: FnOnce(Fa) -> Out
+    } => c(b)(a);
+
+    /// G = Goldfinch
+    ///
+    /// λabcd.ad(bc)
+    G<Ad, Bc, Out>(a: Fa, b: Fb, c: Fc, d: Fd) -> Out
+    where {
+        Fa: FnOnce(Fd) -> Ad,
+        Fb: FnOnce(Fc) -> Bc,
+        Ad: FnOnce(Bc) -> Out
+    } => a(d)(b(c));
+
+    /// H = Hummingbird
+    ///
+    /// λabc.abcb
+    H<Ab, Abc, Out>(a: Fa, b: Fb, c: Fc) -> Out
+    where {
+        Fb: Clone,
+        Fa: FnOnce(Fb) -> Ab,
+        Ab: FnOnce(Fc) -> Abc,
+        Abc: FnOnce(Fb) -> Out
+    } => a(b.clone())(c)(b);
+
+    /// I = Identity Bird aka Idiot
+    ///
+    /// λa.a
+    I<>(a: Fa) -> Fa
+    where {} => a;
+
+    /// J = Jay
+    ///
+    /// λabcd.ab(adc)
+    J<Ab, Ad, Adc, Out>(a: Fa, b: Fb, c: Fc, d: Fd) -> Out
+    where {
+        Fa: Clone + FnOnce(Fb) -> Ab + FnOnce(Fd) -> Ad,
+        Ad: FnOnce(Fc) -> Adc,
+        Ab: FnOnce(Adc) -> Out
+    } => a.clone()(b)(a(d)(c));
+
+    /// K = Kestrel (True)
+    ///
+    /// λab.a
+    K<>(a: Fa, b: Fb) -> Fa
+    where {} => a;
+
+    /// L = Lark
+    ///
+    /// λab.a(bb)
+    L<Bb, Out>(a: Fa, b: Fb) -> Out
+    where {
+        Fb: Clone + FnOnce(Fb) -> Bb,
+        Fa: FnOnce(Bb) -> Out
+    } => a(b.clone()(b));
+
+    /// M = Mockingbird
+    ///
+    /// λa.aa
+    M<Out>(a: Fa) -> Out
+    where {
+        Fa: Clone + FnOnce(Fa) -> Out
+    } => a.clone()(a);
+
+    /// M² = Double Mockingbird
+    ///
+    /// λab.ab(ab)
+    M2<Ab, Out>(a: Fa, b: Fb) -> Out
+    where {
+        Fa: Clone + FnOnce(Fb) -> Ab,
+        Fb: Clone,
+        Ab: FnOnce(Ab) -> Out
+    } => a.clone()(b.clone())(a(b));
+
+    /// O = Owl
+    ///
+    /// λab.b(ab)
+    O<Ab, Out>(a: Fa, b: Fb) -> Out
+    where {
+        Fb: Clone + FnOnce(Ab) -> Out,
+        Fa: FnOnce(Fb) -> Ab
+    } => b.clone()(a(b));
+
+    /// Q = Queer Bird
+    ///
+    /// λabc.b(ac)
+    Q<Ac, Out>(a: Fa, b: Fb, c: Fc) -> Out
+    where {
+        Fa: FnOnce(Fc) -> Ac,
+        Fb: FnOnce(Ac) -> Out
+    } => b(a(c));
+
+    /// Q¹ = Quixotic Bird
+    ///
+    /// λabc.a(cb)
+    Q1<Cb, Out>(a: Fa, b: Fb, c: Fc) -> Out
+    where {
+        Fc: FnOnce(Fb) -> Cb,
+        Fa: FnOnce(Cb) -> Out
+    } => a(c(b));
+
+    /// Q² = Quizzical Bird
+    ///
+    /// λabc.b(ca)
+    Q2<Ca, Out>(a: Fa, b: Fb, c: Fc) -> Out
+    where {
+        Fc: FnOnce(Fa) -> Ca,
+        Fb: FnOnce(Ca) -> Out
+    } => b(c(a));
+
+    /// Q³ = Quirky Bird
+    ///
+    /// λabc.c(ab)
+    Q3<Ab, Out>(a: Fa, b: Fb, c: Fc) -> Out
+    where {
+        Fa: FnOnce(Fb) -> Ab,
+        Fc: FnOnce(Ab) -> Out
+    } => c(a(b));
+
+    /// Q⁴ = Quacky Bird
+    ///
+    /// λabc.c(ba)
+    Q4<Ba, Out>(a: Fa, b: Fb, c: Fc) -> Out
+    where {
+        Fb: FnOnce(Fa) -> Ba,
+        Fc: FnOnce(Ba) -> Out
+    } => c(b(a));
+
+    /// R = Robin
+    ///
+    /// λabc.bca
+    R<Bc, Out>(a: Fa, b: Fb, c: Fc) -> Out
+    where {
+        Fb: FnOnce(Fc) -> Bc,
+        Bc: FnOnce(Fa) -> Out
+    } => b(c)(a);
+
+    /// S = Starling
+    ///
+    /// λabc.ac(bc)
+    S<Ac, Bc, Out>(a: Fa, b: Fb, c: Fc) -> Out
+    where {
+        Fc: Clone,
+        Fa: FnOnce(Fc) -> Ac,
+        Fb: FnOnce(Fc) -> Bc,
+        Ac: FnOnce(Bc) -> Out
+    } => a(c.clone())(b(c));
+
+    /// T = Thrush
+    ///
+    /// λab.ba
+    T<Out>(a: Fa, b: Fb) -> Out
+    where {
+        Fb: FnOnce(Fa) -> Out
+    } => b(a);
+
+    /// U = Turing
+    ///
+    /// λab.b(aab)
+    U<Aa, Aab, Out>(a: Fa, b: Fb) -> Out
+    where {
+        Fa: Clone + FnOnce(Fa) -> Aa,
+        Aa: FnOnce(Fb) -> Aab,
+        Fb: Clone + FnOnce(Aab) -> Out
+    } => b.clone()(a.clone()(a)(b));
+
+    /// V = Vireo aka Pairing
+    ///
+    /// λabc.cab
+    V<Ca, Out>(a: Fa, b: Fb, c: Fc) -> Out
+    where {
+        Fc: FnOnce(Fa) -> Ca,
+        Ca: FnOnce(Fb) -> Out
+    } => c(a)(b);
+
+    /// W = Warbler
+    ///
+    /// λab.abb
+    W<Ab, Out>(a: Fa, b: Fb) -> Out
+    where {
+        Fb: Clone,
+        Fa: FnOnce(Fb) -> Ab,
+        Ab: FnOnce(Fb) -> Out
+    } => a(b.clone())(b);
+
+    /// W¹ = Converse Warbler
+    ///
+    /// λab.baa
+    W1<Ba, Out>(a: Fa, b: Fb) -> Out
+    where {
+        Fa: Clone,
+        Fb: FnOnce(Fa) -> Ba,
+        Ba: FnOnce(Fa) -> Out
+    } => b(a.clone())(a);
+
+    /// I* = Identity Bird Once Removed
+    ///
+    /// λab.ab
+    IStar<Out>(a: Fa, b: Fb) -> Out
+    where {
+        Fa: FnOnce(Fb) -> Out
+    } => a(b);
+
+    /// W* = Warbler Once Removed
+    ///
+    /// λabc.abcc
+    WStar<Ab, Abc, Out>(a: Fa, b: Fb, c: Fc) -> Out
+    where {
+        Fc: Clone,
+        Fa: FnOnce(Fb) -> Ab,
+        Ab: FnOnce(Fc) -> Abc,
+        Abc: FnOnce(Fc) -> Out
+    } => a(b)(c.clone())(c);
+
+    /// C* = Cardinal Once Removed
+    ///
+    /// λabcd.abdc
+    CStar<Ab, Abd, Out>(a: Fa, b: Fb, c: Fc, d: Fd) -> Out
+    where {
+        Fa: FnOnce(Fb) -> Ab,
+        Ab: FnOnce(Fd) -> Abd,
+        Abd: FnOnce(Fc) -> Out
+    } => a(b)(d)(c);
+
+    /// R* = Robin Once Removed
+    ///
+    /// λabcd.acdb
+    RStar<Ac, Acd, Out>(a: Fa, b: Fb, c: Fc, d: Fd) -> Out
+    where {
+        Fa: FnOnce(Fc) -> Ac,
+        Ac: FnOnce(Fd) -> Acd,
+        Acd: FnOnce(Fb) -> Out
+    } => a(c)(d)(b);
+
+    /// F* = Finch Once Removed
+    ///
+    /// λabcd.adcb
+    FStar<Ad, Adc, Out>(a: Fa, b: Fb, c: Fc, d: Fd) -> Out
+    where {
+        Fa: FnOnce(Fd) -> Ad,
+        Ad: FnOnce(Fc) -> Adc,
+        Adc: FnOnce(Fb) -> Out
+    } => a(d)(c)(b);
+
+    /// V* = Vireo Once Removed
+    ///
+    /// λabcd.acbd
+    VStar<Ac, Acb, Out>(a: Fa, b: Fb, c: Fc, d: Fd) -> Out
+    where {
+        Fa: FnOnce(Fc) -> Ac,
+        Ac: FnOnce(Fb) -> Acb,
+        Acb: FnOnce(Fd) -> Out
+    } => a(c)(b)(d);
+
+    /// I** = Identity Bird Twice Removed
+    ///
+    /// λabc.abc
+    IStarStar<Ab, Out>(a: Fa, b: Fb, c: Fc) -> Out
+    where {
+        Fa: FnOnce(Fb) -> Ab,
+        Ab: FnOnce(Fc) -> Out
+    } => a(b)(c);
+
+    /// W** = Warbler Twice Removed
+    ///
+    /// λabcd.abcdd
+    WStarStar<Ab, Abc, Abcd, Out>(a: Fa, b: Fb, c: Fc, d: Fd) -> Out
+    where {
+        Fd: Clone,
+        Fa: FnOnce(Fb) -> Ab,
+        Ab: FnOnce(Fc) -> Abc,
+        Abc: FnOnce(Fd) -> Abcd,
+        Abcd: FnOnce(Fd) -> Out
+    } => a(b)(c)(d.clone())(d);
+
+    /// C** = Cardinal Twice Removed
+    ///
+    /// λabcde.abced
+    CStarStar<Ab, Abc, Abce, Out>(a: Fa, b: Fb, c: Fc, d: Fd, e: Fe) -> Out
+    where {
+        Fa: FnOnce(Fb) -> Ab,
+        Ab: FnOnce(Fc) -> Abc,
+        Abc: FnOnce(Fe) -> Abce,
+        Abce: FnOnce(Fd) -> Out
+    } => a(b)(c)(e)(d);
+
+    /// R** = Robin Twice Removed
+    ///
+    /// λabcde.abdec
+    RStarStar<Ab, Abd, Abde, Out>(a: Fa, b: Fb, c: Fc, d: Fd, e: Fe) -> Out
+    where {
+        Fa: FnOnce(Fb) -> Ab,
+        Ab: FnOnce(Fd) -> Abd,
+        Abd: FnOnce(Fe) -> Abde,
+        Abde: FnOnce(Fc) -> Out
+    } => a(b)(d)(e)(c);
+
+    /// F** = Finch Twice Removed
+    ///
+    /// λabcde.abedc
+    FStarStar<Ab, Abe, Abed, Out>(a: Fa, b: Fb, c: Fc, d: Fd, e: Fe) -> Out
+    where {
+        Fa: FnOnce(Fb) -> Ab,
+        Ab: FnOnce(Fe) -> Abe,
+        Abe: FnOnce(Fd) -> Abed,
+        Abed: FnOnce(Fc) -> Out
+    } => a(b)(e)(d)(c);
+
+    /// V** = Vireo Twice Removed
+    ///
+    /// λabcde.abecd
+    VStarStar<Ab, Abe, Abec, Out>(a: Fa, b: Fb, c: Fc, d: Fd, e: Fe) -> Out
+    where {
+        Fa: FnOnce(Fb) -> Ab,
+        Ab: FnOnce(Fe) -> Abe,
+        Abe: FnOnce(Fc) -> Abec,
+        Abec: FnOnce(Fd) -> Out
+    } => a(b)(e)(c)(d);
+
+    /// KI = Kite (False)
+    ///
+    /// λab.b
+    KI<>(a: Fa, b: Fb) -> Fb
+    where {} => b;
+
+    /// KM = Constant Mocker
+    ///
+    /// λab.bb
+    KM<Out>(a: Fa, b: Fb) -> Out
+    where {
+        Fb: Clone + FnOnce(Fb) -> Out
+    } => b.clone()(b);
+
+    /// C(KM) = Crossed Constant Mocker
+    ///
+    /// λab.aa
+    CKM<Out>(a: Fa, b: Fb) -> Out
+    where {
+        Fa: Clone + FnOnce(Fa) -> Out
+    } => a.clone()(a);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blackbird()
+    {
+        let a = |x: f32| x.sqrt();
+        let b = |x: f32| move |y: f32| x + y/2.0;
+        let c = |x: u8| (x as f32).powi(2);
+        let d = |x: u8| (x as f32*2.0).powi(2);
+
+        // B¹ = BBB
+        let f = crate::b1(a, |x: f32, y: f32| b(x)(y), c, d);
+        let blackbird = B(B)(B);
+
+        for i in 0..=255
+        {
+            for j in 0..=255
+            {
+                assert_eq!(blackbird(a)(b)(c(i))(d(j)), f(i, j))
+            }
+        }
+    }
+
+    #[test]
+    fn test_dove()
+    {
+        let a = |x: f32| move |y: f32| (x + y/2.0).sqrt();
+        let b = |x: u8| (x as f32).powi(2);
+        let c = |x: f32| x*x;
+        let d = |x: u8| x as f32*2.0;
+
+        // D = BB
+        let f = crate::d(|x: f32, y: f32| a(x)(y), b, c, d);
+        let dove = B(B);
+
+        for i in 0..=255
+        {
+            for j in 0..=255
+            {
+                assert_eq!(dove(a)(b(i))(c)(d(j)), f(i, j))
+            }
+        }
+    }
+
+    #[test]
+    fn test_starling()
+    {
+        let a = |x: u8| move |y: f32| x as f32 + y;
+        let b = |x: u8| (x as f32).sqrt();
+
+        let f = crate::s(|x: u8, y: f32| a(x)(y), b, |x: u8| x);
+
+        for i in 0..=255
+        {
+            assert_eq!(S(a)(b)(i), f(i, i))
+        }
+
+        // SKK = I
+        for i in 0..=255
+        {
+            assert_eq!(S(K)(K)(i), I(i))
+        }
+    }
+
+    #[test]
+    fn test_identities()
+    {
+        let x = 1u8;
+        let y = 2u8;
+        let f = |x: u8| x + 10;
+        let g = |x: u8| move |y: u8| x*2 + y;
+
+        // KI = CK
+        assert_eq!(C(K)(x)(y), KI(x)(y));
+        // KI = KI
+        assert_eq!(K(I)(x)(y), KI(x)(y));
+        // I = WK
+        assert_eq!(W(K)(x), I(x));
+        // M = WI, applied to the Identity Bird
+        assert_eq!(W(I)(I)(x), M(I)(x));
+        // T = CI
+        assert_eq!(C(I)(x)(f), T(x)(f));
+        // V = BCT
+        assert_eq!(B(C)(T)(x)(y)(g), V(x)(y)(g));
+        // R = BBT
+        assert_eq!(B(B)(T)(x)(g)(y), R(x)(g)(y));
+        // W = C(BMR)
+        assert_eq!(C(B(M)(R))(g)(x), W(g)(x));
+    }
+}
@@ -0,0 +1,1187 @@
+//! Fallible birds
+//!
+//! The birds of the crate root feed the output of one function straight into the next.
+//! Here each function returns something like a [Result](Result) or an [Option](Option), and the birds compose them like `?` would:
+//! the first [Err](Err) or [None](None) is returned at once, and errors are converted with [From](From) into the error of the last function.
+//!
+//! A bird reads its λ-body by the [first of the possible interpretations](crate#interpretations).
+//! The arguments are evaluated from left to right, so the first of them to fail is the one returned.
+//!
+//! ```rust
+//! use birbs::fallible::try_b;
+//!
+//! #[derive(Debug, PartialEq)]
+//! enum Error
+//! {
+//!     Parse,
+//!     Overflow
+//! }
+//!
+//! impl From<std::num::ParseIntError> for Error
+//! {
+//!     fn from(_: std::num::ParseIntError) -> Self
+//!     {
+//!         Error::Parse
+//!     }
+//! }
+//!
+//! let a = |x: u8| x.checked_add(1).ok_or(Error::Overflow);
+//! let b = |x: u8| x.checked_mul(2).ok_or(Error::Overflow);
+//! let c = |s: &str| s.parse::<u8>();
+//!
+//! let f = try_b(a, b, c);
+//!
+//! assert_eq!(f("10"), Ok(21));
+//! assert_eq!(f("ten"), Err(Error::Parse));
+//! assert_eq!(f("200"), Err(Error::Overflow));
+//! ```
+//!
+//! Y, Θ and Ω are [missing](crate#interpretations), since they never return.
+
+use std::ops::{FromResidual, Try};
+
+/// B = Bluebird
+/// 
+/// λabc.a(bc)
+/// 
+/// returns a(b(c(x1)?)?)
+pub fn try_b<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1) -> R
+where
+    A: Fn(O2::Output) -> R,
+    B: Fn(O1::Output) -> O2,
+    C: Fn(X1) -> O1,
+    O1: Try,
+    O2: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual>
+{
+    move |x1| a(b(c(x1)?)?)
+}
+
+/// B¹ = Blackbird
+/// 
+/// λabcd.a(bcd)
+/// 
+/// returns a(b(c(x1)?, d(x2)?)?)
+pub fn try_b1<A, B, C, D, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(O3::Output) -> R,
+    B: Fn(O1::Output, O2::Output) -> O3,
+    C: Fn(X1) -> O1,
+    D: Fn(X2) -> O2,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual>
+{
+    move |x1, x2| a(b(c(x1)?, d(x2)?)?)
+}
+
+/// B² = Bunting
+/// 
+/// λabcde.a(bcde)
+/// 
+/// returns a(b(c(x1)?, d(x2)?, e(x3)?)?)
+pub fn try_b2<A, B, C, D, E, X1, X2, X3, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: Fn(O4::Output) -> R,
+    B: Fn(O1::Output, O2::Output, O3::Output) -> O4,
+    C: Fn(X1) -> O1,
+    D: Fn(X2) -> O2,
+    E: Fn(X3) -> O3,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    O4: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual> + FromResidual<O4::Residual>
+{
+    move |x1, x2, x3| a(b(c(x1)?, d(x2)?, e(x3)?)?)
+}
+
+/// B³ = Becard
+/// 
+/// λabcd.a(b(cd))
+/// 
+/// returns a(b(c(d(x1)?)?)?)
+pub fn try_b3<A, B, C, D, X1, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1) -> R
+where
+    A: Fn(O3::Output) -> R,
+    B: Fn(O2::Output) -> O3,
+    C: Fn(O1::Output) -> O2,
+    D: Fn(X1) -> O1,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual>
+{
+    move |x1| a(b(c(d(x1)?)?)?)
+}
+
+/// C = Cardinal
+/// 
+/// λabc.acb
+/// 
+/// returns a(c(x1)?, b(x2)?)
+pub fn try_c<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(O1::Output, O2::Output) -> R,
+    B: Fn(X2) -> O2,
+    C: Fn(X1) -> O1,
+    O1: Try,
+    O2: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual>
+{
+    move |x1, x2| a(c(x1)?, b(x2)?)
+}
+
+/// D = Dove
+/// 
+/// λabcd.ab(cd)
+/// 
+/// returns a(b(x1)?, c(d(x2)?)?)
+pub fn try_d<A, B, C, D, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(O1::Output, O3::Output) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(O2::Output) -> O3,
+    D: Fn(X2) -> O2,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual>
+{
+    move |x1, x2| a(b(x1)?, c(d(x2)?)?)
+}
+
+/// D¹ = Dickcissel
+/// 
+/// λabcde.abc(de)
+/// 
+/// returns a(b(x1)?, c(x2)?, d(e(x3)?)?)
+pub fn try_d1<A, B, C, D, E, X1, X2, X3, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: Fn(O1::Output, O2::Output, O4::Output) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X2) -> O2,
+    D: Fn(O3::Output) -> O4,
+    E: Fn(X3) -> O3,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    O4: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual> + FromResidual<O4::Residual>
+{
+    move |x1, x2, x3| a(b(x1)?, c(x2)?, d(e(x3)?)?)
+}
+
+/// D² = Dovekies
+/// 
+/// λabcde.a(bc)(de)
+/// 
+/// returns a(b(c(x1)?)?, d(e(x2)?)?)
+pub fn try_d2<A, B, C, D, E, X1, X2, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(O2::Output, O4::Output) -> R,
+    B: Fn(O1::Output) -> O2,
+    C: Fn(X1) -> O1,
+    D: Fn(O3::Output) -> O4,
+    E: Fn(X2) -> O3,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    O4: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual> + FromResidual<O4::Residual>
+{
+    move |x1, x2| a(b(c(x1)?)?, d(e(x2)?)?)
+}
+
+/// E = Eagle
+/// 
+/// λabcde.ab(cde)
+/// 
+/// returns a(b(x1)?, c(d(x2)?, e(x3)?)?)
+pub fn try_e<A, B, C, D, E, X1, X2, X3, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: Fn(O1::Output, O4::Output) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(O2::Output, O3::Output) -> O4,
+    D: Fn(X2) -> O2,
+    E: Fn(X3) -> O3,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    O4: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual> + FromResidual<O4::Residual>
+{
+    move |x1, x2, x3| a(b(x1)?, c(d(x2)?, e(x3)?)?)
+}
+
+/// Ê = Bald Eagle
+/// 
+/// λabcdefg.a(bcd)(efg)
+/// 
+/// returns a(b(c(x1)?, d(x2)?)?, e(f(x3)?, g(x4)?)?)
+pub fn try_ê<A, B, C, D, E, F, G, X1, X2, X3, X4, O1, O2, O3, O4, O5, O6, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E,
+    f: F,
+    g: G
+) -> impl Fn(X1, X2, X3, X4) -> R
+where
+    A: Fn(O3::Output, O6::Output) -> R,
+    B: Fn(O1::Output, O2::Output) -> O3,
+    C: Fn(X1) -> O1,
+    D: Fn(X2) -> O2,
+    E: Fn(O4::Output, O5::Output) -> O6,
+    F: Fn(X3) -> O4,
+    G: Fn(X4) -> O5,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    O4: Try,
+    O5: Try,
+    O6: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual> + FromResidual<O4::Residual> + FromResidual<O5::Residual> + FromResidual<O6::Residual>
+{
+    move |x1, x2, x3, x4| a(b(c(x1)?, d(x2)?)?, e(f(x3)?, g(x4)?)?)
+}
+
+/// F = Finch
+/// 
+/// λabc.cba
+/// 
+/// returns c(b(x1)?, a(x2)?)
+pub fn try_f<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(X2) -> O2,
+    B: Fn(X1) -> O1,
+    C: Fn(O1::Output, O2::Output) -> R,
+    O1: Try,
+    O2: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual>
+{
+    move |x1, x2| c(b(x1)?, a(x2)?)
+}
+
+/// G = Goldfinch
+/// 
+/// λabcd.ad(bc)
+/// 
+/// returns a(d(x1)?, b(c(x2)?)?)
+pub fn try_g<A, B, C, D, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(O1::Output, O3::Output) -> R,
+    B: Fn(O2::Output) -> O3,
+    C: Fn(X2) -> O2,
+    D: Fn(X1) -> O1,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual>
+{
+    move |x1, x2| a(d(x1)?, b(c(x2)?)?)
+}
+
+/// H = Hummingbird
+/// 
+/// λabc.abcb
+/// 
+/// returns a(b(x1)?, c(x2)?, b(x3)?)
+pub fn try_h<A, B, C, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: Fn(O1::Output, O2::Output, O3::Output) -> R,
+    B: Fn(X1) -> O1 + Fn(X3) -> O3,
+    C: Fn(X2) -> O2,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual>
+{
+    move |x1, x2, x3| a(b(x1)?, c(x2)?, b(x3)?)
+}
+
+/// I = Identity Bird aka Idiot
+/// 
+/// λa.a
+/// 
+/// returns a(x1)
+pub fn try_i<A, X1, R>(
+    a: A
+) -> impl Fn(X1) -> R
+where
+    A: Fn(X1) -> R
+{
+    move |x1| a(x1)
+}
+
+/// J = Jay
+/// 
+/// λabcd.ab(adc)
+/// 
+/// returns a(b(x1)?, a(d(x2)?, c(x3)?)?)
+pub fn try_j<A, B, C, D, X1, X2, X3, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: Fn(O2::Output, O3::Output) -> O4 + Fn(O1::Output, O4::Output) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X3) -> O3,
+    D: Fn(X2) -> O2,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    O4: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual> + FromResidual<O4::Residual>
+{
+    move |x1, x2, x3| a(b(x1)?, a(d(x2)?, c(x3)?)?)
+}
+
+/// K = Kestrel (True)
+/// 
+/// λab.a
+/// 
+/// returns a(x1)
+#[allow(unused)]
+pub fn try_k<A, B, X1, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1) -> R
+where
+    A: Fn(X1) -> R
+{
+    move |x1| a(x1)
+}
+
+/// L = Lark
+/// 
+/// λab.a(bb)
+/// 
+/// returns a(b(b(x1)?)?)
+pub fn try_l<A, B, X1, O1, O2, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1) -> R
+where
+    A: Fn(O2::Output) -> R,
+    B: Fn(X1) -> O1 + Fn(O1::Output) -> O2,
+    O1: Try,
+    O2: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual>
+{
+    move |x1| a(b(b(x1)?)?)
+}
+
+/// M = Mockingbird
+/// 
+/// λa.aa
+/// 
+/// returns a(a(x1)?)
+pub fn try_m<A, X1, O1, R>(
+    a: A
+) -> impl Fn(X1) -> R
+where
+    A: Fn(X1) -> O1 + Fn(O1::Output) -> R,
+    O1: Try,
+    R: FromResidual<O1::Residual>
+{
+    move |x1| a(a(x1)?)
+}
+
+/// M² = Double Mockingbird
+/// 
+/// λab.ab(ab)
+/// 
+/// returns a(b(x1)?, a(b(x2)?)?)
+pub fn try_m2<A, B, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(O2::Output) -> O3 + Fn(O1::Output, O3::Output) -> R,
+    B: Fn(X1) -> O1 + Fn(X2) -> O2,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual>
+{
+    move |x1, x2| a(b(x1)?, a(b(x2)?)?)
+}
+
+/// O = Owl
+/// 
+/// λab.b(ab)
+/// 
+/// returns b(a(b(x1)?)?)
+pub fn try_o<A, B, X1, O1, O2, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1) -> R
+where
+    A: Fn(O1::Output) -> O2,
+    B: Fn(X1) -> O1 + Fn(O2::Output) -> R,
+    O1: Try,
+    O2: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual>
+{
+    move |x1| b(a(b(x1)?)?)
+}
+
+/// Q = Queer Bird
+/// 
+/// λabc.b(ac)
+/// 
+/// returns b(a(c(x1)?)?)
+pub fn try_q<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1) -> R
+where
+    A: Fn(O1::Output) -> O2,
+    B: Fn(O2::Output) -> R,
+    C: Fn(X1) -> O1,
+    O1: Try,
+    O2: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual>
+{
+    move |x1| b(a(c(x1)?)?)
+}
+
+/// Q¹ = Quixotic Bird
+/// 
+/// λabc.a(cb)
+/// 
+/// returns a(c(b(x1)?)?)
+pub fn try_q1<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1) -> R
+where
+    A: Fn(O2::Output) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(O1::Output) -> O2,
+    O1: Try,
+    O2: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual>
+{
+    move |x1| a(c(b(x1)?)?)
+}
+
+/// Q² = Quizzical Bird
+/// 
+/// λabc.b(ca)
+/// 
+/// returns b(c(a(x1)?)?)
+pub fn try_q2<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1) -> R
+where
+    A: Fn(X1) -> O1,
+    B: Fn(O2::Output) -> R,
+    C: Fn(O1::Output) -> O2,
+    O1: Try,
+    O2: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual>
+{
+    move |x1| b(c(a(x1)?)?)
+}
+
+/// Q³ = Quirky Bird
+/// 
+/// λabc.c(ab)
+/// 
+/// returns c(a(b(x1)?)?)
+pub fn try_q3<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1) -> R
+where
+    A: Fn(O1::Output) -> O2,
+    B: Fn(X1) -> O1,
+    C: Fn(O2::Output) -> R,
+    O1: Try,
+    O2: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual>
+{
+    move |x1| c(a(b(x1)?)?)
+}
+
+/// Q⁴ = Quacky Bird
+/// 
+/// λabc.c(ba)
+/// 
+/// returns c(b(a(x1)?)?)
+pub fn try_q4<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1) -> R
+where
+    A: Fn(X1) -> O1,
+    B: Fn(O1::Output) -> O2,
+    C: Fn(O2::Output) -> R,
+    O1: Try,
+    O2: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual>
+{
+    move |x1| c(b(a(x1)?)?)
+}
+
+/// R = Robin
+/// 
+/// λabc.bca
+/// 
+/// returns b(c(x1)?, a(x2)?)
+pub fn try_r<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(X2) -> O2,
+    B: Fn(O1::Output, O2::Output) -> R,
+    C: Fn(X1) -> O1,
+    O1: Try,
+    O2: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual>
+{
+    move |x1, x2| b(c(x1)?, a(x2)?)
+}
+
+/// S = Starling
+/// 
+/// λabc.ac(bc)
+/// 
+/// returns a(c(x1)?, b(c(x2)?)?)
+pub fn try_s<A, B, C, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(O1::Output, O3::Output) -> R,
+    B: Fn(O2::Output) -> O3,
+    C: Fn(X1) -> O1 + Fn(X2) -> O2,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual>
+{
+    move |x1, x2| a(c(x1)?, b(c(x2)?)?)
+}
+
+/// T = Thrush
+/// 
+/// λab.ba
+/// 
+/// returns b(a(x1)?)
+pub fn try_t<A, B, X1, O1, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1) -> R
+where
+    A: Fn(X1) -> O1,
+    B: Fn(O1::Output) -> R,
+    O1: Try,
+    R: FromResidual<O1::Residual>
+{
+    move |x1| b(a(x1)?)
+}
+
+/// U = Turing
+/// 
+/// λab.b(aab)
+/// 
+/// returns b(a(a(x1)?, b(x2)?)?)
+pub fn try_u<A, B, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(X1) -> O1 + Fn(O1::Output, O2::Output) -> O3,
+    B: Fn(X2) -> O2 + Fn(O3::Output) -> R,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual>
+{
+    move |x1, x2| b(a(a(x1)?, b(x2)?)?)
+}
+
+/// V = Vireo aka Pairing
+/// 
+/// λabc.cab
+/// 
+/// returns c(a(x1)?, b(x2)?)
+pub fn try_v<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(X1) -> O1,
+    B: Fn(X2) -> O2,
+    C: Fn(O1::Output, O2::Output) -> R,
+    O1: Try,
+    O2: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual>
+{
+    move |x1, x2| c(a(x1)?, b(x2)?)
+}
+
+/// W = Warbler
+/// 
+/// λab.abb
+/// 
+/// returns a(b(x1)?, b(x2)?)
+pub fn try_w<A, B, X1, X2, O1, O2, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(O1::Output, O2::Output) -> R,
+    B: Fn(X1) -> O1 + Fn(X2) -> O2,
+    O1: Try,
+    O2: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual>
+{
+    move |x1, x2| a(b(x1)?, b(x2)?)
+}
+
+/// W¹ = Converse Warbler
+/// 
+/// λab.baa
+/// 
+/// returns b(a(x1)?, a(x2)?)
+pub fn try_w1<A, B, X1, X2, O1, O2, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(X1) -> O1 + Fn(X2) -> O2,
+    B: Fn(O1::Output, O2::Output) -> R,
+    O1: Try,
+    O2: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual>
+{
+    move |x1, x2| b(a(x1)?, a(x2)?)
+}
+
+/// I* = Identity Bird Once Removed
+/// 
+/// λab.ab
+/// 
+/// returns a(b(x1)?)
+pub fn try_i_star<A, B, X1, O1, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1) -> R
+where
+    A: Fn(O1::Output) -> R,
+    B: Fn(X1) -> O1,
+    O1: Try,
+    R: FromResidual<O1::Residual>
+{
+    move |x1| a(b(x1)?)
+}
+
+/// W* = Warbled Once Removed
+/// 
+/// λabc.abcc
+/// 
+/// returns a(b(x1)?, c(x2)?, c(x3)?)
+pub fn try_w_star<A, B, C, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: Fn(O1::Output, O2::Output, O3::Output) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X2) -> O2 + Fn(X3) -> O3,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual>
+{
+    move |x1, x2, x3| a(b(x1)?, c(x2)?, c(x3)?)
+}
+
+/// C* = Cardinal Once Removed
+/// 
+/// λabcd.abdc
+/// 
+/// returns a(b(x1)?, d(x2)?, c(x3)?)
+pub fn try_c_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: Fn(O1::Output, O2::Output, O3::Output) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X3) -> O3,
+    D: Fn(X2) -> O2,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual>
+{
+    move |x1, x2, x3| a(b(x1)?, d(x2)?, c(x3)?)
+}
+
+/// R* = Robin Once Removed
+/// 
+/// λabcd.acdb
+/// 
+/// returns a(c(x1)?, d(x2)?, b(x3)?)
+pub fn try_r_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: Fn(O1::Output, O2::Output, O3::Output) -> R,
+    B: Fn(X3) -> O3,
+    C: Fn(X1) -> O1,
+    D: Fn(X2) -> O2,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual>
+{
+    move |x1, x2, x3| a(c(x1)?, d(x2)?, b(x3)?)
+}
+
+/// F* = Finch Once Removed
+/// 
+/// λabcd.adcb
+/// 
+/// returns a(d(x1)?, c(x2)?, b(x3)?)
+pub fn try_f_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: Fn(O1::Output, O2::Output, O3::Output) -> R,
+    B: Fn(X3) -> O3,
+    C: Fn(X2) -> O2,
+    D: Fn(X1) -> O1,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual>
+{
+    move |x1, x2, x3| a(d(x1)?, c(x2)?, b(x3)?)
+}
+
+/// V* = Vireo Once Removed
+/// 
+/// λabcd.acbd
+/// 
+/// returns a(c(x1)?, b(x2)?, d(x3)?)
+pub fn try_v_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: Fn(O1::Output, O2::Output, O3::Output) -> R,
+    B: Fn(X2) -> O2,
+    C: Fn(X1) -> O1,
+    D: Fn(X3) -> O3,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual>
+{
+    move |x1, x2, x3| a(c(x1)?, b(x2)?, d(x3)?)
+}
+
+/// I** = Identity Bird Twice Removed
+/// 
+/// λabc.abc
+/// 
+/// returns a(b(x1)?, c(x2)?)
+pub fn try_i_star_star<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(O1::Output, O2::Output) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X2) -> O2,
+    O1: Try,
+    O2: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual>
+{
+    move |x1, x2| a(b(x1)?, c(x2)?)
+}
+
+/// W** = Warbler Twice Removed
+/// 
+/// λabcd.abcdd
+/// 
+/// returns a(b(x1)?, c(x2)?, d(x3)?, d(x4)?)
+pub fn try_w_star_star<A, B, C, D, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2, X3, X4) -> R
+where
+    A: Fn(O1::Output, O2::Output, O3::Output, O4::Output) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X2) -> O2,
+    D: Fn(X3) -> O3 + Fn(X4) -> O4,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    O4: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual> + FromResidual<O4::Residual>
+{
+    move |x1, x2, x3, x4| a(b(x1)?, c(x2)?, d(x3)?, d(x4)?)
+}
+
+/// C** = Cardinal Twice Removed
+/// 
+/// λabcde.abced
+/// 
+/// returns a(b(x1)?, c(x2)?, e(x3)?, d(x4)?)
+pub fn try_c_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl Fn(X1, X2, X3, X4) -> R
+where
+    A: Fn(O1::Output, O2::Output, O3::Output, O4::Output) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X2) -> O2,
+    D: Fn(X4) -> O4,
+    E: Fn(X3) -> O3,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    O4: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual> + FromResidual<O4::Residual>
+{
+    move |x1, x2, x3, x4| a(b(x1)?, c(x2)?, e(x3)?, d(x4)?)
+}
+
+/// R** = Robin Twice Removed
+/// 
+/// λabcde.abdec
+/// 
+/// returns a(b(x1)?, d(x2)?, e(x3)?, c(x4)?)
+pub fn try_r_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl Fn(X1, X2, X3, X4) -> R
+where
+    A: Fn(O1::Output, O2::Output, O3::Output, O4::Output) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X4) -> O4,
+    D: Fn(X2) -> O2,
+    E: Fn(X3) -> O3,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    O4: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual> + FromResidual<O4::Residual>
+{
+    move |x1, x2, x3, x4| a(b(x1)?, d(x2)?, e(x3)?, c(x4)?)
+}
+
+/// F** = Finch Twice Removed
+/// 
+/// λabcde.abedc
+/// 
+/// returns a(b(x1)?, e(x2)?, d(x3)?, c(x4)?)
+pub fn try_f_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl Fn(X1, X2, X3, X4) -> R
+where
+    A: Fn(O1::Output, O2::Output, O3::Output, O4::Output) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X4) -> O4,
+    D: Fn(X3) -> O3,
+    E: Fn(X2) -> O2,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    O4: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual> + FromResidual<O4::Residual>
+{
+    move |x1, x2, x3, x4| a(b(x1)?, e(x2)?, d(x3)?, c(x4)?)
+}
+
+/// V** = Vireo Twice Removed
+/// 
+/// λabcde.abecd
+/// 
+/// returns a(b(x1)?, e(x2)?, c(x3)?, d(x4)?)
+pub fn try_v_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl Fn(X1, X2, X3, X4) -> R
+where
+    A: Fn(O1::Output, O2::Output, O3::Output, O4::Output) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X3) -> O3,
+    D: Fn(X4) -> O4,
+    E: Fn(X2) -> O2,
+    O1: Try,
+    O2: Try,
+    O3: Try,
+    O4: Try,
+    R: FromResidual<O1::Residual> + FromResidual<O2::Residual> + FromResidual<O3::Residual> + FromResidual<O4::Residual>
+{
+    move |x1, x2, x3, x4| a(b(x1)?, e(x2)?, c(x3)?, d(x4)?)
+}
+
+/// KI = Kite (False)
+/// 
+/// λab.b
+/// 
+/// returns b(x1)
+#[allow(unused)]
+pub fn try_ki<A, B, X1, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1) -> R
+where
+    B: Fn(X1) -> R
+{
+    move |x1| b(x1)
+}
+
+/// KM = Constant Mocker
+/// 
+/// λab.bb
+/// 
+/// returns b(b(x1)?)
+#[allow(unused)]
+pub fn try_km<A, B, X1, O1, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1) -> R
+where
+    B: Fn(X1) -> O1 + Fn(O1::Output) -> R,
+    O1: Try,
+    R: FromResidual<O1::Residual>
+{
+    move |x1| b(b(x1)?)
+}
+
+/// C(KM) = Crossed Constant Mocker
+/// 
+/// λab.aa
+/// 
+/// returns a(a(x1)?)
+#[allow(unused)]
+pub fn try_ckm<A, B, X1, O1, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1) -> R
+where
+    A: Fn(X1) -> O1 + Fn(O1::Output) -> R,
+    O1: Try,
+    R: FromResidual<O1::Residual>
+{
+    move |x1| a(a(x1)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    /// A function which never fails, and writes down how it was applied
+    fn ok(name: &'static str) -> impl Fn(String) -> Option<String>
+    {
+        move |x| Some(format!("{name}({x})"))
+    }
+
+    /// A function of two arguments which never fails, and writes down how it was applied
+    fn ok2(name: &'static str) -> impl Fn(String, String) -> Option<String>
+    {
+        move |x, y| Some(format!("{name}({x}, {y})"))
+    }
+
+    /// The inputs x1, x2 and so on
+    fn x(n: u8) -> String
+    {
+        format!("x{n}")
+    }
+
+    #[test]
+    fn test_birds()
+    {
+        assert_eq!(try_b(ok("a"), ok("b"), ok("c"))(x(1)).unwrap(), "a(b(c(x1)))");
+        assert_eq!(try_c(ok2("a"), ok("b"), ok("c"))(x(1), x(2)).unwrap(), "a(c(x1), b(x2))");
+        assert_eq!(try_s(ok2("a"), ok("b"), ok("c"))(x(1), x(2)).unwrap(), "a(c(x1), b(c(x2)))");
+        assert_eq!(try_w(ok2("a"), ok("b"))(x(1), x(2)).unwrap(), "a(b(x1), b(x2))");
+        assert_eq!(try_m(ok("a"))(x(1)).unwrap(), "a(a(x1))");
+        assert_eq!(try_j(ok2("a"), ok("b"), ok("c"), ok("d"))(x(1), x(2), x(3)).unwrap(), "a(b(x1), a(d(x2), c(x3)))");
+        assert_eq!(
+            try_ê(ok2("a"), ok2("b"), ok("c"), ok("d"), ok2("e"), ok("f"), ok("g"))(x(1), x(2), x(3), x(4)).unwrap(),
+            "a(b(c(x1), d(x2)), e(f(x3), g(x4)))"
+        );
+        assert_eq!(try_k(ok("a"), ok("b"))(x(1)).unwrap(), "a(x1)");
+        assert_eq!(try_ki(ok("a"), ok("b"))(x(1)).unwrap(), "b(x1)");
+    }
+
+    #[test]
+    fn test_distinct()
+    {
+        // read as chains of unary functions, these three would all be a(b(c(d(x))))
+        assert_eq!(try_b1(ok("a"), ok2("b"), ok("c"), ok("d"))(x(1), x(2)).unwrap(), "a(b(c(x1), d(x2)))");
+        assert_eq!(try_b3(ok("a"), ok("b"), ok("c"), ok("d"))(x(1)).unwrap(), "a(b(c(d(x1))))");
+        assert_eq!(try_d(ok2("a"), ok("b"), ok("c"), ok("d"))(x(1), x(2)).unwrap(), "a(b(x1), c(d(x2)))");
+    }
+
+    #[test]
+    fn test_short_circuit()
+    {
+        let calls = Cell::new(0);
+        let a = |x: u8, y: u8| {
+            calls.set(calls.get() + 1);
+            Some(x/2 + y/2)
+        };
+        let b = |x: u8| x.checked_sub(10);
+        let c = |x: u8| x.checked_mul(2);
+
+        let f = try_s(a, b, c);
+
+        let mut succeeded = 0;
+        for i in 0..=255
+        {
+            for j in 0..=255
+            {
+                let expected = c(i).zip(c(j).and_then(b))
+                    .map(|(x, y)| x/2 + y/2);
+                succeeded += expected.is_some() as usize;
+                assert_eq!(f(i, j), expected)
+            }
+        }
+        // a only runs if everything before it succeeded
+        assert_eq!(calls.get(), succeeded);
+    }
+
+    #[test]
+    fn test_errors()
+    {
+        #[derive(Debug, PartialEq)]
+        enum Error
+        {
+            Parse(std::num::ParseIntError),
+            Negative(i8)
+        }
+
+        impl From<std::num::ParseIntError> for Error
+        {
+            fn from(error: std::num::ParseIntError) -> Self
+            {
+                Error::Parse(error)
+            }
+        }
+
+        let parse = |s: &str| s.parse::<i8>();
+        let positive = |x: i8| if x < 0 {Err(Error::Negative(x))} else {Ok(x as u8)};
+        let double = |x: u8| Ok::<_, Error>(x as u16*2);
+
+        let f = try_b(double, positive, parse);
+
+        assert_eq!(f("100"), Ok(200));
+        assert_eq!(f("-1"), Err(Error::Negative(-1)));
+        assert!(matches!(f("one"), Err(Error::Parse(_))));
+    }
+}
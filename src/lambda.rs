@@ -0,0 +1,394 @@
+//! λ-terms
+//!
+//! Every bird in the crate root documents its definition as a λ-term, such as `λabc.ac(bc)` for the Starling.
+//! Here those definitions can be parsed and β-reduced.
+//!
+//! ```rust
+//! use birbs::lambda::Lambda;
+//!
+//! let s = Lambda::parse("λabc.ac(bc)").unwrap();
+//! let k = Lambda::parse("λab.a").unwrap();
+//!
+//! // SKK = I
+//! let skk = s.app(k.clone()).app(k);
+//!
+//! assert!(skk.normalize().alpha_eq(&Lambda::parse("λa.a").unwrap()));
+//! ```
+
+use std::collections::BTreeSet;
+
+use crate::term::Term;
+
+mod compile;
+mod machine;
+mod nbe;
+mod parse;
+
+pub use compile::*;
+pub use machine::*;
+pub use nbe::*;
+pub use parse::*;
+
+/// An untyped λ-term
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Lambda
+{
+    /// A variable
+    Var(String),
+    /// Abstraction of a variable over a body (λx.body)
+    Abs(String, Box<Lambda>),
+    /// Application of a term to another
+    App(Box<Lambda>, Box<Lambda>)
+}
+
+impl Lambda
+{
+    /// A variable
+    pub fn var(name: impl Into<String>) -> Lambda
+    {
+        Lambda::Var(name.into())
+    }
+
+    /// Abstracts a variable over a body
+    ///
+    /// returns λvar.body
+    pub fn abs(var: impl Into<String>, body: Lambda) -> Lambda
+    {
+        Lambda::Abs(var.into(), Box::new(body))
+    }
+
+    /// Applies the term to an argument
+    ///
+    /// returns (self arg)
+    pub fn app(self, arg: Lambda) -> Lambda
+    {
+        Lambda::App(Box::new(self), Box::new(arg))
+    }
+
+    /// Applies the term to several arguments, from left to right
+    ///
+    /// returns (self arg1 arg2 ...)
+    pub fn apply(self, args: impl IntoIterator<Item = Lambda>) -> Lambda
+    {
+        args.into_iter()
+            .fold(self, Lambda::app)
+    }
+
+    /// The number of variables, abstractions and applications in the term
+    pub fn size(&self) -> usize
+    {
+        match self
+        {
+            Lambda::Var(_) => 1,
+            Lambda::Abs(_, body) => 1 + body.size(),
+            Lambda::App(fun, arg) => 1 + fun.size() + arg.size()
+        }
+    }
+
+    /// The variables occurring free in the term
+    pub fn free_vars(&self) -> BTreeSet<&str>
+    {
+        match self
+        {
+            Lambda::Var(name) => BTreeSet::from([name.as_str()]),
+            Lambda::Abs(var, body) => {
+                let mut free = body.free_vars();
+                free.remove(var.as_str());
+                free
+            },
+            Lambda::App(fun, arg) => {
+                let mut free = fun.free_vars();
+                free.extend(arg.free_vars());
+                free
+            }
+        }
+    }
+
+    /// Whether the variable occurs free in the term
+    pub fn is_free(&self, var: &str) -> bool
+    {
+        match self
+        {
+            Lambda::Var(name) => name == var,
+            Lambda::Abs(bound, body) => bound != var && body.is_free(var),
+            Lambda::App(fun, arg) => fun.is_free(var) || arg.is_free(var)
+        }
+    }
+
+    /// Capture-avoiding substitution
+    ///
+    /// returns self[var := value]
+    ///
+    /// Abstractions which would capture a free variable of the value are renamed.
+    pub fn subst(&self, var: &str, value: &Lambda) -> Lambda
+    {
+        match self
+        {
+            Lambda::Var(name) => if name == var
+            {
+                value.clone()
+            }
+            else
+            {
+                self.clone()
+            },
+            Lambda::Abs(bound, body) => {
+                if bound == var || !body.is_free(var)
+                {
+                    return self.clone()
+                }
+                if !value.is_free(bound)
+                {
+                    return Lambda::abs(bound.clone(), body.subst(var, value))
+                }
+                let fresh = fresh(bound, |name| name == var || value.is_free(name) || body.is_free(name));
+                let body = body.subst(bound, &Lambda::var(fresh.clone()));
+                Lambda::abs(fresh, body.subst(var, value))
+            },
+            Lambda::App(fun, arg) => fun.subst(var, value).app(arg.subst(var, value))
+        }
+    }
+
+    /// α-equivalence, i.e. equality up to renaming of bound variables
+    ///
+    /// λab.a and λxy.x are α-equivalent, while λab.a and λab.b are not.
+    pub fn alpha_eq(&self, other: &Lambda) -> bool
+    {
+        fn alpha_eq<'a>(lhs: &'a Lambda, rhs: &'a Lambda, bound: &mut Vec<(&'a str, &'a str)>) -> bool
+        {
+            match (lhs, rhs)
+            {
+                (Lambda::Var(lhs), Lambda::Var(rhs)) => {
+                    let lhs_index = bound.iter().rposition(|&(var, _)| var == lhs);
+                    let rhs_index = bound.iter().rposition(|&(_, var)| var == rhs);
+                    match (lhs_index, rhs_index)
+                    {
+                        (None, None) => lhs == rhs,
+                        (lhs, rhs) => lhs == rhs
+                    }
+                },
+                (Lambda::Abs(lhs_var, lhs_body), Lambda::Abs(rhs_var, rhs_body)) => {
+                    bound.push((lhs_var, rhs_var));
+                    let eq = alpha_eq(lhs_body, rhs_body, bound);
+                    bound.pop();
+                    eq
+                },
+                (Lambda::App(lhs_fun, lhs_arg), Lambda::App(rhs_fun, rhs_arg)) => {
+                    alpha_eq(lhs_fun, rhs_fun, bound) && alpha_eq(lhs_arg, rhs_arg, bound)
+                },
+                _ => false
+            }
+        }
+
+        alpha_eq(self, other, &mut vec![])
+    }
+
+    /// Performs a single leftmost-outermost β-reduction
+    ///
+    /// Returns [None](None) if the term is in β-normal form.
+    pub fn step(&self) -> Option<Lambda>
+    {
+        match self
+        {
+            Lambda::Var(_) => None,
+            Lambda::Abs(var, body) => body.step()
+                .map(|body| Lambda::abs(var.clone(), body)),
+            Lambda::App(fun, arg) => {
+                if let Lambda::Abs(var, body) = &**fun
+                {
+                    return Some(body.subst(var, arg))
+                }
+                if let Some(fun) = fun.step()
+                {
+                    return Some(fun.app((**arg).clone()))
+                }
+                arg.step()
+                    .map(|arg| (**fun).clone().app(arg))
+            }
+        }
+    }
+
+    /// β-reduces the term until it is in β-normal form
+    ///
+    /// Terms without a normal form, such as (λa.aa)(λa.aa), never stop reducing.
+    pub fn normalize(self) -> Lambda
+    {
+        let mut term = self;
+        while let Some(next) = term.step()
+        {
+            term = next
+        }
+        term
+    }
+}
+
+/// Finds a variant of the name which is not taken, by appending a number to it.
+pub(crate) fn fresh(name: &str, taken: impl Fn(&str) -> bool) -> String
+{
+    let base = name.trim_end_matches(|c: char| c.is_ascii_digit());
+    (1..)
+        .map(|i| format!("{base}{i}"))
+        .find(|name| !taken(name))
+        .unwrap()
+}
+
+impl std::fmt::Display for Lambda
+{
+    /// Writes the term in the compact notation (e.g. `λabc.ac(bc)`) if every variable is a single letter.
+    ///
+    /// Otherwise it is written with multi-character variables (e.g. `\x y. x (y z)`).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let mut out = String::new();
+        self.render(&mut out, !self.names().all(is_compact));
+        f.write_str(&out)
+    }
+}
+
+impl Lambda
+{
+    /// Every variable name in the term, bound or free
+    fn names(&self) -> Box<dyn Iterator<Item = &str> + '_>
+    {
+        match self
+        {
+            Lambda::Var(name) => Box::new(std::iter::once(name.as_str())),
+            Lambda::Abs(var, body) => Box::new(std::iter::once(var.as_str()).chain(body.names())),
+            Lambda::App(fun, arg) => Box::new(fun.names().chain(arg.names()))
+        }
+    }
+
+    fn render(&self, out: &mut String, words: bool)
+    {
+        match self
+        {
+            Lambda::Var(name) => out.push_str(name),
+            Lambda::Abs(var, body) => {
+                out.push(if words {'\\'} else {'λ'});
+                out.push_str(var);
+                let mut body = &**body;
+                while let Lambda::Abs(var, next) = body
+                {
+                    if words
+                    {
+                        out.push(' ')
+                    }
+                    out.push_str(var);
+                    body = next
+                }
+                out.push('.');
+                if words
+                {
+                    out.push(' ')
+                }
+                body.render(out, words)
+            },
+            Lambda::App(fun, arg) => {
+                if let Lambda::Abs(..) = **fun
+                {
+                    out.push('(');
+                    fun.render(out, words);
+                    out.push(')')
+                }
+                else
+                {
+                    fun.render(out, words)
+                }
+                if words
+                {
+                    out.push(' ')
+                }
+                if let Lambda::Var(..) = **arg
+                {
+                    arg.render(out, words)
+                }
+                else
+                {
+                    out.push('(');
+                    arg.render(out, words);
+                    out.push(')')
+                }
+            }
+        }
+    }
+}
+
+impl From<&Term> for Lambda
+{
+    /// Replaces every bird in the term with its λ-definition
+    fn from(term: &Term) -> Self
+    {
+        match term
+        {
+            Term::Var(name) => Lambda::var(name.clone()),
+            Term::Bird(bird) => Lambda::parse(bird.lambda())
+                .expect("the λ-definitions of the birds are well-formed"),
+            Term::App(fun, arg) => Lambda::from(&**fun).app(Lambda::from(&**arg))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::term::Bird;
+
+    use super::*;
+
+    #[test]
+    fn test_subst()
+    {
+        let term = Lambda::parse("λa.ab").unwrap();
+
+        assert_eq!(term.subst("b", &Lambda::var("c")), Lambda::parse("λa.ac").unwrap());
+        assert_eq!(term.subst("a", &Lambda::var("c")), term);
+        // a would be captured, so the abstraction is renamed
+        assert_eq!(term.subst("b", &Lambda::var("a")), Lambda::parse("λa1.a1a").unwrap());
+    }
+
+    #[test]
+    fn test_alpha_eq()
+    {
+        let parse = |source| Lambda::parse(source).unwrap();
+
+        assert!(parse("λab.a").alpha_eq(&parse("λxy.x")));
+        assert!(!parse("λab.a").alpha_eq(&parse("λab.b")));
+        assert!(parse("λa.ab").alpha_eq(&parse("λc.cb")));
+        assert!(!parse("λa.ab").alpha_eq(&parse("λb.bb")));
+        assert!(parse("λaa.a").alpha_eq(&parse("λab.b")));
+    }
+
+    #[test]
+    fn test_normalize()
+    {
+        let parse = |source| Lambda::parse(source).unwrap();
+
+        // two plus two
+        let plus = parse("λmnfx.mf(nfx)");
+        let two = parse("λfx.f(fx)");
+        let four = parse("λfx.f(f(f(fx)))");
+        assert!(plus.apply([two.clone(), two]).normalize().alpha_eq(&four));
+
+        // reduces under abstractions, without capturing
+        assert!(parse("λb.(λab.a)b").normalize().alpha_eq(&parse("λbc.b")));
+    }
+
+    #[test]
+    fn test_birds()
+    {
+        for &bird in Bird::ALL
+        {
+            if matches!(bird, Bird::Y | Bird::Ω | Bird::Θ)
+            {
+                continue
+            }
+            let args: Vec<_> = ["x", "y", "z", "w", "v", "u", "t"][..bird.arity()].iter()
+                .map(|&name| Term::var(name))
+                .collect();
+
+            let rule = Lambda::from(&bird.rewrite(args.clone()));
+            let lambda = Lambda::from(&Term::Bird(bird).apply(args)).normalize();
+
+            assert!(lambda.alpha_eq(&rule), "{bird}: {lambda} ≠ {rule}")
+        }
+    }
+}
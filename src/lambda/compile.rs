@@ -0,0 +1,503 @@
+use crate::term::{Bird, Term};
+
+use super::Lambda;
+
+/// A bracket abstraction algorithm, for compiling λ-terms to combinator terms
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Algorithm
+{
+    /// Schönfinkel's abstraction, using only S, K and I
+    ///
+    /// * \[x\]x = I
+    /// * \[x\]M = KM, if x is not free in M
+    /// * \[x\]MN = S(\[x\]M)(\[x\]N)
+    ///
+    /// The size of the result grows quickly with the depth of nested abstractions.
+    Naive,
+    /// Turner's abstraction, which optimizes Schönfinkel's abstraction with B, C, the Becard (Turner's B*), S' and C'
+    ///
+    /// * S(Kp)(Kq) = K(pq)
+    /// * S(Kp)I = p
+    /// * S(Kp)(Bqr) = B³pqr
+    /// * S(Kp)q = Bpq
+    /// * S(Bpq)(Kr) = C'pqr
+    /// * Sp(Kq) = Cpq
+    /// * S(Bpq)r = S'pqr
+    ///
+    /// S' and C' are not birds of the aviary, so they are written as B(BS)B and B(BC)B.
+    /// Each rule is only used if the basis has its birds.
+    Turner,
+    /// Kiselyov's linear abstraction, which compiles from de Bruijn indices with bulk combinators
+    ///
+    /// * Bₙfgx₁..xₙ = f(gx₁..xₙ)
+    /// * Cₙfgx₁..xₙ = fx₁..xₙg
+    /// * Sₙfgx₁..xₙ = fx₁..xₙ(gx₁..xₙ)
+    ///
+    /// Each term is compiled to a combinator taking the innermost variables of the context it uses,
+    /// and an application passes those variables to both sides with a bulk combinator.
+    /// The bulk combinators are not birds of the aviary, so they are written as Bₙ₊₁ = BBBₙ, Cₙ₊₁ = BC(BCₙ) and Sₙ₊₁ = BS(BSₙ),
+    /// which makes Bₙ, Cₙ and Sₙ grow with n.
+    /// Counting each of them once, the size of the result is linear in the size of the term with unary de Bruijn indices.
+    Kiselyov,
+    /// Kiselyov's abstraction without bulk combinators, which η-reduces along the way
+    ///
+    /// The result has no linear bound, but it is usually smaller than with Turner's abstraction or the linear abstraction.
+    KiselyovEta
+}
+
+/// The birds a λ-term may be compiled to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Basis
+{
+    /// S, K and I
+    Ski,
+    /// B, C, K and W
+    Bckw,
+    /// Any bird of the aviary
+    Aviary
+}
+
+impl Basis
+{
+    /// The birds of the basis, or [None](None) if any bird may be used
+    pub const fn birds(self) -> Option<&'static [Bird]>
+    {
+        match self
+        {
+            Basis::Ski => Some(&[Bird::S, Bird::K, Bird::I]),
+            Basis::Bckw => Some(&[Bird::B, Bird::C, Bird::K, Bird::W]),
+            Basis::Aviary => None
+        }
+    }
+
+    /// Whether the bird is in the basis
+    pub fn contains(self, bird: Bird) -> bool
+    {
+        match self.birds()
+        {
+            Some(birds) => birds.contains(&bird),
+            None => true
+        }
+    }
+
+    /// Expresses the bird in terms of the birds of the basis
+    ///
+    /// ```rust
+    /// use birbs::term::{Bird, Term};
+    /// use birbs::lambda::Basis;
+    ///
+    /// assert_eq!(Basis::Ski.express(Bird::B).to_string(), "S(KS)K");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the bird is not one of S, K, I, B, C, T, M or W.
+    pub fn express(self, bird: Bird) -> Term
+    {
+        if self.contains(bird)
+        {
+            return Term::Bird(bird)
+        }
+        let source = match (self, bird)
+        {
+            (Basis::Ski, Bird::B) => "S(KS)K",
+            (Basis::Ski, Bird::C) => "S(S(K(S(KS)K))S)(KK)",
+            (Basis::Ski, Bird::T) => "S(K(SI))K",
+            (Basis::Ski, Bird::M) => "SII",
+            (Basis::Ski, Bird::W) => "SS(K I)",
+            (Basis::Bckw, Bird::S) => "B(BW)(BBC)",
+            (Basis::Bckw, Bird::I) => "WK",
+            (Basis::Bckw, Bird::T) => "C(WK)",
+            (Basis::Bckw, Bird::M) => "W(WK)",
+            _ => panic!("{bird} is not expressed in {self:?}")
+        };
+        Term::parse(source)
+            .expect("the expressions of the birds are well-formed")
+    }
+}
+
+impl Lambda
+{
+    /// Compiles the λ-term to a combinator term, by bracket abstraction
+    ///
+    /// Free variables are left as variables of the combinator term.
+    /// The algorithms may be compared by the [size](Term::size) of their results.
+    ///
+    /// ```rust
+    /// use birbs::term::Term;
+    /// use birbs::lambda::{Algorithm, Basis, Lambda};
+    ///
+    /// let s = Lambda::parse("λabc.ac(bc)").unwrap();
+    ///
+    /// let naive = s.compile(Algorithm::Naive, Basis::Ski);
+    /// let turner = s.compile(Algorithm::Turner, Basis::Aviary);
+    /// let kiselyov = s.compile(Algorithm::KiselyovEta, Basis::Aviary);
+    ///
+    /// assert_eq!(turner, Term::parse("S").unwrap());
+    /// assert_eq!(kiselyov, Term::parse("S").unwrap());
+    /// assert_eq!(naive.size(), 31);
+    /// ```
+    pub fn compile(&self, algorithm: Algorithm, basis: Basis) -> Term
+    {
+        match algorithm
+        {
+            Algorithm::Naive | Algorithm::Turner => self.abstract_vars(algorithm, basis),
+            Algorithm::Kiselyov => match linear(self, &mut vec![], basis)
+            {
+                (0, term) => term,
+                _ => unreachable!("a term without context takes no variables")
+            },
+            Algorithm::KiselyovEta => match kiselyov(self, &mut vec![], basis)
+            {
+                Sem::Closed(term) => term,
+                _ => unreachable!("a term without context is closed")
+            }
+        }
+    }
+
+    fn abstract_vars(&self, algorithm: Algorithm, basis: Basis) -> Term
+    {
+        match self
+        {
+            Lambda::Var(name) => Term::var(name.clone()),
+            Lambda::Abs(var, body) => abstract_var(var, body.abstract_vars(algorithm, basis), algorithm, basis),
+            Lambda::App(fun, arg) => fun.abstract_vars(algorithm, basis).app(arg.abstract_vars(algorithm, basis))
+        }
+    }
+}
+
+/// \[var\]term
+fn abstract_var(var: &str, term: Term, algorithm: Algorithm, basis: Basis) -> Term
+{
+    if !term.is_free(var)
+    {
+        return basis.express(Bird::K).app(term)
+    }
+    match term
+    {
+        Term::Var(_) => basis.express(Bird::I),
+        Term::Bird(_) => unreachable!("a bird has no free variables"),
+        Term::App(fun, arg) => {
+            let fun = abstract_var(var, *fun, algorithm, basis);
+            let arg = abstract_var(var, *arg, algorithm, basis);
+            match algorithm
+            {
+                Algorithm::Turner => turner(fun, arg, basis),
+                _ => basis.express(Bird::S).apply([fun, arg])
+            }
+        }
+    }
+}
+
+/// Turner's optimizations of S applied to the two terms
+fn turner(fun: Term, arg: Term, basis: Basis) -> Term
+{
+    let k = basis.express(Bird::K);
+    let constant = |term: &Term| match term
+    {
+        Term::App(head, term) if **head == k => Some((**term).clone()),
+        _ => None
+    };
+    match (constant(&fun), constant(&arg))
+    {
+        (Some(p), Some(q)) => return k.app(p.app(q)),
+        (Some(p), None) if arg == basis.express(Bird::I) => return p,
+        (Some(p), None) if basis.contains(Bird::B3) => {
+            let (head, args) = arg.spine();
+            if let (Term::Bird(Bird::B), &[q, r]) = (head, args.as_slice())
+            {
+                return Term::Bird(Bird::B3).apply([p, q.clone(), r.clone()])
+            }
+        },
+        _ => ()
+    }
+    // B p q, if the basis has B
+    let composed = match fun.spine()
+    {
+        (Term::Bird(Bird::B), args) if args.len() == 2 && basis.contains(Bird::B) => Some((args[0].clone(), args[1].clone())),
+        _ => None
+    };
+    match (constant(&fun), constant(&arg), composed)
+    {
+        (Some(p), _, _) if basis.contains(Bird::B) => Term::Bird(Bird::B).apply([p, arg]),
+        (_, Some(r), Some((p, q))) if basis.contains(Bird::C) => prime(Bird::C, basis).apply([p, q, r]),
+        (_, Some(q), _) if basis.contains(Bird::C) => Term::Bird(Bird::C).apply([fun, q]),
+        (_, _, Some((p, q))) => prime(Bird::S, basis).apply([p, q, arg]),
+        _ => basis.express(Bird::S).apply([fun, arg])
+    }
+}
+
+/// Turner's S' = B(BS)B or C' = B(BC)B, which pass the argument to p through q
+///
+/// * S'pqrx = p(qx)(rx)
+/// * C'pqrx = p(qx)r
+fn prime(bird: Bird, basis: Basis) -> Term
+{
+    let b = Term::Bird(Bird::B);
+    b.clone().apply([b.clone().app(basis.express(bird)), b])
+}
+
+/// Compiles the term to a combinator taking the given number of innermost variables of the context
+fn linear<'a>(term: &'a Lambda, context: &mut Vec<&'a str>, basis: Basis) -> (usize, Term)
+{
+    match term
+    {
+        Lambda::Var(name) => match context.iter().rev().position(|var| var == name)
+        {
+            // λx₀..xᵢ.x₀ = BK(..(BKK))
+            Some(0) => (1, basis.express(Bird::I)),
+            Some(index) => {
+                let k = basis.express(Bird::K);
+                let term = (1..index).fold(k.clone(), |term, _| basis.express(Bird::B).apply([k.clone(), term]));
+                (index + 1, term)
+            },
+            None => (0, Term::var(name.clone()))
+        },
+        Lambda::Abs(var, body) => {
+            context.push(var);
+            let body = linear(body, context, basis);
+            context.pop();
+            match body
+            {
+                (0, term) => (0, basis.express(Bird::K).app(term)),
+                (vars, term) => (vars - 1, term)
+            }
+        },
+        Lambda::App(fun, arg) => {
+            let (n, fun) = linear(fun, context, basis);
+            let (m, arg) = linear(arg, context, basis);
+            let term = match (n, m)
+            {
+                (0, 0) => fun.app(arg),
+                (0, m) => bulk(Bird::B, m, basis).apply([fun, arg]),
+                (n, 0) => bulk(Bird::C, n, basis).apply([fun, arg]),
+                (n, m) if n == m => bulk(Bird::S, n, basis).apply([fun, arg]),
+                (n, m) if n < m => bulk(Bird::B, m - n, basis).apply([bulk(Bird::S, n, basis).app(fun), arg]),
+                (n, m) => {
+                    let fun = bulk(Bird::B, n - m, basis).apply([bulk(Bird::S, m, basis), fun]);
+                    bulk(Bird::C, n - m, basis).apply([fun, arg])
+                }
+            };
+            (n.max(m), term)
+        }
+    }
+}
+
+/// The bulk combinator Bₙ, Cₙ or Sₙ
+///
+/// * Bₙ₊₁ = BBBₙ
+/// * Cₙ₊₁ = BC(BCₙ)
+/// * Sₙ₊₁ = BS(BSₙ)
+fn bulk(bird: Bird, n: usize, basis: Basis) -> Term
+{
+    let b = basis.express(Bird::B);
+    let one = basis.express(bird);
+    (1..n).fold(one.clone(), |term, _| match bird
+    {
+        Bird::B => b.clone().apply([b.clone(), term]),
+        _ => b.clone().apply([one.clone(), b.clone().app(term)])
+    })
+}
+
+/// A combinator term, compiled in a context of bound variables
+enum Sem
+{
+    /// A term which does not depend on the context
+    Closed(Term),
+    /// A term which is the innermost variable of the context
+    Var,
+    /// A term which applies a term in the rest of the context to the innermost variable
+    Need(Box<Sem>),
+    /// A term which ignores the innermost variable, being a term in the rest of the context
+    Weak(Box<Sem>)
+}
+
+fn kiselyov<'a>(term: &'a Lambda, context: &mut Vec<&'a str>, basis: Basis) -> Sem
+{
+    match term
+    {
+        Lambda::Var(name) => match context.iter().rev().position(|var| var == name)
+        {
+            Some(index) => (0..index).fold(Sem::Var, |sem, _| Sem::Weak(Box::new(sem))),
+            None => Sem::Closed(Term::var(name.clone()))
+        },
+        Lambda::Abs(var, body) => {
+            context.push(var);
+            let body = kiselyov(body, context, basis);
+            context.pop();
+            match body
+            {
+                Sem::Closed(term) => Sem::Closed(basis.express(Bird::K).app(term)),
+                Sem::Var => Sem::Closed(basis.express(Bird::I)),
+                Sem::Need(sem) => *sem,
+                Sem::Weak(sem) => combine(Sem::Closed(basis.express(Bird::K)), *sem, basis)
+            }
+        },
+        Lambda::App(fun, arg) => {
+            let fun = kiselyov(fun, context, basis);
+            let arg = kiselyov(arg, context, basis);
+            combine(fun, arg, basis)
+        }
+    }
+}
+
+/// Applies a compiled term to another, in the same context
+fn combine(fun: Sem, arg: Sem, basis: Basis) -> Sem
+{
+    let bird = |bird| Sem::Closed(basis.express(bird));
+    let need = |sem| Sem::Need(Box::new(sem));
+    match (fun, arg)
+    {
+        (Sem::Closed(fun), Sem::Closed(arg)) => Sem::Closed(fun.app(arg)),
+        (Sem::Closed(fun), Sem::Var) => need(Sem::Closed(fun)),
+        (Sem::Closed(fun), Sem::Need(arg)) => need(combine(Sem::Closed(basis.express(Bird::B).app(fun)), *arg, basis)),
+        (Sem::Closed(fun), Sem::Weak(arg)) => Sem::Weak(Box::new(combine(Sem::Closed(fun), *arg, basis))),
+        (Sem::Var, Sem::Closed(arg)) => need(Sem::Closed(basis.express(Bird::T).app(arg))),
+        (Sem::Var, Sem::Var) => need(bird(Bird::M)),
+        (Sem::Var, Sem::Need(arg)) => need(combine(Sem::Closed(basis.express(Bird::S).app(basis.express(Bird::I))), *arg, basis)),
+        (Sem::Var, Sem::Weak(arg)) => need(combine(bird(Bird::T), *arg, basis)),
+        (Sem::Need(fun), Sem::Closed(arg)) => need(combine(combine(bird(Bird::C), *fun, basis), Sem::Closed(arg), basis)),
+        (Sem::Need(fun), Sem::Var) => need(combine(bird(Bird::W), *fun, basis)),
+        (Sem::Need(fun), Sem::Need(arg)) => need(combine(combine(bird(Bird::S), *fun, basis), *arg, basis)),
+        (Sem::Need(fun), Sem::Weak(arg)) => need(combine(combine(bird(Bird::C), *fun, basis), *arg, basis)),
+        (Sem::Weak(fun), Sem::Closed(arg)) => Sem::Weak(Box::new(combine(*fun, Sem::Closed(arg), basis))),
+        (Sem::Weak(fun), Sem::Var) => need(*fun),
+        (Sem::Weak(fun), Sem::Need(arg)) => need(combine(combine(bird(Bird::B), *fun, basis), *arg, basis)),
+        (Sem::Weak(fun), Sem::Weak(arg)) => Sem::Weak(Box::new(combine(*fun, *arg, basis)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALGORITHMS: [Algorithm; 4] = [Algorithm::Naive, Algorithm::Turner, Algorithm::Kiselyov, Algorithm::KiselyovEta];
+    const BASES: [Basis; 3] = [Basis::Ski, Basis::Bckw, Basis::Aviary];
+
+    fn birds(term: &Term) -> Vec<Bird>
+    {
+        match term
+        {
+            Term::Var(_) => vec![],
+            Term::Bird(bird) => vec![*bird],
+            Term::App(fun, arg) => [birds(fun), birds(arg)].concat()
+        }
+    }
+
+    #[test]
+    fn test_express()
+    {
+        for basis in BASES
+        {
+            for bird in [Bird::S, Bird::K, Bird::I, Bird::B, Bird::C, Bird::T, Bird::M, Bird::W]
+            {
+                let args: Vec<_> = ["x", "y", "z"][..bird.arity()].iter()
+                    .map(|&name| Term::var(name))
+                    .collect();
+                let term = basis.express(bird);
+
+                assert!(birds(&term).into_iter().all(|bird| basis.contains(bird)));
+                assert_eq!(term.apply(args.clone()).normalize(), bird.rewrite(args), "{bird} in {basis:?}")
+            }
+        }
+    }
+
+    #[test]
+    fn test_compile_birds()
+    {
+        for &bird in Bird::ALL
+        {
+            if matches!(bird, Bird::Y | Bird::Ω | Bird::Θ)
+            {
+                continue
+            }
+            let lambda = Lambda::parse(bird.lambda()).unwrap();
+            let args: Vec<_> = ["x", "y", "z", "w", "v", "u", "t"][..bird.arity()].iter()
+                .map(|&name| Term::var(name))
+                .collect();
+            for algorithm in ALGORITHMS
+            {
+                for basis in BASES
+                {
+                    let term = lambda.compile(algorithm, basis);
+
+                    assert!(birds(&term).into_iter().all(|bird| basis.contains(bird)), "{term} is not in {basis:?}");
+                    assert_eq!(
+                        term.apply(args.clone()).normalize(),
+                        bird.rewrite(args.clone()),
+                        "{bird} compiled with {algorithm:?} to {basis:?}"
+                    )
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_compile_free_vars()
+    {
+        let lambda = Lambda::parse("λa.f(ax)a").unwrap();
+        for algorithm in ALGORITHMS
+        {
+            for basis in BASES
+            {
+                let term = lambda.compile(algorithm, basis);
+                let f = Term::var("f");
+                let x = Term::var("x");
+                let y = Term::var("y");
+
+                assert_eq!(term.app(y.clone()).normalize(), f.app(y.clone().app(x)).app(y))
+            }
+        }
+    }
+
+    #[test]
+    fn test_compile_turner()
+    {
+        let compile = |source| Lambda::parse(source).unwrap().compile(Algorithm::Turner, Basis::Aviary);
+
+        assert_eq!(compile("λx.f(gx)(hx)"), Term::parse("B(BS)B f g h").unwrap());
+        assert_eq!(compile("λx.f(gx)y"), Term::parse("B(BC)B f g y").unwrap());
+        assert_eq!(compile("λx.f(g(hx))"), Term::parse("B³ f g h").unwrap());
+    }
+
+    #[test]
+    fn test_compile_sizes()
+    {
+        // the Church numeral for addition
+        let plus = Lambda::parse("λmnfx.mf(nfx)").unwrap();
+
+        let naive = plus.compile(Algorithm::Naive, Basis::Ski).size();
+        let turner = plus.compile(Algorithm::Turner, Basis::Ski).size();
+        let kiselyov = plus.compile(Algorithm::KiselyovEta, Basis::Ski).size();
+        assert!(kiselyov < naive && turner < naive);
+
+        let turner = plus.compile(Algorithm::Turner, Basis::Aviary).size();
+        let kiselyov = plus.compile(Algorithm::KiselyovEta, Basis::Aviary).size();
+        assert!(kiselyov <= turner);
+    }
+
+    #[test]
+    fn test_compile_linear()
+    {
+        // λx₁..xₙ.xₙ..x₁, whose size with unary de Bruijn indices is n + (n - 1) + (1 + .. + n)
+        for n in 1..=32
+        {
+            let vars: Vec<_> = (1..=n).map(|i| format!("x{i}")).collect();
+            let body = vars.iter().rev()
+                .map(|var| Lambda::var(var.clone()))
+                .reduce(Lambda::app)
+                .unwrap();
+            let lambda = vars.iter().rev()
+                .fold(body, |body, var| Lambda::abs(var.clone(), body));
+            let de_bruijn = 2*n - 1 + n*(n + 1)/2;
+
+            let kiselyov = lambda.compile(Algorithm::Kiselyov, Basis::Ski).size();
+            assert!(kiselyov <= 16*de_bruijn, "{kiselyov} for {n} variables");
+            if n == 32
+            {
+                let turner = lambda.compile(Algorithm::Turner, Basis::Ski).size();
+                assert!(turner > 16*de_bruijn)
+            }
+        }
+    }
+}
@@ -0,0 +1,511 @@
+use std::rc::Rc;
+
+use super::{fresh, Lambda};
+
+/// An abstract machine, which evaluates a λ-term one transition at a time.
+///
+/// Machines only evaluate as far as their strategy goes, so they never reduce under an abstraction,
+/// and a term stuck on a free variable is read back with its arguments as they are.
+///
+/// ```rust
+/// use birbs::lambda::{Cek, Krivine, Lambda, Machine, Secd};
+///
+/// // SKK x
+/// let term = Lambda::parse("(λabc.ac(bc))(λab.a)(λab.a)x").unwrap();
+///
+/// assert_eq!(Krivine::load(&term).run(), Lambda::var("x"));
+/// assert_eq!(Cek::load(&term).run(), Lambda::var("x"));
+/// assert_eq!(Secd::load(&term).run(), Lambda::var("x"));
+/// ```
+pub trait Machine: Sized
+{
+    /// The initial state of the machine evaluating the term
+    fn load(term: &Lambda) -> Self;
+
+    /// Performs a single transition
+    ///
+    /// Returns false, and leaves the state as it is, if the machine has halted.
+    fn step(&mut self) -> bool;
+
+    /// The value the machine halted with, or [None](None) if it has not halted yet
+    fn result(&self) -> Option<Lambda>;
+
+    /// Steps until the machine halts
+    ///
+    /// Just like [Lambda::normalize](Lambda::normalize), this never returns if the evaluation diverges.
+    fn run(mut self) -> Lambda
+    {
+        while self.step() {}
+        self.result()
+            .expect("a machine which can't step has halted")
+    }
+}
+
+/// An environment, binding variables to values
+///
+/// Environments are persistent, so closures can share them.
+#[derive(Debug)]
+pub struct Env<V>
+{
+    frame: Option<Rc<(String, V, Env<V>)>>
+}
+
+impl<V> Clone for Env<V>
+{
+    fn clone(&self) -> Self
+    {
+        Env {
+            frame: self.frame.clone()
+        }
+    }
+}
+
+impl<V> Default for Env<V>
+{
+    fn default() -> Self
+    {
+        Env {
+            frame: None
+        }
+    }
+}
+
+impl<V> Env<V>
+{
+    /// The empty environment
+    pub fn new() -> Env<V>
+    {
+        Env::default()
+    }
+
+    /// Binds the variable, shadowing any earlier binding
+    pub fn bind(&self, var: impl Into<String>, value: V) -> Env<V>
+    {
+        Env {
+            frame: Some(Rc::new((var.into(), value, self.clone())))
+        }
+    }
+
+    /// The value bound to the variable, if any
+    pub fn lookup(&self, var: &str) -> Option<&V>
+    {
+        let mut env = self;
+        while let Some(frame) = &env.frame
+        {
+            if frame.0 == var
+            {
+                return Some(&frame.1)
+            }
+            env = &frame.2
+        }
+        None
+    }
+
+    /// Replaces the free variables of the term which are bound in the environment by their values
+    fn close(&self, term: &Lambda, read: fn(&V) -> Lambda) -> Lambda
+    {
+        let values: Vec<(String, Lambda)> = term.free_vars()
+            .into_iter()
+            .filter_map(|var| self.lookup(var).map(|value| (var.to_string(), read(value))))
+            .collect();
+
+        // Renames the variables first, so that substituting one value can't affect another
+        let mut term = term.clone();
+        let mut renamed: Vec<(String, &Lambda)> = vec![];
+        for (var, value) in &values
+        {
+            let name = fresh(var, |name| {
+                term.is_free(name)
+                    || values.iter().any(|(_, value)| value.is_free(name))
+                    || renamed.iter().any(|(other, _)| other == name)
+            });
+            term = term.subst(var, &Lambda::var(name.clone()));
+            renamed.push((name, value))
+        }
+        for (name, value) in renamed
+        {
+            term = term.subst(&name, value)
+        }
+        term
+    }
+}
+
+/// An unevaluated term, together with the environment it is to be evaluated in
+#[derive(Clone, Debug)]
+pub struct Closure
+{
+    pub term: Lambda,
+    pub env: Env<Closure>
+}
+
+impl Closure
+{
+    /// Reads the closure back as a λ-term
+    pub fn read(&self) -> Lambda
+    {
+        self.env.close(&self.term, Closure::read)
+    }
+}
+
+/// The Krivine machine, which evaluates call-by-name to weak head normal form
+///
+/// Arguments are pushed onto the stack unevaluated, and only evaluated once a variable bound to them is the head of the term.
+#[derive(Clone, Debug)]
+pub struct Krivine
+{
+    /// The term in head position
+    pub term: Lambda,
+    /// The environment of the term
+    pub env: Env<Closure>,
+    /// The arguments the term is applied to, with the first argument on top
+    pub stack: Vec<Closure>
+}
+
+impl Machine for Krivine
+{
+    fn load(term: &Lambda) -> Self
+    {
+        Krivine {
+            term: term.clone(),
+            env: Env::new(),
+            stack: vec![]
+        }
+    }
+
+    fn step(&mut self) -> bool
+    {
+        match &self.term
+        {
+            Lambda::App(fun, arg) => {
+                self.stack.push(Closure {
+                    term: (**arg).clone(),
+                    env: self.env.clone()
+                });
+                self.term = (**fun).clone()
+            },
+            Lambda::Abs(var, body) => match self.stack.pop()
+            {
+                Some(arg) => {
+                    self.env = self.env.bind(var.clone(), arg);
+                    self.term = (**body).clone()
+                },
+                None => return false
+            },
+            Lambda::Var(var) => match self.env.lookup(var)
+            {
+                Some(closure) => *self = Krivine {
+                    term: closure.term.clone(),
+                    env: closure.env.clone(),
+                    stack: std::mem::take(&mut self.stack)
+                },
+                None => return false
+            }
+        }
+        true
+    }
+
+    fn result(&self) -> Option<Lambda>
+    {
+        match &self.term
+        {
+            Lambda::Abs(..) if self.stack.is_empty() => (),
+            Lambda::Var(var) if self.env.lookup(var).is_none() => (),
+            _ => return None
+        }
+        Some(
+            self.stack.iter()
+                .rev()
+                .fold(self.env.close(&self.term, Closure::read), |term, arg| term.app(arg.read()))
+        )
+    }
+}
+
+/// A value of a call-by-value machine
+#[derive(Clone, Debug)]
+pub enum Value
+{
+    /// An abstraction, together with the environment of its body
+    Closure(String, Lambda, Env<Value>),
+    /// A term stuck on a free variable, such as `x(λa.a)`
+    Neutral(Lambda)
+}
+
+impl Value
+{
+    /// Reads the value back as a λ-term
+    pub fn read(&self) -> Lambda
+    {
+        match self
+        {
+            Value::Closure(var, body, env) => env.close(&Lambda::abs(var.clone(), body.clone()), Value::read),
+            Value::Neutral(term) => term.clone()
+        }
+    }
+
+    /// Looks up a variable, which is neutral if it is free
+    fn lookup(env: &Env<Value>, var: &str) -> Value
+    {
+        env.lookup(var)
+            .cloned()
+            .unwrap_or_else(|| Value::Neutral(Lambda::var(var)))
+    }
+}
+
+/// What the [CEK machine](Cek) is doing
+#[derive(Clone, Debug)]
+pub enum Control
+{
+    /// Evaluating a term in an environment
+    Eval(Lambda, Env<Value>),
+    /// Returning a value to the continuation
+    Return(Value)
+}
+
+/// A frame of the continuation of the [CEK machine](Cek)
+#[derive(Clone, Debug)]
+pub enum Frame
+{
+    /// The function has been evaluated, and the argument is next
+    Arg(Lambda, Env<Value>),
+    /// The argument is being evaluated, and will then be passed to the function
+    Fun(Value)
+}
+
+/// The CEK machine, which evaluates call-by-value, left to right
+#[derive(Clone, Debug)]
+pub struct Cek
+{
+    /// The control, together with the environment
+    pub control: Control,
+    /// The continuation, with the innermost frame on top
+    pub kont: Vec<Frame>
+}
+
+impl Machine for Cek
+{
+    fn load(term: &Lambda) -> Self
+    {
+        Cek {
+            control: Control::Eval(term.clone(), Env::new()),
+            kont: vec![]
+        }
+    }
+
+    fn step(&mut self) -> bool
+    {
+        self.control = match &self.control
+        {
+            Control::Eval(Lambda::Var(var), env) => Control::Return(Value::lookup(env, var)),
+            Control::Eval(Lambda::Abs(var, body), env) => Control::Return(Value::Closure(var.clone(), (**body).clone(), env.clone())),
+            Control::Eval(Lambda::App(fun, arg), env) => {
+                self.kont.push(Frame::Arg((**arg).clone(), env.clone()));
+                Control::Eval((**fun).clone(), env.clone())
+            },
+            Control::Return(value) => match self.kont.pop()
+            {
+                Some(Frame::Arg(arg, env)) => {
+                    self.kont.push(Frame::Fun(value.clone()));
+                    Control::Eval(arg, env)
+                },
+                Some(Frame::Fun(Value::Closure(var, body, env))) => Control::Eval(body, env.bind(var, value.clone())),
+                Some(Frame::Fun(Value::Neutral(term))) => Control::Return(Value::Neutral(term.app(value.read()))),
+                None => return false
+            }
+        };
+        true
+    }
+
+    fn result(&self) -> Option<Lambda>
+    {
+        match &self.control
+        {
+            Control::Return(value) if self.kont.is_empty() => Some(value.read()),
+            _ => None
+        }
+    }
+}
+
+/// An instruction of the [SECD machine](Secd)
+#[derive(Clone, Debug)]
+pub enum Instr
+{
+    /// Evaluates the term, pushing its value onto the stack
+    Term(Lambda),
+    /// Pops a function, then its argument, and applies the one to the other
+    Apply
+}
+
+/// A suspended computation of the [SECD machine](Secd), resumed once the current one returns
+#[derive(Clone, Debug)]
+pub struct Dump
+{
+    pub stack: Vec<Value>,
+    pub env: Env<Value>,
+    pub control: Vec<Instr>
+}
+
+/// The SECD machine, which evaluates call-by-value, right to left
+#[derive(Clone, Debug)]
+pub struct Secd
+{
+    /// The values computed so far
+    pub stack: Vec<Value>,
+    /// The environment of the instructions
+    pub env: Env<Value>,
+    /// The instructions, with the next one on top
+    pub control: Vec<Instr>,
+    /// The computations to return to
+    pub dump: Vec<Dump>
+}
+
+impl Machine for Secd
+{
+    fn load(term: &Lambda) -> Self
+    {
+        Secd {
+            stack: vec![],
+            env: Env::new(),
+            control: vec![Instr::Term(term.clone())],
+            dump: vec![]
+        }
+    }
+
+    fn step(&mut self) -> bool
+    {
+        match self.control.pop()
+        {
+            Some(Instr::Term(Lambda::Var(var))) => self.stack.push(Value::lookup(&self.env, &var)),
+            Some(Instr::Term(Lambda::Abs(var, body))) => self.stack.push(Value::Closure(var, *body, self.env.clone())),
+            Some(Instr::Term(Lambda::App(fun, arg))) => self.control.extend([Instr::Apply, Instr::Term(*fun), Instr::Term(*arg)]),
+            Some(Instr::Apply) => {
+                let fun = self.stack.pop().unwrap();
+                let arg = self.stack.pop().unwrap();
+                match fun
+                {
+                    Value::Closure(var, body, env) => {
+                        self.dump.push(Dump {
+                            stack: std::mem::take(&mut self.stack),
+                            env: std::mem::replace(&mut self.env, env.bind(var, arg)),
+                            control: std::mem::replace(&mut self.control, vec![Instr::Term(body)])
+                        })
+                    },
+                    Value::Neutral(term) => self.stack.push(Value::Neutral(term.app(arg.read())))
+                }
+            },
+            None => match self.dump.pop()
+            {
+                Some(dump) => {
+                    let value = self.stack.pop().unwrap();
+                    *self = Secd {
+                        stack: dump.stack,
+                        env: dump.env,
+                        control: dump.control,
+                        dump: std::mem::take(&mut self.dump)
+                    };
+                    self.stack.push(value)
+                },
+                None => return false
+            }
+        }
+        true
+    }
+
+    fn result(&self) -> Option<Lambda>
+    {
+        match self.stack.as_slice()
+        {
+            [value] if self.control.is_empty() && self.dump.is_empty() => Some(value.read()),
+            _ => None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::term::{Bird, Term};
+
+    use super::*;
+
+    fn run_all(term: &Lambda) -> [Lambda; 3]
+    {
+        [Krivine::load(term).run(), Cek::load(term).run(), Secd::load(term).run()]
+    }
+
+    #[test]
+    fn test_birds()
+    {
+        for &bird in Bird::ALL
+        {
+            if matches!(bird, Bird::Y | Bird::Ω | Bird::Θ)
+            {
+                continue
+            }
+            let args: Vec<_> = ["x", "y", "z", "w", "v", "u", "t"][..bird.arity()].iter()
+                .map(|&name| Term::var(name))
+                .collect();
+
+            let term = Term::Bird(bird).apply(args);
+            let reduced = Lambda::from(&term.clone().normalize());
+
+            for result in run_all(&Lambda::from(&term))
+            {
+                assert!(result.alpha_eq(&reduced), "{bird}: {result} ≠ {reduced}")
+            }
+        }
+    }
+
+    #[test]
+    fn test_strategies()
+    {
+        let parse = |source| Lambda::parse(source).unwrap();
+
+        // K x Ω only halts call-by-name
+        let term = parse("(λab.a)x((λa.aa)(λa.aa))");
+        assert_eq!(Krivine::load(&term).run(), Lambda::var("x"));
+        let mut cek = Cek::load(&term);
+        let mut secd = Secd::load(&term);
+        for _ in 0..1000
+        {
+            assert!(cek.step() && secd.step())
+        }
+
+        // none of the machines reduce under an abstraction
+        let term = parse("λa.(λb.b)a");
+        for result in run_all(&term)
+        {
+            assert_eq!(result, term)
+        }
+
+        // the environment is substituted back without capture
+        let term = parse("(λab.a)b");
+        for result in run_all(&term)
+        {
+            assert!(result.alpha_eq(&parse("λc.b")), "{result}")
+        }
+    }
+
+    #[test]
+    fn test_step()
+    {
+        let term = Lambda::parse("(λa.a)x").unwrap();
+
+        let mut krivine = Krivine::load(&term);
+        assert!(krivine.result().is_none());
+        assert!(krivine.step());
+        assert_eq!(krivine.term, Lambda::parse("λa.a").unwrap());
+        assert_eq!(krivine.stack.len(), 1);
+        assert!(krivine.step() && krivine.step());
+        assert!(!krivine.step());
+        assert_eq!(krivine.result(), Some(Lambda::var("x")));
+
+        let mut cek = Cek::load(&term);
+        let mut steps = 0;
+        while cek.step()
+        {
+            steps += 1
+        }
+        // eval the application, the function, the argument and the body, each followed by a return
+        assert_eq!(steps, 6);
+        assert_eq!(cek.result(), Some(Lambda::var("x")));
+    }
+}
@@ -0,0 +1,352 @@
+use std::cell::{Cell, OnceCell};
+use std::collections::BTreeSet;
+use std::rc::Rc;
+
+use super::Lambda;
+
+/// A λ-term with its bound variables written as de Bruijn indices
+///
+/// The index of a variable counts the abstractions between it and its binder, so λab.a is `Abs(Abs(Bound(1)))`.
+/// α-equivalent terms are therefore equal.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeBruijn
+{
+    /// A bound variable, by its index
+    Bound(usize),
+    /// A free variable, by its name
+    Free(String),
+    /// An abstraction
+    Abs(Box<DeBruijn>),
+    /// Application of a term to another
+    App(Box<DeBruijn>, Box<DeBruijn>)
+}
+
+/// The error of a normalization which did not finish within its fuel.
+///
+/// Terms such as Ω or Yx have no normal form, so normalizing them always runs out of fuel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutOfFuel;
+
+impl std::fmt::Display for OutOfFuel
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        f.write_str("ran out of fuel before reaching a normal form")
+    }
+}
+
+impl std::error::Error for OutOfFuel {}
+
+impl DeBruijn
+{
+    /// Normalizes the term by evaluation
+    ///
+    /// The term is evaluated into rust closures, with an abstraction becoming a closure over its environment.
+    /// The normal form is then read back by applying each closure to a fresh variable, written as a de Bruijn level.
+    ///
+    /// Arguments are evaluated lazily, at most once, so a term has a normal form here whenever it has one by normal order reduction.
+    /// Every β-reduction costs one unit of fuel. Since evaluation recurses, the fuel also bounds the depth of the stack.
+    pub fn normalize(&self, fuel: usize) -> Result<DeBruijn, OutOfFuel>
+    {
+        let fuel = Cell::new(fuel);
+        let value = eval(self, &Env::default(), &fuel)?;
+        read(&value, 0, &fuel)
+    }
+
+    fn free_vars(&self) -> BTreeSet<&str>
+    {
+        match self
+        {
+            DeBruijn::Bound(_) => BTreeSet::new(),
+            DeBruijn::Free(name) => BTreeSet::from([name.as_str()]),
+            DeBruijn::Abs(body) => body.free_vars(),
+            DeBruijn::App(fun, arg) => {
+                let mut free = fun.free_vars();
+                free.extend(arg.free_vars());
+                free
+            }
+        }
+    }
+}
+
+impl Lambda
+{
+    /// Normalizes the term by evaluation, see [DeBruijn::normalize](DeBruijn::normalize).
+    ///
+    /// This is much faster than [Lambda::normalize](Lambda::normalize), and gives up instead of looping forever.
+    ///
+    /// ```rust
+    /// use birbs::lambda::{Lambda, OutOfFuel};
+    ///
+    /// let parse = |source| Lambda::parse(source).unwrap();
+    ///
+    /// // two to the power of three
+    /// let eight = parse("(λmn.nm)(λfx.f(fx))(λfx.f(f(fx)))").normalize_by_evaluation(1000).unwrap();
+    /// assert_eq!(eight, parse("λab.a(a(a(a(a(a(a(ab)))))))"));
+    ///
+    /// assert_eq!(parse("(λa.aa)(λa.aa)").normalize_by_evaluation(1000), Err(OutOfFuel));
+    /// ```
+    pub fn normalize_by_evaluation(&self, fuel: usize) -> Result<Lambda, OutOfFuel>
+    {
+        DeBruijn::from(self).normalize(fuel)
+            .map(|term| Lambda::from(&term))
+    }
+}
+
+impl From<&Lambda> for DeBruijn
+{
+    fn from(term: &Lambda) -> Self
+    {
+        fn convert<'a>(term: &'a Lambda, bound: &mut Vec<&'a str>) -> DeBruijn
+        {
+            match term
+            {
+                Lambda::Var(name) => match bound.iter().rposition(|&var| var == name)
+                {
+                    Some(i) => DeBruijn::Bound(bound.len() - 1 - i),
+                    None => DeBruijn::Free(name.clone())
+                },
+                Lambda::Abs(var, body) => {
+                    bound.push(var);
+                    let body = convert(body, bound);
+                    bound.pop();
+                    DeBruijn::Abs(Box::new(body))
+                },
+                Lambda::App(fun, arg) => DeBruijn::App(Box::new(convert(fun, bound)), Box::new(convert(arg, bound)))
+            }
+        }
+
+        convert(term, &mut vec![])
+    }
+}
+
+impl From<&DeBruijn> for Lambda
+{
+    /// Names the bound variables a, b, c, ... by their level, skipping the names of free variables.
+    fn from(term: &DeBruijn) -> Self
+    {
+        fn convert(term: &DeBruijn, names: &mut Vec<String>, free: &BTreeSet<&str>) -> Lambda
+        {
+            match term
+            {
+                DeBruijn::Bound(i) => Lambda::var(names[names.len() - 1 - i].clone()),
+                DeBruijn::Free(name) => Lambda::var(name.clone()),
+                DeBruijn::Abs(body) => {
+                    let name = (names.len()..)
+                        .map(|i| match i/26
+                        {
+                            0 => ((b'a' + (i % 26) as u8) as char).to_string(),
+                            n => format!("{}{n}", (b'a' + (i % 26) as u8) as char)
+                        })
+                        .find(|name| !free.contains(name.as_str()) && !names.contains(name))
+                        .unwrap();
+                    names.push(name.clone());
+                    let body = convert(body, names, free);
+                    names.pop();
+                    Lambda::abs(name, body)
+                },
+                DeBruijn::App(fun, arg) => convert(fun, names, free).app(convert(arg, names, free))
+            }
+        }
+
+        convert(term, &mut vec![], &term.free_vars())
+    }
+}
+
+/// A semantic value
+#[derive(Clone)]
+enum Sem<'a>
+{
+    Fun(Rc<dyn Fn(Rc<Thunk<'a>>) -> Result<Sem<'a>, OutOfFuel> + 'a>),
+    Neutral(Rc<Neutral<'a>>)
+}
+
+/// A value stuck on a variable
+enum Neutral<'a>
+{
+    /// A variable bound during read back, by its de Bruijn level
+    Level(usize),
+    Free(&'a str),
+    App(Rc<Neutral<'a>>, Rc<Thunk<'a>>)
+}
+
+/// A lazily evaluated argument
+struct Thunk<'a>
+{
+    value: OnceCell<Sem<'a>>,
+    delayed: Option<(&'a DeBruijn, Env<'a>)>
+}
+
+impl<'a> Thunk<'a>
+{
+    fn force(&self, fuel: &'a Cell<usize>) -> Result<Sem<'a>, OutOfFuel>
+    {
+        if let Some(value) = self.value.get()
+        {
+            return Ok(value.clone())
+        }
+        let (term, env) = self.delayed.as_ref()
+            .expect("a thunk is either evaluated or delayed");
+        let value = eval(term, env, fuel)?;
+        Ok(self.value.get_or_init(|| value).clone())
+    }
+}
+
+/// The values of the bound variables, with index 0 first
+#[derive(Clone, Default)]
+struct Env<'a>
+{
+    frame: Option<Rc<(Rc<Thunk<'a>>, Env<'a>)>>
+}
+
+impl<'a> Env<'a>
+{
+    fn bind(&self, value: Rc<Thunk<'a>>) -> Env<'a>
+    {
+        Env {
+            frame: Some(Rc::new((value, self.clone())))
+        }
+    }
+
+    fn get(&self, index: usize) -> &Rc<Thunk<'a>>
+    {
+        let mut frame = self.frame.as_ref()
+            .expect("bound variables are bound in the environment");
+        for _ in 0..index
+        {
+            frame = frame.1.frame.as_ref()
+                .expect("bound variables are bound in the environment")
+        }
+        &frame.0
+    }
+}
+
+fn eval<'a>(term: &'a DeBruijn, env: &Env<'a>, fuel: &'a Cell<usize>) -> Result<Sem<'a>, OutOfFuel>
+{
+    match term
+    {
+        DeBruijn::Bound(i) => env.get(*i).force(fuel),
+        DeBruijn::Free(name) => Ok(Sem::Neutral(Rc::new(Neutral::Free(name)))),
+        DeBruijn::Abs(body) => {
+            let env = env.clone();
+            Ok(Sem::Fun(Rc::new(move |arg| eval(body, &env.bind(arg), fuel))))
+        },
+        DeBruijn::App(fun, arg) => {
+            let fun = eval(fun, env, fuel)?;
+            let arg = Rc::new(Thunk {
+                value: OnceCell::new(),
+                delayed: Some((arg, env.clone()))
+            });
+            apply(fun, arg, fuel)
+        }
+    }
+}
+
+fn apply<'a>(fun: Sem<'a>, arg: Rc<Thunk<'a>>, fuel: &'a Cell<usize>) -> Result<Sem<'a>, OutOfFuel>
+{
+    match fun
+    {
+        Sem::Fun(fun) => {
+            if fuel.get() == 0
+            {
+                return Err(OutOfFuel)
+            }
+            fuel.set(fuel.get() - 1);
+            fun(arg)
+        },
+        Sem::Neutral(neutral) => Ok(Sem::Neutral(Rc::new(Neutral::App(neutral, arg))))
+    }
+}
+
+/// Reads a value back as a normal form, under `depth` abstractions
+fn read<'a>(value: &Sem<'a>, depth: usize, fuel: &'a Cell<usize>) -> Result<DeBruijn, OutOfFuel>
+{
+    match value
+    {
+        Sem::Fun(fun) => {
+            let var = Rc::new(Thunk {
+                value: OnceCell::from(Sem::Neutral(Rc::new(Neutral::Level(depth)))),
+                delayed: None
+            });
+            Ok(DeBruijn::Abs(Box::new(read(&fun(var)?, depth + 1, fuel)?)))
+        },
+        Sem::Neutral(neutral) => read_neutral(neutral, depth, fuel)
+    }
+}
+
+fn read_neutral<'a>(neutral: &Neutral<'a>, depth: usize, fuel: &'a Cell<usize>) -> Result<DeBruijn, OutOfFuel>
+{
+    match neutral
+    {
+        Neutral::Level(level) => Ok(DeBruijn::Bound(depth - 1 - level)),
+        Neutral::Free(name) => Ok(DeBruijn::Free(name.to_string())),
+        Neutral::App(fun, arg) => Ok(DeBruijn::App(
+            Box::new(read_neutral(fun, depth, fuel)?),
+            Box::new(read(&arg.force(fuel)?, depth, fuel)?)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::term::{Bird, Term};
+
+    use super::*;
+
+    #[test]
+    fn test_de_bruijn()
+    {
+        let parse = |source| Lambda::parse(source).unwrap();
+
+        assert_eq!(
+            DeBruijn::from(&parse("λab.a")),
+            DeBruijn::Abs(Box::new(DeBruijn::Abs(Box::new(DeBruijn::Bound(1)))))
+        );
+        assert_eq!(DeBruijn::from(&parse("λab.a")), DeBruijn::from(&parse("λxy.x")));
+        assert_eq!(DeBruijn::from(&parse("λa.b")), DeBruijn::Abs(Box::new(DeBruijn::Free("b".to_string()))));
+
+        // the names avoid free variables
+        assert_eq!(Lambda::from(&DeBruijn::from(&parse("λxy.xya"))), parse("λbc.bca"));
+    }
+
+    #[test]
+    fn test_normalize()
+    {
+        let parse = |source| Lambda::parse(source).unwrap();
+
+        // two plus two
+        let four = parse("(λmnfx.mf(nfx))(λfx.f(fx))(λfx.f(fx))").normalize_by_evaluation(100).unwrap();
+        assert_eq!(four, parse("λab.a(a(a(ab)))"));
+
+        // K x Ω has a normal form, since Ω is never evaluated
+        assert_eq!(parse("(λab.a)x((λa.aa)(λa.aa))").normalize_by_evaluation(100), Ok(Lambda::var("x")));
+
+        // three to the power of three is much too slow to reach by rewriting
+        let church = |n| Lambda::parse(&format!("λab.{}b{}", "a(".repeat(n), ")".repeat(n))).unwrap();
+        let power = parse("λmn.nm").apply([church(3), church(3)]);
+        assert_eq!(power.normalize_by_evaluation(1000), Ok(church(27)));
+        assert_eq!(power.normalize_by_evaluation(5), Err(OutOfFuel));
+    }
+
+    #[test]
+    fn test_birds()
+    {
+        for &bird in Bird::ALL
+        {
+            let lambda = Lambda::parse(bird.lambda()).unwrap();
+            if matches!(bird, Bird::Y | Bird::Ω | Bird::Θ)
+            {
+                assert_eq!(lambda.normalize_by_evaluation(1000), Err(OutOfFuel), "{bird}");
+                continue
+            }
+            assert_eq!(lambda.normalize_by_evaluation(1000), Ok(lambda.clone()), "{bird}");
+
+            let args: Vec<_> = ["x", "y", "z", "w", "v", "u", "t"][..bird.arity()].iter()
+                .map(|&name| Term::var(name))
+                .collect();
+            let rule = Lambda::from(&bird.rewrite(args.clone()));
+            let result = Lambda::from(&Term::Bird(bird).apply(args)).normalize_by_evaluation(1000);
+            assert_eq!(result, Ok(rule), "{bird}")
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use std::str::FromStr;
+
+use super::Lambda;
+
+/// An error encountered while parsing a [Lambda](Lambda).
+///
+/// Positions are byte offsets into the parsed string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError
+{
+    /// There was no term where one was expected
+    Empty(usize),
+    /// A character which does not begin a variable, an abstraction or a group
+    UnexpectedChar(usize, char),
+    /// An abstraction without any variables to bind
+    EmptyBinder(usize),
+    /// An abstraction where the variables were not followed by a `.`
+    ExpectedDot(usize),
+    /// A parenthesis which was opened but never closed
+    UnclosedParen(usize),
+    /// A parenthesis which was closed but never opened
+    UnopenedParen(usize)
+}
+
+impl std::fmt::Display for ParseError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            ParseError::Empty(i) => write!(f, "expected a term at {i}"),
+            ParseError::UnexpectedChar(i, c) => write!(f, "unexpected character {c:?} at {i}"),
+            ParseError::EmptyBinder(i) => write!(f, "expected a variable to bind at {i}"),
+            ParseError::ExpectedDot(i) => write!(f, "expected '.' at {i}"),
+            ParseError::UnclosedParen(i) => write!(f, "unclosed parenthesis at {i}"),
+            ParseError::UnopenedParen(i) => write!(f, "unopened parenthesis at {i}")
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Lambda
+{
+    /// Parses a λ-term
+    ///
+    /// Two notations are accepted:
+    /// * The compact notation of the birds' definitions, `λabc.ac(bc)`.
+    ///   Variables are a single lowercase letter, optionally followed by digits and primes, so `ab` is `a` applied to `b`.
+    /// * A notation with multi-character variables, `\x y. x (y z)`, used whenever the source contains a `\`.
+    ///   Variables are identifiers, separated by whitespace.
+    ///
+    /// In both notations application associates to the left, and the body of an abstraction extends as far to the right as possible.
+    ///
+    /// ```rust
+    /// use birbs::lambda::Lambda;
+    ///
+    /// let kestrel = Lambda::parse("λab.a").unwrap();
+    /// let first = Lambda::parse("\\first second. first").unwrap();
+    ///
+    /// assert!(kestrel.alpha_eq(&first));
+    /// ```
+    pub fn parse(source: &str) -> Result<Lambda, ParseError>
+    {
+        let mut parser = Parser {
+            source,
+            position: 0,
+            words: source.contains('\\')
+        };
+        let term = parser.sequence()?
+            .ok_or(ParseError::Empty(parser.position))?;
+        match parser.peek()
+        {
+            Some(_) => Err(ParseError::UnopenedParen(parser.position)),
+            None => Ok(term)
+        }
+    }
+}
+
+impl FromStr for Lambda
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        Lambda::parse(s)
+    }
+}
+
+/// Whether the name can be written in the compact notation
+pub(super) fn is_compact(name: &str) -> bool
+{
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_lowercase()) && chars.all(|c| c.is_ascii_digit() || c == '\'')
+}
+
+struct Parser<'a>
+{
+    source: &'a str,
+    position: usize,
+    words: bool
+}
+
+impl<'a> Parser<'a>
+{
+    fn rest(&self) -> &'a str
+    {
+        &self.source[self.position..]
+    }
+
+    /// Skips whitespace, and returns the next character
+    fn peek(&mut self) -> Option<char>
+    {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+        self.rest().chars().next()
+    }
+
+    /// Parses a variable, if there is one
+    fn var(&mut self) -> Option<&'a str>
+    {
+        let rest = self.rest();
+        let mut chars = rest.chars();
+        let first = chars.next()?;
+        let len = if self.words
+        {
+            if !(first.is_ascii_alphabetic() || first == '_')
+            {
+                return None
+            }
+            rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '\''))
+                .unwrap_or(rest.len())
+        }
+        else
+        {
+            if !first.is_ascii_lowercase()
+            {
+                return None
+            }
+            rest[1..].find(|c: char| !(c.is_ascii_digit() || c == '\''))
+                .map_or(rest.len(), |i| i + 1)
+        };
+        self.position += len;
+        Some(&rest[..len])
+    }
+
+    /// Parses atoms until the end of the source or a closing parenthesis
+    fn sequence(&mut self) -> Result<Option<Lambda>, ParseError>
+    {
+        let mut term: Option<Lambda> = None;
+        while let Some(atom) = self.atom()?
+        {
+            term = Some(match term
+            {
+                Some(term) => term.app(atom),
+                None => atom
+            })
+        }
+        Ok(term)
+    }
+
+    fn atom(&mut self) -> Result<Option<Lambda>, ParseError>
+    {
+        match self.peek()
+        {
+            None | Some(')') => Ok(None),
+            Some('(') => {
+                let open = self.position;
+                self.position += 1;
+                let term = self.sequence()?
+                    .ok_or(ParseError::Empty(self.position))?;
+                if self.peek() != Some(')')
+                {
+                    return Err(ParseError::UnclosedParen(open))
+                }
+                self.position += 1;
+                Ok(Some(term))
+            },
+            Some(c @ ('λ' | '\\')) => {
+                self.position += c.len_utf8();
+                let mut vars = vec![];
+                while self.peek().is_some()
+                {
+                    match self.var()
+                    {
+                        Some(var) => vars.push(var),
+                        None => break
+                    }
+                }
+                if vars.is_empty()
+                {
+                    return Err(ParseError::EmptyBinder(self.position))
+                }
+                if self.peek() != Some('.')
+                {
+                    return Err(ParseError::ExpectedDot(self.position))
+                }
+                self.position += 1;
+                let body = self.sequence()?
+                    .ok_or(ParseError::Empty(self.position))?;
+                Ok(Some(vars.into_iter().rev().fold(body, |body, var| Lambda::abs(var, body))))
+            },
+            Some(c) => match self.var()
+            {
+                Some(var) => Ok(Some(Lambda::var(var))),
+                None => Err(ParseError::UnexpectedChar(self.position, c))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse()
+    {
+        let [a, b, c] = ["a", "b", "c"].map(Lambda::var);
+
+        assert_eq!(
+            Lambda::parse("λabc.ac(bc)"),
+            Ok(Lambda::abs("a", Lambda::abs("b", Lambda::abs("c", a.clone().app(c.clone()).app(b.clone().app(c.clone()))))))
+        );
+        assert_eq!(
+            Lambda::parse("(λa.aa)(λa.aa)"),
+            Ok(Lambda::abs("a", a.clone().app(a.clone())).app(Lambda::abs("a", a.clone().app(a.clone()))))
+        );
+        assert_eq!(
+            Lambda::parse("\\first second. first (second first)"),
+            Ok(Lambda::abs("first", Lambda::abs("second", Lambda::var("first").app(Lambda::var("second").app(Lambda::var("first"))))))
+        );
+        assert_eq!(Lambda::parse("λa.λb.a"), Lambda::parse("λab.a"));
+        assert_eq!(Lambda::parse("λa b. a"), Lambda::parse("λab.a"));
+        assert_eq!(Lambda::parse("a(λb.b)c"), Ok(a.clone().app(Lambda::abs("b", b.clone())).app(c.clone())));
+        assert_eq!(Lambda::parse("λa1.a1a"), Ok(Lambda::abs("a1", Lambda::var("a1").app(a))));
+    }
+
+    #[test]
+    fn test_parse_errors()
+    {
+        assert_eq!(Lambda::parse(""), Err(ParseError::Empty(0)));
+        assert_eq!(Lambda::parse("λ.a"), Err(ParseError::EmptyBinder(2)));
+        assert_eq!(Lambda::parse("λab"), Err(ParseError::ExpectedDot(4)));
+        assert_eq!(Lambda::parse("λa."), Err(ParseError::Empty(4)));
+        assert_eq!(Lambda::parse("(ab"), Err(ParseError::UnclosedParen(0)));
+        assert_eq!(Lambda::parse("ab)"), Err(ParseError::UnopenedParen(2)));
+        assert_eq!(Lambda::parse("aB"), Err(ParseError::UnexpectedChar(1, 'B')));
+    }
+
+    #[test]
+    fn test_round_trip()
+    {
+        for source in ["λabc.ac(bc)", "λabcdefg.a(bcd)(efg)", "(λa.aa)(λa.aa)", "a(λb.b)c", "λa1.a1'a", "\\first second. first (second first)"]
+        {
+            let term = Lambda::parse(source).unwrap();
+            assert_eq!(term.to_string(), source);
+            assert_eq!(Lambda::parse(&term.to_string()), Ok(term))
+        }
+    }
+}
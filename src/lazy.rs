@@ -0,0 +1,1134 @@
+//! Lazy birds
+//!
+//! Combinatory logic is call-by-name: an argument is only evaluated if something uses it.
+//! Here, a bird passes each argument it computes to a function as a [Thunk](Thunk), which is only evaluated once the function
+//! dereferences it, so that a function ignoring an argument never computes it. A thunk is evaluated at most once, however often it is used.
+//! The inputs are passed on as they are.
+//!
+//! The birds read their λ-body by the [first of the possible interpretations](crate#interpretations).
+//!
+//! ```rust
+//! use birbs::lazy::{lazy_s, Thunk};
+//!
+//! let first = |x: Thunk<u8>, _: Thunk<u8>| *x;
+//!
+//! // b(c(y)) is never computed
+//! let f = lazy_s(first, |_: Thunk<u8>| -> u8 { panic!() }, |x: u8| x + 1);
+//!
+//! assert_eq!(f(1, 2), 2);
+//! ```
+//!
+//! K and KI don't call the argument they keep, so here they take thunks, any [FnOnce](FnOnce) returning the value or a [Thunk](Thunk),
+//! and return the one they keep, unevaluated.
+//!
+//! ```rust
+//! use std::cell::LazyCell;
+//! use birbs::lazy::{lazy_k, Thunk};
+//!
+//! let a = Thunk::from(LazyCell::new(|| 1));
+//! let b = || -> u8 { panic!() };
+//!
+//! assert_eq!(*lazy_k(a, b), 1);
+//! ```
+//!
+//! Y passes a thunk of Ya, so it returns as soon as a stops forcing it, such as on the base case of a recursion.
+//! Θ has the same λ-body, a(Θa), so [lazy_y] stands for it too, while Ω has no function which could stop it,
+//! so it is [missing](crate#interpretations).
+//!
+//! ```rust
+//! use std::rc::Rc;
+//! use birbs::lazy::lazy_y;
+//!
+//! type Function<'a> = Rc<dyn Fn(u64) -> u64 + 'a>;
+//!
+//! let factorial = lazy_y::<_, Function>(&|factorial| Rc::new(move |n| if n == 0 {1} else {n*(*factorial)(n - 1)}));
+//!
+//! assert_eq!(factorial(5), 120);
+//! ```
+
+use std::cell::LazyCell;
+use std::ops::Deref;
+
+/// A value which is evaluated when it is first dereferenced
+/// 
+/// This is a [LazyCell](LazyCell) over a boxed closure, so that the functions passed to the birds can name its type.
+pub struct Thunk<'a, T>(LazyCell<T, Box<dyn FnOnce() -> T + 'a>>);
+
+impl<'a, T> Thunk<'a, T>
+{
+    /// A thunk evaluating the function
+    pub fn new(function: impl FnOnce() -> T + 'a) -> Self
+    {
+        Thunk(LazyCell::new(Box::new(function)))
+    }
+
+    /// Evaluates the thunk, unless it already was, and returns a reference to its value
+    pub fn force(this: &Self) -> &T
+    {
+        LazyCell::force(&this.0)
+    }
+
+    /// Evaluates the thunk, unless it already was, and returns its value
+    pub fn into_inner(this: Self) -> T
+    {
+        LazyCell::into_inner(this.0)
+            .unwrap_or_else(|function| function())
+    }
+}
+
+impl<T> Deref for Thunk<'_, T>
+{
+    type Target = T;
+
+    fn deref(&self) -> &T
+    {
+        Thunk::force(self)
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for Thunk<'_, T>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        self.0.fmt(f)
+    }
+}
+
+impl<'a, T, F> From<LazyCell<T, F>> for Thunk<'a, T>
+where
+    F: FnOnce() -> T + 'a,
+    T: 'a
+{
+    /// A thunk in the same state as the cell: evaluated if the cell was, and evaluating its function otherwise
+    fn from(cell: LazyCell<T, F>) -> Self
+    {
+        match LazyCell::into_inner(cell)
+        {
+            Ok(value) => {
+                let thunk = Thunk::new(move || value);
+                Thunk::force(&thunk);
+                thunk
+            },
+            Err(function) => Thunk::new(function)
+        }
+    }
+}
+
+impl<T> FnOnce<()> for Thunk<'_, T>
+{
+    type Output = T;
+
+    extern "rust-call" fn call_once(self, (): ()) -> T
+    {
+        Thunk::into_inner(self)
+    }
+}
+
+/// B = Bluebird
+/// 
+/// λabc.a(bc)
+/// 
+/// returns a(b(c(x1)))
+pub fn lazy_b<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O2>) -> R,
+    B: for<'t> Fn(Thunk<'t, O1>) -> O2,
+    C: Fn(X1) -> O1
+{
+    move |x1| a(Thunk::new(|| b(Thunk::new(|| c(x1)))))
+}
+
+/// B¹ = Blackbird
+/// 
+/// λabcd.a(bcd)
+/// 
+/// returns a(b(c(x1), d(x2)))
+pub fn lazy_b1<A, B, C, D, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O3>) -> R,
+    B: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>) -> O3,
+    C: Fn(X1) -> O1,
+    D: Fn(X2) -> O2
+{
+    move |x1, x2| a(Thunk::new(|| b(Thunk::new(|| c(x1)), Thunk::new(|| d(x2)))))
+}
+
+/// B² = Bunting
+/// 
+/// λabcde.a(bcde)
+/// 
+/// returns a(b(c(x1), d(x2), e(x3)))
+pub fn lazy_b2<A, B, C, D, E, X1, X2, X3, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O4>) -> R,
+    B: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>, Thunk<'t, O3>) -> O4,
+    C: Fn(X1) -> O1,
+    D: Fn(X2) -> O2,
+    E: Fn(X3) -> O3
+{
+    move |x1, x2, x3| a(Thunk::new(|| b(Thunk::new(|| c(x1)), Thunk::new(|| d(x2)), Thunk::new(|| e(x3)))))
+}
+
+/// B³ = Becard
+/// 
+/// λabcd.a(b(cd))
+/// 
+/// returns a(b(c(d(x1))))
+pub fn lazy_b3<A, B, C, D, X1, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O3>) -> R,
+    B: for<'t> Fn(Thunk<'t, O2>) -> O3,
+    C: for<'t> Fn(Thunk<'t, O1>) -> O2,
+    D: Fn(X1) -> O1
+{
+    move |x1| a(Thunk::new(|| b(Thunk::new(|| c(Thunk::new(|| d(x1)))))))
+}
+
+/// C = Cardinal
+/// 
+/// λabc.acb
+/// 
+/// returns a(c(x1), b(x2))
+pub fn lazy_c<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1, X2) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>) -> R,
+    B: Fn(X2) -> O2,
+    C: Fn(X1) -> O1
+{
+    move |x1, x2| a(Thunk::new(|| c(x1)), Thunk::new(|| b(x2)))
+}
+
+/// D = Dove
+/// 
+/// λabcd.ab(cd)
+/// 
+/// returns a(b(x1), c(d(x2)))
+pub fn lazy_d<A, B, C, D, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O3>) -> R,
+    B: Fn(X1) -> O1,
+    C: for<'t> Fn(Thunk<'t, O2>) -> O3,
+    D: Fn(X2) -> O2
+{
+    move |x1, x2| a(Thunk::new(|| b(x1)), Thunk::new(|| c(Thunk::new(|| d(x2)))))
+}
+
+/// D¹ = Dickcissel
+/// 
+/// λabcde.abc(de)
+/// 
+/// returns a(b(x1), c(x2), d(e(x3)))
+pub fn lazy_d1<A, B, C, D, E, X1, X2, X3, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>, Thunk<'t, O4>) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X2) -> O2,
+    D: for<'t> Fn(Thunk<'t, O3>) -> O4,
+    E: Fn(X3) -> O3
+{
+    move |x1, x2, x3| a(Thunk::new(|| b(x1)), Thunk::new(|| c(x2)), Thunk::new(|| d(Thunk::new(|| e(x3)))))
+}
+
+/// D² = Dovekies
+/// 
+/// λabcde.a(bc)(de)
+/// 
+/// returns a(b(c(x1)), d(e(x2)))
+pub fn lazy_d2<A, B, C, D, E, X1, X2, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl Fn(X1, X2) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O2>, Thunk<'t, O4>) -> R,
+    B: for<'t> Fn(Thunk<'t, O1>) -> O2,
+    C: Fn(X1) -> O1,
+    D: for<'t> Fn(Thunk<'t, O3>) -> O4,
+    E: Fn(X2) -> O3
+{
+    move |x1, x2| a(Thunk::new(|| b(Thunk::new(|| c(x1)))), Thunk::new(|| d(Thunk::new(|| e(x2)))))
+}
+
+/// E = Eagle
+/// 
+/// λabcde.ab(cde)
+/// 
+/// returns a(b(x1), c(d(x2), e(x3)))
+pub fn lazy_e<A, B, C, D, E, X1, X2, X3, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O4>) -> R,
+    B: Fn(X1) -> O1,
+    C: for<'t> Fn(Thunk<'t, O2>, Thunk<'t, O3>) -> O4,
+    D: Fn(X2) -> O2,
+    E: Fn(X3) -> O3
+{
+    move |x1, x2, x3| a(Thunk::new(|| b(x1)), Thunk::new(|| c(Thunk::new(|| d(x2)), Thunk::new(|| e(x3)))))
+}
+
+/// Ê = Bald Eagle
+/// 
+/// λabcdefg.a(bcd)(efg)
+/// 
+/// returns a(b(c(x1), d(x2)), e(f(x3), g(x4)))
+pub fn lazy_ê<A, B, C, D, E, F, G, X1, X2, X3, X4, O1, O2, O3, O4, O5, O6, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E,
+    f: F,
+    g: G
+) -> impl Fn(X1, X2, X3, X4) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O3>, Thunk<'t, O6>) -> R,
+    B: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>) -> O3,
+    C: Fn(X1) -> O1,
+    D: Fn(X2) -> O2,
+    E: for<'t> Fn(Thunk<'t, O4>, Thunk<'t, O5>) -> O6,
+    F: Fn(X3) -> O4,
+    G: Fn(X4) -> O5
+{
+    move |x1, x2, x3, x4| a(Thunk::new(|| b(Thunk::new(|| c(x1)), Thunk::new(|| d(x2)))), Thunk::new(|| e(Thunk::new(|| f(x3)), Thunk::new(|| g(x4)))))
+}
+
+/// F = Finch
+/// 
+/// λabc.cba
+/// 
+/// returns c(b(x1), a(x2))
+pub fn lazy_f<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(X2) -> O2,
+    B: Fn(X1) -> O1,
+    C: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>) -> R
+{
+    move |x1, x2| c(Thunk::new(|| b(x1)), Thunk::new(|| a(x2)))
+}
+
+/// G = Goldfinch
+/// 
+/// λabcd.ad(bc)
+/// 
+/// returns a(d(x1), b(c(x2)))
+pub fn lazy_g<A, B, C, D, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O3>) -> R,
+    B: for<'t> Fn(Thunk<'t, O2>) -> O3,
+    C: Fn(X2) -> O2,
+    D: Fn(X1) -> O1
+{
+    move |x1, x2| a(Thunk::new(|| d(x1)), Thunk::new(|| b(Thunk::new(|| c(x2)))))
+}
+
+/// H = Hummingbird
+/// 
+/// λabc.abcb
+/// 
+/// returns a(b(x1), c(x2), b(x3))
+pub fn lazy_h<A, B, C, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>, Thunk<'t, O3>) -> R,
+    B: Fn(X1) -> O1 + Fn(X3) -> O3,
+    C: Fn(X2) -> O2
+{
+    move |x1, x2, x3| a(Thunk::new(|| b(x1)), Thunk::new(|| c(x2)), Thunk::new(|| b(x3)))
+}
+
+/// I = Identity Bird aka Idiot
+/// 
+/// λa.a
+/// 
+/// returns a(x1)
+pub fn lazy_i<A, X1, R>(
+    a: A
+) -> impl Fn(X1) -> R
+where
+    A: Fn(X1) -> R
+{
+    move |x1| a(x1)
+}
+
+/// J = Jay
+/// 
+/// λabcd.ab(adc)
+/// 
+/// returns a(b(x1), a(d(x2), c(x3)))
+pub fn lazy_j<A, B, C, D, X1, X2, X3, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O2>, Thunk<'t, O3>) -> O4 + for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O4>) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X3) -> O3,
+    D: Fn(X2) -> O2
+{
+    move |x1, x2, x3| a(Thunk::new(|| b(x1)), Thunk::new(|| a(Thunk::new(|| d(x2)), Thunk::new(|| c(x3)))))
+}
+
+/// K = Kestrel (True)
+/// 
+/// λab.a
+/// 
+/// returns a as a thunk, never evaluating b
+#[allow(unused)]
+pub fn lazy_k<'a, A, B, T>(
+    a: A,
+    b: B
+) -> Thunk<'a, T>
+where
+    A: FnOnce() -> T + 'a
+{
+    Thunk::new(a)
+}
+
+/// L = Lark
+/// 
+/// λab.a(bb)
+/// 
+/// returns a(b(b(x1)))
+pub fn lazy_l<A, B, X1, O1, O2, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O2>) -> R,
+    B: Fn(X1) -> O1 + for<'t> Fn(Thunk<'t, O1>) -> O2
+{
+    move |x1| a(Thunk::new(|| b(Thunk::new(|| b(x1)))))
+}
+
+/// M = Mockingbird
+/// 
+/// λa.aa
+/// 
+/// returns a(a(x1))
+pub fn lazy_m<A, X1, O1, R>(
+    a: A
+) -> impl Fn(X1) -> R
+where
+    A: Fn(X1) -> O1 + for<'t> Fn(Thunk<'t, O1>) -> R
+{
+    move |x1| a(Thunk::new(|| a(x1)))
+}
+
+/// M² = Double Mockingbird
+/// 
+/// λab.ab(ab)
+/// 
+/// returns a(b(x1), a(b(x2)))
+pub fn lazy_m2<A, B, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1, X2) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O2>) -> O3 + for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O3>) -> R,
+    B: Fn(X1) -> O1 + Fn(X2) -> O2
+{
+    move |x1, x2| a(Thunk::new(|| b(x1)), Thunk::new(|| a(Thunk::new(|| b(x2)))))
+}
+
+/// O = Owl
+/// 
+/// λab.b(ab)
+/// 
+/// returns b(a(b(x1)))
+pub fn lazy_o<A, B, X1, O1, O2, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>) -> O2,
+    B: Fn(X1) -> O1 + for<'t> Fn(Thunk<'t, O2>) -> R
+{
+    move |x1| b(Thunk::new(|| a(Thunk::new(|| b(x1)))))
+}
+
+/// Q = Queer Bird
+/// 
+/// λabc.b(ac)
+/// 
+/// returns b(a(c(x1)))
+pub fn lazy_q<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>) -> O2,
+    B: for<'t> Fn(Thunk<'t, O2>) -> R,
+    C: Fn(X1) -> O1
+{
+    move |x1| b(Thunk::new(|| a(Thunk::new(|| c(x1)))))
+}
+
+/// Q¹ = Quixotic Bird
+/// 
+/// λabc.a(cb)
+/// 
+/// returns a(c(b(x1)))
+pub fn lazy_q1<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O2>) -> R,
+    B: Fn(X1) -> O1,
+    C: for<'t> Fn(Thunk<'t, O1>) -> O2
+{
+    move |x1| a(Thunk::new(|| c(Thunk::new(|| b(x1)))))
+}
+
+/// Q² = Quizzical Bird
+/// 
+/// λabc.b(ca)
+/// 
+/// returns b(c(a(x1)))
+pub fn lazy_q2<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1) -> R
+where
+    A: Fn(X1) -> O1,
+    B: for<'t> Fn(Thunk<'t, O2>) -> R,
+    C: for<'t> Fn(Thunk<'t, O1>) -> O2
+{
+    move |x1| b(Thunk::new(|| c(Thunk::new(|| a(x1)))))
+}
+
+/// Q³ = Quirky Bird
+/// 
+/// λabc.c(ab)
+/// 
+/// returns c(a(b(x1)))
+pub fn lazy_q3<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>) -> O2,
+    B: Fn(X1) -> O1,
+    C: for<'t> Fn(Thunk<'t, O2>) -> R
+{
+    move |x1| c(Thunk::new(|| a(Thunk::new(|| b(x1)))))
+}
+
+/// Q⁴ = Quacky Bird
+/// 
+/// λabc.c(ba)
+/// 
+/// returns c(b(a(x1)))
+pub fn lazy_q4<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1) -> R
+where
+    A: Fn(X1) -> O1,
+    B: for<'t> Fn(Thunk<'t, O1>) -> O2,
+    C: for<'t> Fn(Thunk<'t, O2>) -> R
+{
+    move |x1| c(Thunk::new(|| b(Thunk::new(|| a(x1)))))
+}
+
+/// R = Robin
+/// 
+/// λabc.bca
+/// 
+/// returns b(c(x1), a(x2))
+pub fn lazy_r<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(X2) -> O2,
+    B: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>) -> R,
+    C: Fn(X1) -> O1
+{
+    move |x1, x2| b(Thunk::new(|| c(x1)), Thunk::new(|| a(x2)))
+}
+
+/// S = Starling
+/// 
+/// λabc.ac(bc)
+/// 
+/// returns a(c(x1), b(c(x2)))
+pub fn lazy_s<A, B, C, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1, X2) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O3>) -> R,
+    B: for<'t> Fn(Thunk<'t, O2>) -> O3,
+    C: Fn(X1) -> O1 + Fn(X2) -> O2
+{
+    move |x1, x2| a(Thunk::new(|| c(x1)), Thunk::new(|| b(Thunk::new(|| c(x2)))))
+}
+
+/// T = Thrush
+/// 
+/// λab.ba
+/// 
+/// returns b(a(x1))
+pub fn lazy_t<A, B, X1, O1, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1) -> R
+where
+    A: Fn(X1) -> O1,
+    B: for<'t> Fn(Thunk<'t, O1>) -> R
+{
+    move |x1| b(Thunk::new(|| a(x1)))
+}
+
+/// U = Turing
+/// 
+/// λab.b(aab)
+/// 
+/// returns b(a(a(x1), b(x2)))
+pub fn lazy_u<A, B, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(X1) -> O1 + for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>) -> O3,
+    B: Fn(X2) -> O2 + for<'t> Fn(Thunk<'t, O3>) -> R
+{
+    move |x1, x2| b(Thunk::new(|| a(Thunk::new(|| a(x1)), Thunk::new(|| b(x2)))))
+}
+
+/// V = Vireo aka Pairing
+/// 
+/// λabc.cab
+/// 
+/// returns c(a(x1), b(x2))
+pub fn lazy_v<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(X1) -> O1,
+    B: Fn(X2) -> O2,
+    C: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>) -> R
+{
+    move |x1, x2| c(Thunk::new(|| a(x1)), Thunk::new(|| b(x2)))
+}
+
+/// W = Warbler
+/// 
+/// λab.abb
+/// 
+/// returns a(b(x1), b(x2))
+pub fn lazy_w<A, B, X1, X2, O1, O2, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1, X2) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>) -> R,
+    B: Fn(X1) -> O1 + Fn(X2) -> O2
+{
+    move |x1, x2| a(Thunk::new(|| b(x1)), Thunk::new(|| b(x2)))
+}
+
+/// W¹ = Converse Warbler
+/// 
+/// λab.baa
+/// 
+/// returns b(a(x1), a(x2))
+pub fn lazy_w1<A, B, X1, X2, O1, O2, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(X1) -> O1 + Fn(X2) -> O2,
+    B: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>) -> R
+{
+    move |x1, x2| b(Thunk::new(|| a(x1)), Thunk::new(|| a(x2)))
+}
+
+/// Y = Why Bird
+/// 
+/// λa.a(Ya)
+/// 
+/// returns a(Ya), where Ya is only evaluated if a forces it
+pub fn lazy_y<'a, A, R>(a: &'a A) -> R
+where
+    A: Fn(Thunk<'a, R>) -> R
+{
+    a(Thunk::new(move || lazy_y(a)))
+}
+
+/// I* = Identity Bird Once Removed
+/// 
+/// λab.ab
+/// 
+/// returns a(b(x1))
+pub fn lazy_i_star<A, B, X1, O1, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>) -> R,
+    B: Fn(X1) -> O1
+{
+    move |x1| a(Thunk::new(|| b(x1)))
+}
+
+/// W* = Warbled Once Removed
+/// 
+/// λabc.abcc
+/// 
+/// returns a(b(x1), c(x2), c(x3))
+pub fn lazy_w_star<A, B, C, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>, Thunk<'t, O3>) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X2) -> O2 + Fn(X3) -> O3
+{
+    move |x1, x2, x3| a(Thunk::new(|| b(x1)), Thunk::new(|| c(x2)), Thunk::new(|| c(x3)))
+}
+
+/// C* = Cardinal Once Removed
+/// 
+/// λabcd.abdc
+/// 
+/// returns a(b(x1), d(x2), c(x3))
+pub fn lazy_c_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>, Thunk<'t, O3>) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X3) -> O3,
+    D: Fn(X2) -> O2
+{
+    move |x1, x2, x3| a(Thunk::new(|| b(x1)), Thunk::new(|| d(x2)), Thunk::new(|| c(x3)))
+}
+
+/// R* = Robin Once Removed
+/// 
+/// λabcd.acdb
+/// 
+/// returns a(c(x1), d(x2), b(x3))
+pub fn lazy_r_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>, Thunk<'t, O3>) -> R,
+    B: Fn(X3) -> O3,
+    C: Fn(X1) -> O1,
+    D: Fn(X2) -> O2
+{
+    move |x1, x2, x3| a(Thunk::new(|| c(x1)), Thunk::new(|| d(x2)), Thunk::new(|| b(x3)))
+}
+
+/// F* = Finch Once Removed
+/// 
+/// λabcd.adcb
+/// 
+/// returns a(d(x1), c(x2), b(x3))
+pub fn lazy_f_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>, Thunk<'t, O3>) -> R,
+    B: Fn(X3) -> O3,
+    C: Fn(X2) -> O2,
+    D: Fn(X1) -> O1
+{
+    move |x1, x2, x3| a(Thunk::new(|| d(x1)), Thunk::new(|| c(x2)), Thunk::new(|| b(x3)))
+}
+
+/// V* = Vireo Once Removed
+/// 
+/// λabcd.acbd
+/// 
+/// returns a(c(x1), b(x2), d(x3))
+pub fn lazy_v_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>, Thunk<'t, O3>) -> R,
+    B: Fn(X2) -> O2,
+    C: Fn(X1) -> O1,
+    D: Fn(X3) -> O3
+{
+    move |x1, x2, x3| a(Thunk::new(|| c(x1)), Thunk::new(|| b(x2)), Thunk::new(|| d(x3)))
+}
+
+/// I** = Identity Bird Twice Removed
+/// 
+/// λabc.abc
+/// 
+/// returns a(b(x1), c(x2))
+pub fn lazy_i_star_star<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1, X2) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X2) -> O2
+{
+    move |x1, x2| a(Thunk::new(|| b(x1)), Thunk::new(|| c(x2)))
+}
+
+/// W** = Warbler Twice Removed
+/// 
+/// λabcd.abcdd
+/// 
+/// returns a(b(x1), c(x2), d(x3), d(x4))
+pub fn lazy_w_star_star<A, B, C, D, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2, X3, X4) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>, Thunk<'t, O3>, Thunk<'t, O4>) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X2) -> O2,
+    D: Fn(X3) -> O3 + Fn(X4) -> O4
+{
+    move |x1, x2, x3, x4| a(Thunk::new(|| b(x1)), Thunk::new(|| c(x2)), Thunk::new(|| d(x3)), Thunk::new(|| d(x4)))
+}
+
+/// C** = Cardinal Twice Removed
+/// 
+/// λabcde.abced
+/// 
+/// returns a(b(x1), c(x2), e(x3), d(x4))
+pub fn lazy_c_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl Fn(X1, X2, X3, X4) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>, Thunk<'t, O3>, Thunk<'t, O4>) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X2) -> O2,
+    D: Fn(X4) -> O4,
+    E: Fn(X3) -> O3
+{
+    move |x1, x2, x3, x4| a(Thunk::new(|| b(x1)), Thunk::new(|| c(x2)), Thunk::new(|| e(x3)), Thunk::new(|| d(x4)))
+}
+
+/// R** = Robin Twice Removed
+/// 
+/// λabcde.abdec
+/// 
+/// returns a(b(x1), d(x2), e(x3), c(x4))
+pub fn lazy_r_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl Fn(X1, X2, X3, X4) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>, Thunk<'t, O3>, Thunk<'t, O4>) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X4) -> O4,
+    D: Fn(X2) -> O2,
+    E: Fn(X3) -> O3
+{
+    move |x1, x2, x3, x4| a(Thunk::new(|| b(x1)), Thunk::new(|| d(x2)), Thunk::new(|| e(x3)), Thunk::new(|| c(x4)))
+}
+
+/// F** = Finch Twice Removed
+/// 
+/// λabcde.abedc
+/// 
+/// returns a(b(x1), e(x2), d(x3), c(x4))
+pub fn lazy_f_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl Fn(X1, X2, X3, X4) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>, Thunk<'t, O3>, Thunk<'t, O4>) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X4) -> O4,
+    D: Fn(X3) -> O3,
+    E: Fn(X2) -> O2
+{
+    move |x1, x2, x3, x4| a(Thunk::new(|| b(x1)), Thunk::new(|| e(x2)), Thunk::new(|| d(x3)), Thunk::new(|| c(x4)))
+}
+
+/// V** = Vireo Twice Removed
+/// 
+/// λabcde.abecd
+/// 
+/// returns a(b(x1), e(x2), c(x3), d(x4))
+pub fn lazy_v_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl Fn(X1, X2, X3, X4) -> R
+where
+    A: for<'t> Fn(Thunk<'t, O1>, Thunk<'t, O2>, Thunk<'t, O3>, Thunk<'t, O4>) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(X3) -> O3,
+    D: Fn(X4) -> O4,
+    E: Fn(X2) -> O2
+{
+    move |x1, x2, x3, x4| a(Thunk::new(|| b(x1)), Thunk::new(|| e(x2)), Thunk::new(|| c(x3)), Thunk::new(|| d(x4)))
+}
+
+/// KI = Kite (False)
+/// 
+/// λab.b
+/// 
+/// returns b as a thunk, never evaluating a
+#[allow(unused)]
+pub fn lazy_ki<'a, A, B, T>(
+    a: A,
+    b: B
+) -> Thunk<'a, T>
+where
+    B: FnOnce() -> T + 'a
+{
+    Thunk::new(b)
+}
+
+/// KM = Constant Mocker
+/// 
+/// λab.bb
+/// 
+/// returns b(b(x1))
+#[allow(unused)]
+pub fn lazy_km<A, B, X1, O1, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1) -> R
+where
+    B: Fn(X1) -> O1 + for<'t> Fn(Thunk<'t, O1>) -> R
+{
+    move |x1| b(Thunk::new(|| b(x1)))
+}
+
+/// C(KM) = Crossed Constant Mocker
+/// 
+/// λab.aa
+/// 
+/// returns a(a(x1))
+#[allow(unused)]
+pub fn lazy_ckm<A, B, X1, O1, R>(
+    a: A,
+    b: B
+) -> impl Fn(X1) -> R
+where
+    A: Fn(X1) -> O1 + for<'t> Fn(Thunk<'t, O1>) -> R
+{
+    move |x1| a(Thunk::new(|| a(x1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn test_thunk()
+    {
+        let count = Cell::new(0);
+        let thunk = Thunk::new(|| {
+            count.set(count.get() + 1);
+            2
+        });
+
+        assert_eq!(count.get(), 0);
+        assert_eq!(*thunk + *thunk, 4);
+        assert_eq!(Thunk::into_inner(thunk), 2);
+        assert_eq!(count.get(), 1);
+
+        let cell = LazyCell::new(|| 3);
+        LazyCell::force(&cell);
+        let thunk = Thunk::from(cell);
+
+        // a cell which was evaluated stays evaluated
+        assert_eq!(format!("{thunk:?}"), "LazyCell(3)");
+        assert_eq!(thunk(), 3);
+
+        let cell = LazyCell::new(|| {
+            count.set(count.get() + 1);
+            4
+        });
+        let thunk = Thunk::from(cell);
+
+        assert_eq!(format!("{thunk:?}"), "LazyCell(<uninit>)");
+        assert_eq!(count.get(), 1);
+        assert_eq!(*thunk, 4);
+        assert_eq!(format!("{thunk:?}"), "LazyCell(4)");
+        assert_eq!(count.get(), 2);
+    }
+
+    #[test]
+    fn test_birds()
+    {
+        let a = |x: u8, y: u8| x.wrapping_mul(3).wrapping_add(y);
+        let b = |x: u8| x.wrapping_mul(5);
+        let c = |x: u8| x.wrapping_add(7);
+        let d = |x: u8| x.rotate_left(1);
+
+        let lazy_a = |x: Thunk<u8>, y: Thunk<u8>| a(*x, *y);
+        let lazy_b = |x: Thunk<u8>| b(*x);
+        let lazy_c = |x: Thunk<u8>| c(*x);
+
+        let bluebird = super::lazy_b(lazy_b, lazy_c, d);
+        let starling = lazy_s(lazy_a, lazy_b, c);
+        let warbler = lazy_w(lazy_a, b);
+        let jay = lazy_j(lazy_a, b, c, d);
+        let bald_eagle = lazy_ê(lazy_a, lazy_a, b, c, lazy_a, d, b);
+
+        for i in 0..=255
+        {
+            assert_eq!(bluebird(i), b(c(d(i))));
+            for j in (0..=255).step_by(15)
+            {
+                assert_eq!(starling(i, j), a(c(i), b(c(j))));
+                assert_eq!(warbler(i, j), a(b(i), b(j)));
+                assert_eq!(jay(i, j, i), a(b(i), a(d(j), c(i))));
+                assert_eq!(bald_eagle(i, j, j, i), a(a(b(i), c(j)), a(d(j), b(i))));
+            }
+        }
+    }
+
+    #[test]
+    fn test_call_by_need()
+    {
+        let calls = Cell::new(0);
+        let b = |x: u8| {
+            calls.set(calls.get() + 1);
+            x*2
+        };
+        let choose = |x: Thunk<u8>, condition: Thunk<bool>, y: Thunk<u8>| if *condition {*x + *x} else {*y};
+
+        let hummingbird = lazy_h(choose, b, |x: u8| x > 2);
+
+        // only the branch which is chosen is computed, once
+        assert_eq!(hummingbird(1, 3, 5), 4);
+        assert_eq!(calls.take(), 1);
+        assert_eq!(hummingbird(1, 2, 5), 10);
+        assert_eq!(calls.take(), 1);
+    }
+
+    #[test]
+    fn test_discard()
+    {
+        let never = || -> u8 { panic!("evaluated") };
+
+        assert_eq!(Thunk::into_inner(lazy_k(|| 1, never)), 1);
+        assert_eq!(Thunk::into_inner(lazy_ki(never, || 2)), 2);
+
+        let unevaluated = lazy_k(never, never);
+        drop(unevaluated);
+
+        let second = |_: Thunk<u8>, y: Thunk<u8>| *y;
+
+        assert_eq!(lazy_d(second, |_: u8| never(), |x: Thunk<u8>| *x + 1, |x: u8| x)(1, 2), 3);
+    }
+
+    #[test]
+    fn test_fixed_point()
+    {
+        let calls = Cell::new(0);
+        let a = |x: Thunk<u8>| {
+            calls.set(calls.get() + 1);
+            if calls.get() < 3 {*x + 1} else {0}
+        };
+
+        // Ya is forced until a stops recursing
+        assert_eq!(lazy_y(&a), 2);
+        assert_eq!(calls.get(), 3);
+        assert_eq!(lazy_y(&|_: Thunk<u8>| 1), 1)
+    }
+}
@@ -3,6 +3,10 @@
 #![feature(const_trait_impl)]
 #![feature(const_precise_live_drops)]
 #![feature(type_alias_impl_trait)]
+#![feature(tuple_trait)]
+#![feature(try_trait_v2)]
+#![feature(future_join)]
+#![feature(lazy_cell_into_inner)]
 
 // for test
 #![feature(fn_traits)]
@@ -13,10 +17,41 @@
 //! Combinator birds implemented in rust, using currying composition.
 //! 
 //! Functions are to be passed into the bird functions, yielding a function-composition.
+//! 
+//! # Interpretations
+//! 
+//! A composition can be called in more than one way, so each bird lists the possible interpretations of its λ-body.
+//! The first of them reads each variable followed by arguments as a call with all of them,
+//! and each variable on its own as a call with the next input, so S, λabc.ac(bc), returns a(c(x1), b(c(x2))).
+//! 
+//! The birds of [once], [mutable], [lazy], [asynchronous], [fallible] and [parallel] all read their λ-body this way.
+//! 
+//! Y, Θ and Ω never return when the arguments they compute are evaluated: Y and Θ call a on the result of a forever,
+//! and Ω loops without calling anything. So they are missing from the modules which evaluate each argument as it is passed,
+//! while [lazy] has Y, since a [Thunk](lazy::Thunk) only recurses when it is forced.
 
 use currycompose::*;
 use currying::*;
 
+pub mod term;
+pub mod lambda;
+pub mod symbol;
+pub mod curried;
+pub mod named;
+pub mod traced;
+pub mod fallible;
+pub mod asynchronous;
+pub mod mutable;
+pub mod once;
+pub mod shared;
+pub mod parallel;
+pub mod lazy;
+pub mod memo;
+pub mod arrow;
+pub mod category;
+pub mod reader;
+pub mod arity;
+
 /// B = Bluebird
 /// 
 /// λabc.a(bc)
@@ -0,0 +1,602 @@
+//! Memoization
+//!
+//! A [Memo](Memo) wraps a function with a [Cache](Cache) of the outputs it returned, keyed by its arguments,
+//! so that a pipeline called again with the same inputs, or a component of it, isn't recomputed.
+//! The cache is either a [HashMap](HashMap), which is unbounded, or an [Lru](Lru), which keeps the most recently used outputs.
+//!
+//! ```rust
+//! use birbs::memo::{Memo, Lru};
+//!
+//! let f = birbs::b(|x: u64| x + 1, |x: u64| x*x, |x: u64| x.pow(3));
+//! let f = Memo::with_cache(f, Lru::new(64));
+//!
+//! assert_eq!(f(2), 65);
+//! assert_eq!(Memo::cache(&f).len(), 1);
+//! ```
+//!
+//! J, L, M, M², H, O, S, U, W, W¹, W*, W**, KM and C(KM) use an argument twice.
+//! Their memoizing variants here wrap it in a [Memo](Memo) shared by its uses, through [Rc](Rc) as in [shared](crate::shared),
+//! so that a shared argument given the same input twice computes its output once.
+//!
+//! ```rust
+//! use std::cell::Cell;
+//! use birbs::memo::memo_w;
+//!
+//! let calls = Cell::new(0);
+//! let square = |x: u64| {
+//!     calls.set(calls.get() + 1);
+//!     x*x
+//! };
+//!
+//! let f = memo_w(|x: u64, y: u64| x + y, square);
+//!
+//! assert_eq!(f(3, 3), 18);
+//! assert_eq!(calls.get(), 1);
+//! ```
+//!
+//! The cache is behind a [RefCell](std::cell::RefCell), so a [Memo](Memo) can't be shared between threads.
+
+use std::cell::{Ref, RefCell};
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+use std::marker::Tuple;
+use std::rc::Rc;
+
+use currycompose::*;
+
+use crate::shared::{self, Shared};
+
+/// A store of the outputs of a function, keyed by its arguments
+pub trait Cache<K, V>
+{
+    /// The output cached for the arguments, if any
+    fn get(&mut self, key: &K) -> Option<&V>;
+
+    /// Caches the output for the arguments
+    fn insert(&mut self, key: K, value: V);
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Cache<K, V> for HashMap<K, V, S>
+{
+    fn get(&mut self, key: &K) -> Option<&V>
+    {
+        HashMap::get(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V)
+    {
+        HashMap::insert(self, key, value);
+    }
+}
+
+/// A cache holding at most a given number of outputs, evicting the least recently used one to make room
+#[derive(Clone, Debug)]
+pub struct Lru<K, V>
+{
+    capacity: usize,
+    entries: HashMap<K, (V, u64)>,
+    order: BTreeMap<u64, K>,
+    time: u64
+}
+
+impl<K, V> Lru<K, V>
+{
+    /// An empty cache, holding at most `capacity` outputs
+    pub fn new(capacity: usize) -> Self
+    {
+        Lru {
+            capacity,
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            time: 0
+        }
+    }
+
+    /// The most outputs it holds
+    pub fn capacity(&self) -> usize
+    {
+        self.capacity
+    }
+
+    /// The number of outputs it holds
+    pub fn len(&self) -> usize
+    {
+        self.entries.len()
+    }
+
+    /// Whether it holds no outputs
+    pub fn is_empty(&self) -> bool
+    {
+        self.entries.is_empty()
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> Cache<K, V> for Lru<K, V>
+{
+    fn get(&mut self, key: &K) -> Option<&V>
+    {
+        let (value, used) = self.entries.get_mut(key)?;
+        self.time += 1;
+        let key = self.order.remove(used)
+            .expect("every entry is in the order");
+        *used = self.time;
+        self.order.insert(self.time, key);
+        Some(value)
+    }
+
+    fn insert(&mut self, key: K, value: V)
+    {
+        if self.capacity == 0
+        {
+            return
+        }
+        self.time += 1;
+        match self.entries.insert(key.clone(), (value, self.time))
+        {
+            Some((_, used)) => {
+                self.order.remove(&used);
+            },
+            None => if self.entries.len() > self.capacity
+            {
+                if let Some((_, oldest)) = self.order.pop_first()
+                {
+                    self.entries.remove(&oldest);
+                }
+            }
+        }
+        self.order.insert(self.time, key);
+    }
+}
+
+/// A function which caches its outputs
+/// 
+/// Calling it with arguments it was called with before returns a clone of the output it returned then, without calling the function.
+/// Its [Debug](std::fmt::Debug) is the one of the function, so that it is named as the function in a [Pipeline](crate::named::Pipeline).
+#[derive(Clone)]
+pub struct Memo<F, C>
+{
+    function: F,
+    cache: RefCell<C>
+}
+
+/// A function behind a [Memo](Memo) with an unbounded cache, shared through [Rc](Rc)
+pub type Memoized<F, X, O> = Shared<Rc<Memo<F, HashMap<X, O>>>>;
+
+/// Memoizes a function in an unbounded [HashMap](HashMap)
+pub fn memo<F, X, O>(function: F) -> Memo<F, HashMap<X, O>>
+where
+    F: Fn<X, Output = O>,
+    X: Tuple
+{
+    Memo::with_cache(function, HashMap::new())
+}
+
+impl<F, C> Memo<F, C>
+{
+    /// Memoizes a function in the given cache
+    pub fn with_cache(function: F, cache: C) -> Self
+    {
+        Memo {
+            function,
+            cache: RefCell::new(cache)
+        }
+    }
+
+    /// The cache of the outputs
+    pub fn cache(this: &Self) -> Ref<'_, C>
+    {
+        this.cache.borrow()
+    }
+}
+
+impl<F: std::fmt::Debug, C> std::fmt::Debug for Memo<F, C>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        self.function.fmt(f)
+    }
+}
+
+impl<F, C, X> FnOnce<X> for Memo<F, C>
+where
+    F: Fn<X>,
+    F::Output: Clone,
+    C: Cache<X, F::Output>,
+    X: Tuple + Clone
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(self, args: X) -> Self::Output
+    {
+        self.call(args)
+    }
+}
+
+impl<F, C, X> FnMut<X> for Memo<F, C>
+where
+    F: Fn<X>,
+    F::Output: Clone,
+    C: Cache<X, F::Output>,
+    X: Tuple + Clone
+{
+    extern "rust-call" fn call_mut(&mut self, args: X) -> Self::Output
+    {
+        self.call(args)
+    }
+}
+
+impl<F, C, X> Fn<X> for Memo<F, C>
+where
+    F: Fn<X>,
+    F::Output: Clone,
+    C: Cache<X, F::Output>,
+    X: Tuple + Clone
+{
+    extern "rust-call" fn call(&self, args: X) -> Self::Output
+    {
+        if let Some(output) = self.cache.borrow_mut().get(&args)
+        {
+            return output.clone()
+        }
+        // the cache isn't borrowed during the call, so that the function may call the memo again
+        let output = self.function.call(args.clone());
+        self.cache.borrow_mut().insert(args, output.clone());
+        output
+    }
+}
+
+/// J = Jay
+/// 
+/// λabcd.ab(adc)
+/// 
+/// [j](crate::j), sharing a single [Memo](Memo) of `a` between its uses
+pub fn memo_j<A, B, C, D, XA, OA, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> Composition<Composition<Memoized<A, XA, OA>, B, X1A, X1B>, Composition<Composition<Memoized<A, XA, OA>, D, X2A, X2B>, C, X3A, X3B>, X4A, X4B>
+where
+    A: Fn<XA, Output = OA>,
+    XA: Tuple,
+    Memoized<A, XA, OA>: Compose<B, X1A, X1B> + Compose<D, X2A, X2B>,
+    Composition<Memoized<A, XA, OA>, D, X2A, X2B>: Compose<C, X3A, X3B>,
+    Composition<Memoized<A, XA, OA>, B, X1A, X1B>: Compose<Composition<Composition<Memoized<A, XA, OA>, D, X2A, X2B>, C, X3A, X3B>, X4A, X4B>
+{
+    shared::j_clone(Shared(Rc::new(memo(a))), b, c, d)
+}
+
+/// L = Lark
+/// 
+/// λab.a(bb)
+/// 
+/// [l](crate::l), sharing a single [Memo](Memo) of `b` between its uses
+pub fn memo_l<A, B, XB, OB, X1A, X1B, X2A, X2B>(
+    a: A,
+    b: B
+) -> Composition<A, Composition<Memoized<B, XB, OB>, Memoized<B, XB, OB>, X1A, X1B>, X2A, X2B>
+where
+    B: Fn<XB, Output = OB>,
+    XB: Tuple,
+    Memoized<B, XB, OB>: Compose<Memoized<B, XB, OB>, X1A, X1B>,
+    A: Compose<Composition<Memoized<B, XB, OB>, Memoized<B, XB, OB>, X1A, X1B>, X2A, X2B>
+{
+    shared::l_clone(a, Shared(Rc::new(memo(b))))
+}
+
+/// M = Mockingbird
+/// 
+/// λa.aa
+/// 
+/// [m](crate::m), sharing a single [Memo](Memo) of `a` between its uses
+pub fn memo_m<A, XA, OA, X1A, X1B>(
+    a: A
+) -> Composition<Memoized<A, XA, OA>, Memoized<A, XA, OA>, X1A, X1B>
+where
+    A: Fn<XA, Output = OA>,
+    XA: Tuple,
+    Memoized<A, XA, OA>: Compose<Memoized<A, XA, OA>, X1A, X1B>
+{
+    shared::m_clone(Shared(Rc::new(memo(a))))
+}
+
+/// M² = Double Mockingbird
+/// 
+/// λab.ab(ab)
+/// 
+/// [m2](crate::m2), sharing a single [Memo](Memo) of each of `a` and `b` between their uses
+pub fn memo_m2<A, B, XA, OA, XB, OB, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B
+) -> Composition<Composition<Memoized<A, XA, OA>, Memoized<B, XB, OB>, X1A, X1B>, Composition<Memoized<A, XA, OA>, Memoized<B, XB, OB>, X2A, X2B>, X3A, X3B>
+where
+    A: Fn<XA, Output = OA>,
+    XA: Tuple,
+    B: Fn<XB, Output = OB>,
+    XB: Tuple,
+    Memoized<A, XA, OA>: Compose<Memoized<B, XB, OB>, X1A, X1B> + Compose<Memoized<B, XB, OB>, X2A, X2B>,
+    Composition<Memoized<A, XA, OA>, Memoized<B, XB, OB>, X1A, X1B>: Compose<Composition<Memoized<A, XA, OA>, Memoized<B, XB, OB>, X2A, X2B>, X3A, X3B>
+{
+    shared::m2_clone(Shared(Rc::new(memo(a))), Shared(Rc::new(memo(b))))
+}
+
+/// H = Hummingbird
+/// 
+/// λabc.abcb
+/// 
+/// [h](crate::h), sharing a single [Memo](Memo) of `b` between its uses
+pub fn memo_h<A, B, C, XB, OB, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B,
+    c: C
+) -> Composition<Composition<Composition<A, Memoized<B, XB, OB>, X1A, X1B>, C, X2A, X2B>, Memoized<B, XB, OB>, X3A, X3B>
+where
+    B: Fn<XB, Output = OB>,
+    XB: Tuple,
+    A: Compose<Memoized<B, XB, OB>, X1A, X1B>,
+    Composition<A, Memoized<B, XB, OB>, X1A, X1B>: Compose<C, X2A, X2B>,
+    Composition<Composition<A, Memoized<B, XB, OB>, X1A, X1B>, C, X2A, X2B>: Compose<Memoized<B, XB, OB>, X3A, X3B>
+{
+    shared::h_clone(a, Shared(Rc::new(memo(b))), c)
+}
+
+/// U = Turing
+/// 
+/// λab.b(aab)
+/// 
+/// [u](crate::u), sharing a single [Memo](Memo) of each of `a` and `b` between their uses
+pub fn memo_u<A, B, XA, OA, XB, OB, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B
+) -> Composition<Memoized<B, XB, OB>, Composition<Composition<Memoized<A, XA, OA>, Memoized<A, XA, OA>, X1A, X1B>, Memoized<B, XB, OB>, X2A, X2B>, X3A, X3B>
+where
+    A: Fn<XA, Output = OA>,
+    XA: Tuple,
+    B: Fn<XB, Output = OB>,
+    XB: Tuple,
+    Memoized<A, XA, OA>: Compose<Memoized<A, XA, OA>, X1A, X1B>,
+    Composition<Memoized<A, XA, OA>, Memoized<A, XA, OA>, X1A, X1B>: Compose<Memoized<B, XB, OB>, X2A, X2B>,
+    Memoized<B, XB, OB>: Compose<Composition<Composition<Memoized<A, XA, OA>, Memoized<A, XA, OA>, X1A, X1B>, Memoized<B, XB, OB>, X2A, X2B>, X3A, X3B>
+{
+    shared::u_clone(Shared(Rc::new(memo(a))), Shared(Rc::new(memo(b))))
+}
+
+/// W = Warbler
+/// 
+/// λab.abb
+/// 
+/// [w](crate::w), sharing a single [Memo](Memo) of `b` between its uses
+pub fn memo_w<A, B, XB, OB, X1A, X1B, X2A, X2B>(
+    a: A,
+    b: B
+) -> Composition<Composition<A, Memoized<B, XB, OB>, X1A, X1B>, Memoized<B, XB, OB>, X2A, X2B>
+where
+    B: Fn<XB, Output = OB>,
+    XB: Tuple,
+    A: Compose<Memoized<B, XB, OB>, X1A, X1B>,
+    Composition<A, Memoized<B, XB, OB>, X1A, X1B>: Compose<Memoized<B, XB, OB>, X2A, X2B>
+{
+    shared::w_clone(a, Shared(Rc::new(memo(b))))
+}
+
+/// W¹ = Converse Warbler
+/// 
+/// λab.baa
+/// 
+/// [w1](crate::w1), sharing a single [Memo](Memo) of `a` between its uses
+pub fn memo_w1<A, B, XA, OA, X1A, X1B, X2A, X2B>(
+    a: A,
+    b: B
+) -> Composition<Composition<B, Memoized<A, XA, OA>, X1A, X1B>, Memoized<A, XA, OA>, X2A, X2B>
+where
+    A: Fn<XA, Output = OA>,
+    XA: Tuple,
+    B: Compose<Memoized<A, XA, OA>, X1A, X1B>,
+    Composition<B, Memoized<A, XA, OA>, X1A, X1B>: Compose<Memoized<A, XA, OA>, X2A, X2B>
+{
+    shared::w1_clone(Shared(Rc::new(memo(a))), b)
+}
+
+/// W* = Warbled Once Removed
+/// 
+/// λabc.abcc
+/// 
+/// [w_star](crate::w_star), sharing a single [Memo](Memo) of `c` between its uses
+pub fn memo_w_star<A, B, C, XC, OC, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B,
+    c: C
+) -> Composition<Composition<Composition<A, B, X1A, X1B>, Memoized<C, XC, OC>, X2A, X2B>, Memoized<C, XC, OC>, X3A, X3B>
+where
+    C: Fn<XC, Output = OC>,
+    XC: Tuple,
+    A: Compose<B, X1A, X1B>,
+    Composition<A, B, X1A, X1B>: Compose<Memoized<C, XC, OC>, X2A, X2B>,
+    Composition<Composition<A, B, X1A, X1B>, Memoized<C, XC, OC>, X2A, X2B>: Compose<Memoized<C, XC, OC>, X3A, X3B>
+{
+    shared::w_star_clone(a, b, Shared(Rc::new(memo(c))))
+}
+
+/// W** = Warbler Twice Removed
+/// 
+/// λabcd.abcdd
+/// 
+/// [w_star_star](crate::w_star_star), sharing a single [Memo](Memo) of `d` between its uses
+pub fn memo_w_star_star<A, B, C, D, XD, OD, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> Composition<Composition<Composition<Composition<A, B, X1A, X1B>, C, X2A, X2B>, Memoized<D, XD, OD>, X3A, X3B>, Memoized<D, XD, OD>, X4A, X4B>
+where
+    D: Fn<XD, Output = OD>,
+    XD: Tuple,
+    A: Compose<B, X1A, X1B>,
+    Composition<A, B, X1A, X1B>: Compose<C, X2A, X2B>,
+    Composition<Composition<A, B, X1A, X1B>, C, X2A, X2B>: Compose<Memoized<D, XD, OD>, X3A, X3B>,
+    Composition<Composition<Composition<A, B, X1A, X1B>, C, X2A, X2B>, Memoized<D, XD, OD>, X3A, X3B>: Compose<Memoized<D, XD, OD>, X4A, X4B>
+{
+    shared::w_star_star_clone(a, b, c, Shared(Rc::new(memo(d))))
+}
+
+/// KM = Constant Mocker
+/// 
+/// λab.bb
+/// 
+/// [km](crate::km), sharing a single [Memo](Memo) of `b` between its uses
+#[allow(unused)]
+pub fn memo_km<A, B, XB, OB, X1A, X1B>(
+    a: A,
+    b: B
+) -> Composition<Memoized<B, XB, OB>, Memoized<B, XB, OB>, X1A, X1B>
+where
+    B: Fn<XB, Output = OB>,
+    XB: Tuple,
+    Memoized<B, XB, OB>: Compose<Memoized<B, XB, OB>, X1A, X1B>
+{
+    shared::km_clone(a, Shared(Rc::new(memo(b))))
+}
+
+/// C(KM) = Crossed Constant Mocker
+/// 
+/// λab.aa
+/// 
+/// [ckm](crate::ckm), sharing a single [Memo](Memo) of `a` between its uses
+#[allow(unused)]
+pub fn memo_ckm<A, B, XA, OA, X1A, X1B>(
+    a: A,
+    b: B
+) -> Composition<Memoized<A, XA, OA>, Memoized<A, XA, OA>, X1A, X1B>
+where
+    A: Fn<XA, Output = OA>,
+    XA: Tuple,
+    Memoized<A, XA, OA>: Compose<Memoized<A, XA, OA>, X1A, X1B>
+{
+    shared::ckm_clone(Shared(Rc::new(memo(a))), b)
+}
+
+/// O = Owl
+/// 
+/// λab.b(ab)
+/// 
+/// [o](crate::o), sharing a single [Memo](Memo) of `b` between its uses
+pub fn memo_o<A, B, XB, OB, X1A, X1B, X2A, X2B>(
+    a: A,
+    b: B
+) -> Composition<Memoized<B, XB, OB>, Composition<A, Memoized<B, XB, OB>, X1A, X1B>, X2A, X2B>
+where
+    B: Fn<XB, Output = OB>,
+    XB: Tuple,
+    A: Compose<Memoized<B, XB, OB>, X1A, X1B>,
+    Memoized<B, XB, OB>: Compose<Composition<A, Memoized<B, XB, OB>, X1A, X1B>, X2A, X2B>
+{
+    shared::o_clone(a, Shared(Rc::new(memo(b))))
+}
+
+/// S = Starling
+/// 
+/// λabc.ac(bc)
+/// 
+/// [s](crate::s), sharing a single [Memo](Memo) of `c` between its uses
+pub fn memo_s<A, B, C, XC, OC, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B,
+    c: C
+) -> Composition<Composition<A, Memoized<C, XC, OC>, X1A, X1B>, Composition<B, Memoized<C, XC, OC>, X2A, X2B>, X3A, X3B>
+where
+    C: Fn<XC, Output = OC>,
+    XC: Tuple,
+    A: Compose<Memoized<C, XC, OC>, X1A, X1B>,
+    B: Compose<Memoized<C, XC, OC>, X2A, X2B>,
+    Composition<A, Memoized<C, XC, OC>, X1A, X1B>: Compose<Composition<B, Memoized<C, XC, OC>, X2A, X2B>, X3A, X3B>
+{
+    shared::s_clone(a, b, Shared(Rc::new(memo(c))))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn test_memo()
+    {
+        let calls = Cell::new(0);
+        let f = memo(|x: u64, y: u64| {
+            calls.set(calls.get() + 1);
+            x.pow(y as u32)
+        });
+
+        assert_eq!(f(2, 10), 1024);
+        assert_eq!(f(2, 10), 1024);
+        assert_eq!(f(10, 2), 100);
+        assert_eq!(calls.get(), 2);
+        assert_eq!(Memo::cache(&f).len(), 2);
+    }
+
+    #[test]
+    fn test_lru()
+    {
+        let mut lru = Lru::new(2);
+
+        lru.insert(1, "a");
+        lru.insert(2, "b");
+        assert_eq!(lru.get(&1), Some(&"a"));
+        // 2 is the least recently used
+        lru.insert(3, "c");
+        assert_eq!(lru.get(&2), None);
+        assert_eq!(lru.get(&1), Some(&"a"));
+        assert_eq!(lru.get(&3), Some(&"c"));
+        lru.insert(3, "d");
+        lru.insert(4, "e");
+        assert_eq!(lru.get(&1), None);
+        assert_eq!(lru.get(&3), Some(&"d"));
+        assert_eq!(lru.len(), 2);
+
+        let mut empty = Lru::new(0);
+
+        empty.insert(1, "a");
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_birds()
+    {
+        let calls = Cell::new(0);
+        let square = |x: u64| {
+            calls.set(calls.get() + 1);
+            x*x
+        };
+        let add = |x: u64, y: u64| x + y;
+
+        let warbler = memo_w(add, square);
+
+        assert_eq!(warbler(3, 3), 18);
+        assert_eq!(calls.take(), 1);
+        assert_eq!(warbler(3, 4), 25);
+        assert_eq!(calls.take(), 1);
+
+        let mockingbird = memo_m(square);
+
+        assert_eq!(mockingbird(3), 81);
+        // square(9) is only computed the first time
+        assert_eq!(mockingbird(9), 6561);
+        assert_eq!(calls.take(), 3);
+
+        let owl = memo_o(|x: u64| x.isqrt(), square);
+
+        // b(a(b(x))), with b given 3 both times
+        assert_eq!(owl(3), 9);
+        assert_eq!(calls.take(), 1);
+
+        let starling = memo_s(add, |x: u64| x + 1, square);
+
+        // a(c(x), b(c(y))), with c given 3 both times
+        assert_eq!(starling(3, 3), 19);
+        assert_eq!(calls.take(), 1);
+        assert_eq!(starling(3, 2), 14);
+        // c(3) was computed already
+        assert_eq!(calls.take(), 1);
+    }
+}
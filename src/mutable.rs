@@ -0,0 +1,1015 @@
+//! Birds over stateful closures
+//!
+//! The birds of the crate root compose functions which are [Fn](Fn) in practice, and copy the ones they duplicate.
+//! Here each function is [FnMut](FnMut), such as a counter or a filter keeping state, and a bird composes them into an [FnMut](FnMut).
+//!
+//! A bird reads its λ-body by the [first of the possible interpretations](crate#interpretations).
+//! The arguments are evaluated from left to right, so the closures are called in the order given for each bird.
+//!
+//! A bird which duplicates an argument, such as W, H, S or J, calls that one closure once for each time it occurs,
+//! so each call sees the state left behind by the call before it. Nothing is cloned, and nothing is required to be [Clone](Clone).
+//! Where a closure occurs in its own arguments, as in J, M or U, its arguments are evaluated before it is called.
+//!
+//! ```rust
+//! use birbs::mutable::mut_w;
+//!
+//! let mut count = 0;
+//! let next = |x: u32| {
+//!     count += 1;
+//!     x*10 + count
+//! };
+//!
+//! let mut f = mut_w(|x, y| (x, y), next);
+//!
+//! assert_eq!(f(1, 2), (11, 22));
+//! assert_eq!(f(1, 2), (13, 24));
+//! ```
+//!
+//! Y, Θ and Ω are [missing](crate#interpretations), since they never return.
+
+/// B = Bluebird
+/// 
+/// λabc.a(bc)
+/// 
+/// returns a(b(c(x1))), calling c, b, a in that order
+pub fn mut_b<A, B, C, X1, O1, O2, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C
+) -> impl FnMut(X1) -> R
+where
+    A: FnMut(O2) -> R,
+    B: FnMut(O1) -> O2,
+    C: FnMut(X1) -> O1
+{
+    move |x1| a(b(c(x1)))
+}
+
+/// B¹ = Blackbird
+/// 
+/// λabcd.a(bcd)
+/// 
+/// returns a(b(c(x1), d(x2))), calling c, d, b, a in that order
+pub fn mut_b1<A, B, C, D, X1, X2, O1, O2, O3, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C,
+    mut d: D
+) -> impl FnMut(X1, X2) -> R
+where
+    A: FnMut(O3) -> R,
+    B: FnMut(O1, O2) -> O3,
+    C: FnMut(X1) -> O1,
+    D: FnMut(X2) -> O2
+{
+    move |x1, x2| a(b(c(x1), d(x2)))
+}
+
+/// B² = Bunting
+/// 
+/// λabcde.a(bcde)
+/// 
+/// returns a(b(c(x1), d(x2), e(x3))), calling c, d, e, b, a in that order
+pub fn mut_b2<A, B, C, D, E, X1, X2, X3, O1, O2, O3, O4, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C,
+    mut d: D,
+    mut e: E
+) -> impl FnMut(X1, X2, X3) -> R
+where
+    A: FnMut(O4) -> R,
+    B: FnMut(O1, O2, O3) -> O4,
+    C: FnMut(X1) -> O1,
+    D: FnMut(X2) -> O2,
+    E: FnMut(X3) -> O3
+{
+    move |x1, x2, x3| a(b(c(x1), d(x2), e(x3)))
+}
+
+/// B³ = Becard
+/// 
+/// λabcd.a(b(cd))
+/// 
+/// returns a(b(c(d(x1)))), calling d, c, b, a in that order
+pub fn mut_b3<A, B, C, D, X1, O1, O2, O3, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C,
+    mut d: D
+) -> impl FnMut(X1) -> R
+where
+    A: FnMut(O3) -> R,
+    B: FnMut(O2) -> O3,
+    C: FnMut(O1) -> O2,
+    D: FnMut(X1) -> O1
+{
+    move |x1| a(b(c(d(x1))))
+}
+
+/// C = Cardinal
+/// 
+/// λabc.acb
+/// 
+/// returns a(c(x1), b(x2)), calling c, b, a in that order
+pub fn mut_c<A, B, C, X1, X2, O1, O2, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C
+) -> impl FnMut(X1, X2) -> R
+where
+    A: FnMut(O1, O2) -> R,
+    B: FnMut(X2) -> O2,
+    C: FnMut(X1) -> O1
+{
+    move |x1, x2| a(c(x1), b(x2))
+}
+
+/// D = Dove
+/// 
+/// λabcd.ab(cd)
+/// 
+/// returns a(b(x1), c(d(x2))), calling b, d, c, a in that order
+pub fn mut_d<A, B, C, D, X1, X2, O1, O2, O3, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C,
+    mut d: D
+) -> impl FnMut(X1, X2) -> R
+where
+    A: FnMut(O1, O3) -> R,
+    B: FnMut(X1) -> O1,
+    C: FnMut(O2) -> O3,
+    D: FnMut(X2) -> O2
+{
+    move |x1, x2| a(b(x1), c(d(x2)))
+}
+
+/// D¹ = Dickcissel
+/// 
+/// λabcde.abc(de)
+/// 
+/// returns a(b(x1), c(x2), d(e(x3))), calling b, c, e, d, a in that order
+pub fn mut_d1<A, B, C, D, E, X1, X2, X3, O1, O2, O3, O4, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C,
+    mut d: D,
+    mut e: E
+) -> impl FnMut(X1, X2, X3) -> R
+where
+    A: FnMut(O1, O2, O4) -> R,
+    B: FnMut(X1) -> O1,
+    C: FnMut(X2) -> O2,
+    D: FnMut(O3) -> O4,
+    E: FnMut(X3) -> O3
+{
+    move |x1, x2, x3| a(b(x1), c(x2), d(e(x3)))
+}
+
+/// D² = Dovekies
+/// 
+/// λabcde.a(bc)(de)
+/// 
+/// returns a(b(c(x1)), d(e(x2))), calling c, b, e, d, a in that order
+pub fn mut_d2<A, B, C, D, E, X1, X2, O1, O2, O3, O4, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C,
+    mut d: D,
+    mut e: E
+) -> impl FnMut(X1, X2) -> R
+where
+    A: FnMut(O2, O4) -> R,
+    B: FnMut(O1) -> O2,
+    C: FnMut(X1) -> O1,
+    D: FnMut(O3) -> O4,
+    E: FnMut(X2) -> O3
+{
+    move |x1, x2| a(b(c(x1)), d(e(x2)))
+}
+
+/// E = Eagle
+/// 
+/// λabcde.ab(cde)
+/// 
+/// returns a(b(x1), c(d(x2), e(x3))), calling b, d, e, c, a in that order
+pub fn mut_e<A, B, C, D, E, X1, X2, X3, O1, O2, O3, O4, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C,
+    mut d: D,
+    mut e: E
+) -> impl FnMut(X1, X2, X3) -> R
+where
+    A: FnMut(O1, O4) -> R,
+    B: FnMut(X1) -> O1,
+    C: FnMut(O2, O3) -> O4,
+    D: FnMut(X2) -> O2,
+    E: FnMut(X3) -> O3
+{
+    move |x1, x2, x3| a(b(x1), c(d(x2), e(x3)))
+}
+
+/// Ê = Bald Eagle
+/// 
+/// λabcdefg.a(bcd)(efg)
+/// 
+/// returns a(b(c(x1), d(x2)), e(f(x3), g(x4))), calling c, d, b, f, g, e, a in that order
+pub fn mut_ê<A, B, C, D, E, F, G, X1, X2, X3, X4, O1, O2, O3, O4, O5, O6, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C,
+    mut d: D,
+    mut e: E,
+    mut f: F,
+    mut g: G
+) -> impl FnMut(X1, X2, X3, X4) -> R
+where
+    A: FnMut(O3, O6) -> R,
+    B: FnMut(O1, O2) -> O3,
+    C: FnMut(X1) -> O1,
+    D: FnMut(X2) -> O2,
+    E: FnMut(O4, O5) -> O6,
+    F: FnMut(X3) -> O4,
+    G: FnMut(X4) -> O5
+{
+    move |x1, x2, x3, x4| a(b(c(x1), d(x2)), e(f(x3), g(x4)))
+}
+
+/// F = Finch
+/// 
+/// λabc.cba
+/// 
+/// returns c(b(x1), a(x2)), calling b, a, c in that order
+pub fn mut_f<A, B, C, X1, X2, O1, O2, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C
+) -> impl FnMut(X1, X2) -> R
+where
+    A: FnMut(X2) -> O2,
+    B: FnMut(X1) -> O1,
+    C: FnMut(O1, O2) -> R
+{
+    move |x1, x2| c(b(x1), a(x2))
+}
+
+/// G = Goldfinch
+/// 
+/// λabcd.ad(bc)
+/// 
+/// returns a(d(x1), b(c(x2))), calling d, c, b, a in that order
+pub fn mut_g<A, B, C, D, X1, X2, O1, O2, O3, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C,
+    mut d: D
+) -> impl FnMut(X1, X2) -> R
+where
+    A: FnMut(O1, O3) -> R,
+    B: FnMut(O2) -> O3,
+    C: FnMut(X2) -> O2,
+    D: FnMut(X1) -> O1
+{
+    move |x1, x2| a(d(x1), b(c(x2)))
+}
+
+/// H = Hummingbird
+/// 
+/// λabc.abcb
+/// 
+/// returns a(b(x1), c(x2), b(x3)), calling b, c, b, a in that order
+pub fn mut_h<A, B, C, X1, X2, X3, O1, O2, O3, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C
+) -> impl FnMut(X1, X2, X3) -> R
+where
+    A: FnMut(O1, O2, O3) -> R,
+    B: FnMut(X1) -> O1 + FnMut(X3) -> O3,
+    C: FnMut(X2) -> O2
+{
+    move |x1, x2, x3| a(b(x1), c(x2), b(x3))
+}
+
+/// I = Identity Bird aka Idiot
+/// 
+/// λa.a
+/// 
+/// returns a(x1), calling a in that order
+pub fn mut_i<A, X1, R>(
+    mut a: A
+) -> impl FnMut(X1) -> R
+where
+    A: FnMut(X1) -> R
+{
+    move |x1| a(x1)
+}
+
+/// J = Jay
+/// 
+/// λabcd.ab(adc)
+/// 
+/// returns a(b(x1), a(d(x2), c(x3))), calling b, d, c, a, a in that order
+pub fn mut_j<A, B, C, D, X1, X2, X3, O1, O2, O3, O4, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C,
+    mut d: D
+) -> impl FnMut(X1, X2, X3) -> R
+where
+    A: FnMut(O2, O3) -> O4 + FnMut(O1, O4) -> R,
+    B: FnMut(X1) -> O1,
+    C: FnMut(X3) -> O3,
+    D: FnMut(X2) -> O2
+{
+    move |x1, x2, x3| {
+        let y1 = b(x1);
+        let y2 = a(d(x2), c(x3));
+        a(y1, y2)
+    }
+}
+
+/// K = Kestrel (True)
+/// 
+/// λab.a
+/// 
+/// returns a(x1), calling a in that order
+#[allow(unused)]
+pub fn mut_k<A, B, X1, R>(
+    mut a: A,
+    b: B
+) -> impl FnMut(X1) -> R
+where
+    A: FnMut(X1) -> R
+{
+    move |x1| a(x1)
+}
+
+/// L = Lark
+/// 
+/// λab.a(bb)
+/// 
+/// returns a(b(b(x1))), calling b, b, a in that order
+pub fn mut_l<A, B, X1, O1, O2, R>(
+    mut a: A,
+    mut b: B
+) -> impl FnMut(X1) -> R
+where
+    A: FnMut(O2) -> R,
+    B: FnMut(X1) -> O1 + FnMut(O1) -> O2
+{
+    move |x1| {
+        let y1 = b(x1);
+        let y2 = b(y1);
+        a(y2)
+    }
+}
+
+/// M = Mockingbird
+/// 
+/// λa.aa
+/// 
+/// returns a(a(x1)), calling a, a in that order
+pub fn mut_m<A, X1, O1, R>(
+    mut a: A
+) -> impl FnMut(X1) -> R
+where
+    A: FnMut(X1) -> O1 + FnMut(O1) -> R
+{
+    move |x1| {
+        let y1 = a(x1);
+        a(y1)
+    }
+}
+
+/// M² = Double Mockingbird
+/// 
+/// λab.ab(ab)
+/// 
+/// returns a(b(x1), a(b(x2))), calling b, b, a, a in that order
+pub fn mut_m2<A, B, X1, X2, O1, O2, O3, R>(
+    mut a: A,
+    mut b: B
+) -> impl FnMut(X1, X2) -> R
+where
+    A: FnMut(O2) -> O3 + FnMut(O1, O3) -> R,
+    B: FnMut(X1) -> O1 + FnMut(X2) -> O2
+{
+    move |x1, x2| {
+        let y1 = b(x1);
+        let y2 = a(b(x2));
+        a(y1, y2)
+    }
+}
+
+/// O = Owl
+/// 
+/// λab.b(ab)
+/// 
+/// returns b(a(b(x1))), calling b, a, b in that order
+pub fn mut_o<A, B, X1, O1, O2, R>(
+    mut a: A,
+    mut b: B
+) -> impl FnMut(X1) -> R
+where
+    A: FnMut(O1) -> O2,
+    B: FnMut(X1) -> O1 + FnMut(O2) -> R
+{
+    move |x1| {
+        let y1 = a(b(x1));
+        b(y1)
+    }
+}
+
+/// Q = Queer Bird
+/// 
+/// λabc.b(ac)
+/// 
+/// returns b(a(c(x1))), calling c, a, b in that order
+pub fn mut_q<A, B, C, X1, O1, O2, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C
+) -> impl FnMut(X1) -> R
+where
+    A: FnMut(O1) -> O2,
+    B: FnMut(O2) -> R,
+    C: FnMut(X1) -> O1
+{
+    move |x1| b(a(c(x1)))
+}
+
+/// Q¹ = Quixotic Bird
+/// 
+/// λabc.a(cb)
+/// 
+/// returns a(c(b(x1))), calling b, c, a in that order
+pub fn mut_q1<A, B, C, X1, O1, O2, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C
+) -> impl FnMut(X1) -> R
+where
+    A: FnMut(O2) -> R,
+    B: FnMut(X1) -> O1,
+    C: FnMut(O1) -> O2
+{
+    move |x1| a(c(b(x1)))
+}
+
+/// Q² = Quizzical Bird
+/// 
+/// λabc.b(ca)
+/// 
+/// returns b(c(a(x1))), calling a, c, b in that order
+pub fn mut_q2<A, B, C, X1, O1, O2, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C
+) -> impl FnMut(X1) -> R
+where
+    A: FnMut(X1) -> O1,
+    B: FnMut(O2) -> R,
+    C: FnMut(O1) -> O2
+{
+    move |x1| b(c(a(x1)))
+}
+
+/// Q³ = Quirky Bird
+/// 
+/// λabc.c(ab)
+/// 
+/// returns c(a(b(x1))), calling b, a, c in that order
+pub fn mut_q3<A, B, C, X1, O1, O2, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C
+) -> impl FnMut(X1) -> R
+where
+    A: FnMut(O1) -> O2,
+    B: FnMut(X1) -> O1,
+    C: FnMut(O2) -> R
+{
+    move |x1| c(a(b(x1)))
+}
+
+/// Q⁴ = Quacky Bird
+/// 
+/// λabc.c(ba)
+/// 
+/// returns c(b(a(x1))), calling a, b, c in that order
+pub fn mut_q4<A, B, C, X1, O1, O2, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C
+) -> impl FnMut(X1) -> R
+where
+    A: FnMut(X1) -> O1,
+    B: FnMut(O1) -> O2,
+    C: FnMut(O2) -> R
+{
+    move |x1| c(b(a(x1)))
+}
+
+/// R = Robin
+/// 
+/// λabc.bca
+/// 
+/// returns b(c(x1), a(x2)), calling c, a, b in that order
+pub fn mut_r<A, B, C, X1, X2, O1, O2, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C
+) -> impl FnMut(X1, X2) -> R
+where
+    A: FnMut(X2) -> O2,
+    B: FnMut(O1, O2) -> R,
+    C: FnMut(X1) -> O1
+{
+    move |x1, x2| b(c(x1), a(x2))
+}
+
+/// S = Starling
+/// 
+/// λabc.ac(bc)
+/// 
+/// returns a(c(x1), b(c(x2))), calling c, c, b, a in that order
+pub fn mut_s<A, B, C, X1, X2, O1, O2, O3, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C
+) -> impl FnMut(X1, X2) -> R
+where
+    A: FnMut(O1, O3) -> R,
+    B: FnMut(O2) -> O3,
+    C: FnMut(X1) -> O1 + FnMut(X2) -> O2
+{
+    move |x1, x2| a(c(x1), b(c(x2)))
+}
+
+/// T = Thrush
+/// 
+/// λab.ba
+/// 
+/// returns b(a(x1)), calling a, b in that order
+pub fn mut_t<A, B, X1, O1, R>(
+    mut a: A,
+    mut b: B
+) -> impl FnMut(X1) -> R
+where
+    A: FnMut(X1) -> O1,
+    B: FnMut(O1) -> R
+{
+    move |x1| b(a(x1))
+}
+
+/// U = Turing
+/// 
+/// λab.b(aab)
+/// 
+/// returns b(a(a(x1), b(x2))), calling a, b, a, b in that order
+pub fn mut_u<A, B, X1, X2, O1, O2, O3, R>(
+    mut a: A,
+    mut b: B
+) -> impl FnMut(X1, X2) -> R
+where
+    A: FnMut(X1) -> O1 + FnMut(O1, O2) -> O3,
+    B: FnMut(X2) -> O2 + FnMut(O3) -> R
+{
+    move |x1, x2| {
+        let y1 = a(x1);
+        let y2 = b(x2);
+        let y3 = a(y1, y2);
+        b(y3)
+    }
+}
+
+/// V = Vireo aka Pairing
+/// 
+/// λabc.cab
+/// 
+/// returns c(a(x1), b(x2)), calling a, b, c in that order
+pub fn mut_v<A, B, C, X1, X2, O1, O2, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C
+) -> impl FnMut(X1, X2) -> R
+where
+    A: FnMut(X1) -> O1,
+    B: FnMut(X2) -> O2,
+    C: FnMut(O1, O2) -> R
+{
+    move |x1, x2| c(a(x1), b(x2))
+}
+
+/// W = Warbler
+/// 
+/// λab.abb
+/// 
+/// returns a(b(x1), b(x2)), calling b, b, a in that order
+pub fn mut_w<A, B, X1, X2, O1, O2, R>(
+    mut a: A,
+    mut b: B
+) -> impl FnMut(X1, X2) -> R
+where
+    A: FnMut(O1, O2) -> R,
+    B: FnMut(X1) -> O1 + FnMut(X2) -> O2
+{
+    move |x1, x2| a(b(x1), b(x2))
+}
+
+/// W¹ = Converse Warbler
+/// 
+/// λab.baa
+/// 
+/// returns b(a(x1), a(x2)), calling a, a, b in that order
+pub fn mut_w1<A, B, X1, X2, O1, O2, R>(
+    mut a: A,
+    mut b: B
+) -> impl FnMut(X1, X2) -> R
+where
+    A: FnMut(X1) -> O1 + FnMut(X2) -> O2,
+    B: FnMut(O1, O2) -> R
+{
+    move |x1, x2| b(a(x1), a(x2))
+}
+
+/// I* = Identity Bird Once Removed
+/// 
+/// λab.ab
+/// 
+/// returns a(b(x1)), calling b, a in that order
+pub fn mut_i_star<A, B, X1, O1, R>(
+    mut a: A,
+    mut b: B
+) -> impl FnMut(X1) -> R
+where
+    A: FnMut(O1) -> R,
+    B: FnMut(X1) -> O1
+{
+    move |x1| a(b(x1))
+}
+
+/// W* = Warbled Once Removed
+/// 
+/// λabc.abcc
+/// 
+/// returns a(b(x1), c(x2), c(x3)), calling b, c, c, a in that order
+pub fn mut_w_star<A, B, C, X1, X2, X3, O1, O2, O3, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C
+) -> impl FnMut(X1, X2, X3) -> R
+where
+    A: FnMut(O1, O2, O3) -> R,
+    B: FnMut(X1) -> O1,
+    C: FnMut(X2) -> O2 + FnMut(X3) -> O3
+{
+    move |x1, x2, x3| a(b(x1), c(x2), c(x3))
+}
+
+/// C* = Cardinal Once Removed
+/// 
+/// λabcd.abdc
+/// 
+/// returns a(b(x1), d(x2), c(x3)), calling b, d, c, a in that order
+pub fn mut_c_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C,
+    mut d: D
+) -> impl FnMut(X1, X2, X3) -> R
+where
+    A: FnMut(O1, O2, O3) -> R,
+    B: FnMut(X1) -> O1,
+    C: FnMut(X3) -> O3,
+    D: FnMut(X2) -> O2
+{
+    move |x1, x2, x3| a(b(x1), d(x2), c(x3))
+}
+
+/// R* = Robin Once Removed
+/// 
+/// λabcd.acdb
+/// 
+/// returns a(c(x1), d(x2), b(x3)), calling c, d, b, a in that order
+pub fn mut_r_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C,
+    mut d: D
+) -> impl FnMut(X1, X2, X3) -> R
+where
+    A: FnMut(O1, O2, O3) -> R,
+    B: FnMut(X3) -> O3,
+    C: FnMut(X1) -> O1,
+    D: FnMut(X2) -> O2
+{
+    move |x1, x2, x3| a(c(x1), d(x2), b(x3))
+}
+
+/// F* = Finch Once Removed
+/// 
+/// λabcd.adcb
+/// 
+/// returns a(d(x1), c(x2), b(x3)), calling d, c, b, a in that order
+pub fn mut_f_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C,
+    mut d: D
+) -> impl FnMut(X1, X2, X3) -> R
+where
+    A: FnMut(O1, O2, O3) -> R,
+    B: FnMut(X3) -> O3,
+    C: FnMut(X2) -> O2,
+    D: FnMut(X1) -> O1
+{
+    move |x1, x2, x3| a(d(x1), c(x2), b(x3))
+}
+
+/// V* = Vireo Once Removed
+/// 
+/// λabcd.acbd
+/// 
+/// returns a(c(x1), b(x2), d(x3)), calling c, b, d, a in that order
+pub fn mut_v_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C,
+    mut d: D
+) -> impl FnMut(X1, X2, X3) -> R
+where
+    A: FnMut(O1, O2, O3) -> R,
+    B: FnMut(X2) -> O2,
+    C: FnMut(X1) -> O1,
+    D: FnMut(X3) -> O3
+{
+    move |x1, x2, x3| a(c(x1), b(x2), d(x3))
+}
+
+/// I** = Identity Bird Twice Removed
+/// 
+/// λabc.abc
+/// 
+/// returns a(b(x1), c(x2)), calling b, c, a in that order
+pub fn mut_i_star_star<A, B, C, X1, X2, O1, O2, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C
+) -> impl FnMut(X1, X2) -> R
+where
+    A: FnMut(O1, O2) -> R,
+    B: FnMut(X1) -> O1,
+    C: FnMut(X2) -> O2
+{
+    move |x1, x2| a(b(x1), c(x2))
+}
+
+/// W** = Warbler Twice Removed
+/// 
+/// λabcd.abcdd
+/// 
+/// returns a(b(x1), c(x2), d(x3), d(x4)), calling b, c, d, d, a in that order
+pub fn mut_w_star_star<A, B, C, D, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C,
+    mut d: D
+) -> impl FnMut(X1, X2, X3, X4) -> R
+where
+    A: FnMut(O1, O2, O3, O4) -> R,
+    B: FnMut(X1) -> O1,
+    C: FnMut(X2) -> O2,
+    D: FnMut(X3) -> O3 + FnMut(X4) -> O4
+{
+    move |x1, x2, x3, x4| a(b(x1), c(x2), d(x3), d(x4))
+}
+
+/// C** = Cardinal Twice Removed
+/// 
+/// λabcde.abced
+/// 
+/// returns a(b(x1), c(x2), e(x3), d(x4)), calling b, c, e, d, a in that order
+pub fn mut_c_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C,
+    mut d: D,
+    mut e: E
+) -> impl FnMut(X1, X2, X3, X4) -> R
+where
+    A: FnMut(O1, O2, O3, O4) -> R,
+    B: FnMut(X1) -> O1,
+    C: FnMut(X2) -> O2,
+    D: FnMut(X4) -> O4,
+    E: FnMut(X3) -> O3
+{
+    move |x1, x2, x3, x4| a(b(x1), c(x2), e(x3), d(x4))
+}
+
+/// R** = Robin Twice Removed
+/// 
+/// λabcde.abdec
+/// 
+/// returns a(b(x1), d(x2), e(x3), c(x4)), calling b, d, e, c, a in that order
+pub fn mut_r_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C,
+    mut d: D,
+    mut e: E
+) -> impl FnMut(X1, X2, X3, X4) -> R
+where
+    A: FnMut(O1, O2, O3, O4) -> R,
+    B: FnMut(X1) -> O1,
+    C: FnMut(X4) -> O4,
+    D: FnMut(X2) -> O2,
+    E: FnMut(X3) -> O3
+{
+    move |x1, x2, x3, x4| a(b(x1), d(x2), e(x3), c(x4))
+}
+
+/// F** = Finch Twice Removed
+/// 
+/// λabcde.abedc
+/// 
+/// returns a(b(x1), e(x2), d(x3), c(x4)), calling b, e, d, c, a in that order
+pub fn mut_f_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C,
+    mut d: D,
+    mut e: E
+) -> impl FnMut(X1, X2, X3, X4) -> R
+where
+    A: FnMut(O1, O2, O3, O4) -> R,
+    B: FnMut(X1) -> O1,
+    C: FnMut(X4) -> O4,
+    D: FnMut(X3) -> O3,
+    E: FnMut(X2) -> O2
+{
+    move |x1, x2, x3, x4| a(b(x1), e(x2), d(x3), c(x4))
+}
+
+/// V** = Vireo Twice Removed
+/// 
+/// λabcde.abecd
+/// 
+/// returns a(b(x1), e(x2), c(x3), d(x4)), calling b, e, c, d, a in that order
+pub fn mut_v_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    mut a: A,
+    mut b: B,
+    mut c: C,
+    mut d: D,
+    mut e: E
+) -> impl FnMut(X1, X2, X3, X4) -> R
+where
+    A: FnMut(O1, O2, O3, O4) -> R,
+    B: FnMut(X1) -> O1,
+    C: FnMut(X3) -> O3,
+    D: FnMut(X4) -> O4,
+    E: FnMut(X2) -> O2
+{
+    move |x1, x2, x3, x4| a(b(x1), e(x2), c(x3), d(x4))
+}
+
+/// KI = Kite (False)
+/// 
+/// λab.b
+/// 
+/// returns b(x1), calling b in that order
+#[allow(unused)]
+pub fn mut_ki<A, B, X1, R>(
+    a: A,
+    mut b: B
+) -> impl FnMut(X1) -> R
+where
+    B: FnMut(X1) -> R
+{
+    move |x1| b(x1)
+}
+
+/// KM = Constant Mocker
+/// 
+/// λab.bb
+/// 
+/// returns b(b(x1)), calling b, b in that order
+#[allow(unused)]
+pub fn mut_km<A, B, X1, O1, R>(
+    a: A,
+    mut b: B
+) -> impl FnMut(X1) -> R
+where
+    B: FnMut(X1) -> O1 + FnMut(O1) -> R
+{
+    move |x1| {
+        let y1 = b(x1);
+        b(y1)
+    }
+}
+
+/// C(KM) = Crossed Constant Mocker
+/// 
+/// λab.aa
+/// 
+/// returns a(a(x1)), calling a, a in that order
+#[allow(unused)]
+pub fn mut_ckm<A, B, X1, O1, R>(
+    mut a: A,
+    b: B
+) -> impl FnMut(X1) -> R
+where
+    A: FnMut(X1) -> O1 + FnMut(O1) -> R
+{
+    move |x1| {
+        let y1 = a(x1);
+        a(y1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A closure counting its calls, and adding the count to its argument
+    fn counter() -> impl FnMut(u32) -> u32
+    {
+        let mut count = 0;
+        move |x| {
+            count += 1;
+            x*10 + count
+        }
+    }
+
+    #[test]
+    fn test_duplicated()
+    {
+        let mut warbler = mut_w(|x, y| (x, y), counter());
+        assert_eq!(warbler(1, 2), (11, 22));
+        assert_eq!(warbler(1, 2), (13, 24));
+
+        let mut hummingbird = mut_h(|x, y, z| (x, y, z), counter(), |x: u32| x);
+        assert_eq!(hummingbird(1, 2, 3), (11, 2, 32));
+        assert_eq!(hummingbird(1, 2, 3), (13, 2, 34));
+
+        let mut starling = mut_s(|x, y| (x, y), |x: u32| x*2, counter());
+        assert_eq!(starling(1, 2), (11, 44));
+        assert_eq!(starling(1, 2), (13, 48));
+
+        // the inner call of a goes first
+        let mut count = 0;
+        let a = |x: u32, y: u32| {
+            count += 1;
+            x + y + count*100
+        };
+        let mut jay = mut_j(a, |x: u32| x, |x: u32| x, |x: u32| x);
+        assert_eq!(jay(1, 2, 3), 1 + (2 + 3 + 100) + 200);
+        assert_eq!(jay(1, 2, 3), 1 + (2 + 3 + 300) + 400);
+    }
+
+    #[test]
+    fn test_order()
+    {
+        let mut log = vec![];
+        {
+            let c = |x: u8| {
+                log.push(x);
+                x
+            };
+            let mut starling = mut_s(|x: u8, y: u8| x + y, |x: u8| x*2, c);
+            for i in 0..10
+            {
+                assert_eq!(starling(i, i + 1), i + (i + 1)*2)
+            }
+        }
+        assert_eq!(log, [0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10]);
+    }
+
+    #[test]
+    fn test_filter()
+    {
+        // drops repeated values
+        let mut last = None;
+        let dedup = |x: u8| {
+            let fresh = last != Some(x);
+            last = Some(x);
+            fresh.then_some(x)
+        };
+        let mut total = 0;
+        let sum = |x: Option<u8>| {
+            total += x.unwrap_or(0) as u32;
+            total
+        };
+
+        let mut f = mut_b(sum, dedup, |x: u8| x/2);
+        let totals: Vec<_> = [2, 3, 4, 4, 6, 1, 0]
+            .into_iter()
+            .map(&mut f)
+            .collect();
+
+        assert_eq!(totals, [1, 1, 3, 3, 6, 6, 6]);
+    }
+}
@@ -0,0 +1,347 @@
+//! Named functions, and the structure of compositions
+//!
+//! The type of a composition is an unreadable nest of [Composition](currycompose::Composition)s.
+//! Wrapping each function passed into a bird in [Named](Named) gives it a name, and [Pipeline::of](Pipeline::of)
+//! then recovers the structure of the composition, written the same way as the documentation of the birds.
+//! Each function says what its structure is through the [Structure](Structure) trait.
+//!
+//! ```rust
+//! use birbs::named::{named, Pipeline};
+//!
+//! let a = named("a", |x: f32| x.sqrt());
+//! let b = named("b", |x: f32| x + 1.0);
+//! let c = named("c", |x: u8| x as f32);
+//!
+//! let f = birbs::b(a, b, c);
+//!
+//! assert_eq!(f(3), 2.0);
+//! assert_eq!(Pipeline::of(&f).to_string(), "a ∘ (b ∘ c)");
+//! ```
+//!
+//! A [Symbol](crate::symbol::Symbol) names itself, and any other function can, by implementing [Structure](Structure).
+
+use std::marker::Tuple;
+
+use currycompose::Composition;
+
+/// A function with a name
+///
+/// Calling it calls the function. Its [Debug](std::fmt::Debug) and [Display](std::fmt::Display) only write the name,
+/// so it can stand in for a function which is not [Debug](std::fmt::Debug), such as a closure.
+///
+/// The name is `'static`, so that the wrapper stays [Copy](Copy), as birds which duplicate an argument require.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Named<F>
+{
+    pub name: &'static str,
+    pub function: F
+}
+
+/// Gives a function a name
+pub const fn named<F>(name: &'static str, function: F) -> Named<F>
+{
+    Named {
+        name,
+        function
+    }
+}
+
+impl<F> std::fmt::Debug for Named<F>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        f.write_str(self.name)
+    }
+}
+
+impl<F> std::fmt::Display for Named<F>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        f.write_str(self.name)
+    }
+}
+
+impl<F: FnOnce<X>, X: Tuple> FnOnce<X> for Named<F>
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(self, args: X) -> Self::Output
+    {
+        self.function.call_once(args)
+    }
+}
+
+impl<F: FnMut<X>, X: Tuple> FnMut<X> for Named<F>
+{
+    extern "rust-call" fn call_mut(&mut self, args: X) -> Self::Output
+    {
+        self.function.call_mut(args)
+    }
+}
+
+impl<F: Fn<X>, X: Tuple> Fn<X> for Named<F>
+{
+    extern "rust-call" fn call(&self, args: X) -> Self::Output
+    {
+        self.function.call(args)
+    }
+}
+
+/// The structure of a composition
+///
+/// Displays as `g ∘ f`, where ∘ is left-associative, so a composition on the right is put in parentheses,
+/// such as `a ∘ (b ∘ c ∘ d) ∘ (e ∘ f ∘ g)` for the Bald Eagle.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Pipeline
+{
+    /// A function, by its name
+    Function(String),
+    /// g ∘ f
+    Composition(Box<Pipeline>, Box<Pipeline>)
+}
+
+impl Pipeline
+{
+    /// The structure of a composition
+    pub fn of<T: Structure + ?Sized>(function: &T) -> Pipeline
+    {
+        function.pipeline()
+    }
+
+    /// The names of the functions, from left to right
+    pub fn functions(&self) -> Vec<&str>
+    {
+        match self
+        {
+            Pipeline::Function(name) => vec![name],
+            Pipeline::Composition(g, f) => {
+                let mut functions = g.functions();
+                functions.extend(f.functions());
+                functions
+            }
+        }
+    }
+
+    /// The composition tree in the dot language of Graphviz
+    ///
+    /// Every composition is a node labelled ∘, with an edge to g and an edge to f, in that order.
+    ///
+    /// ```rust
+    /// use birbs::named::Pipeline;
+    /// use birbs::symbol::sym;
+    ///
+    /// let f = birbs::b(sym("a"), sym("b"), sym("c"));
+    ///
+    /// println!("{}", Pipeline::of(&f).to_dot());
+    /// ```
+    pub fn to_dot(&self) -> String
+    {
+        fn node(pipeline: &Pipeline, dot: &mut String, count: &mut usize) -> usize
+        {
+            let id = *count;
+            *count += 1;
+            match pipeline
+            {
+                Pipeline::Function(name) => {
+                    let label = name.replace('\\', "\\\\")
+                        .replace('"', "\\\"");
+                    dot.push_str(&format!("    {id} [label=\"{label}\"];\n"))
+                },
+                Pipeline::Composition(g, f) => {
+                    dot.push_str(&format!("    {id} [label=\"∘\"];\n"));
+                    for child in [g, f]
+                    {
+                        let child = node(child, dot, count);
+                        dot.push_str(&format!("    {id} -> {child};\n"))
+                    }
+                }
+            }
+            id
+        }
+
+        let mut dot = String::from("digraph {\n");
+        node(self, &mut dot, &mut 0);
+        dot.push('}');
+        dot
+    }
+}
+
+/// The structure of a function
+///
+/// A function on its own is a [Function](Pipeline::Function), named as [Named](Named) and [Symbol](crate::symbol::Symbol) are,
+/// and a composition is a composition of the structures of its functions.
+pub trait Structure
+{
+    /// The structure of the function
+    fn pipeline(&self) -> Pipeline;
+}
+
+/// The fields of a [Composition](currycompose::Composition) are private, so its structure is read from the pieces
+/// written by its derived [Debug](std::fmt::Debug), `Composition`, ` { `, `g`, `: `, then `g`, and so on for `f` and `phantom`.
+/// A function within it is named by what its own [Debug](std::fmt::Debug) writes, which is never parsed.
+impl<G, F, XG, XF> Structure for Composition<G, F, XG, XF>
+where
+    Self: std::fmt::Debug
+{
+    fn pipeline(&self) -> Pipeline
+    {
+        /// Keeps each piece written apart from the others
+        struct Pieces(Vec<String>);
+
+        impl std::fmt::Write for Pieces
+        {
+            fn write_str(&mut self, s: &str) -> std::fmt::Result
+            {
+                self.0.push(s.to_string());
+                Ok(())
+            }
+        }
+
+        const G: [&str; 4] = ["Composition", " { ", "g", ": "];
+        const F: [&str; 3] = [", ", "f", ": "];
+        const PHANTOM: [&str; 3] = [", ", "phantom", ": "];
+
+        fn starts_with(pieces: &[String], with: &[&str]) -> bool
+        {
+            pieces.len() >= with.len() && pieces.iter().zip(with).all(|(piece, with)| piece == with)
+        }
+
+        /// Reads a composition or a function from the start of the pieces, up to the pieces which follow it
+        fn read(pieces: &mut &[String], until: &[&str]) -> Pipeline
+        {
+            if starts_with(pieces, &G)
+            {
+                *pieces = &pieces[G.len()..];
+                let g = read(pieces, &F);
+                *pieces = &pieces[F.len()..];
+                let f = read(pieces, &PHANTOM);
+                let end = pieces.iter()
+                    .position(|piece| piece == " }")
+                    .map_or(pieces.len(), |i| i + 1);
+                *pieces = &pieces[end..];
+                return Pipeline::Composition(Box::new(g), Box::new(f))
+            }
+            let end = (0..pieces.len())
+                .find(|&i| !until.is_empty() && starts_with(&pieces[i..], until))
+                .unwrap_or(pieces.len());
+            let name = pieces[..end].concat();
+            *pieces = &pieces[end..];
+            Pipeline::Function(name)
+        }
+
+        let mut pieces = Pieces(vec![]);
+        let _ = std::fmt::write(&mut pieces, format_args!("{self:?}"));
+        read(&mut pieces.0.as_slice(), &[])
+    }
+}
+
+impl<F> Structure for Named<F>
+{
+    fn pipeline(&self) -> Pipeline
+    {
+        Pipeline::Function(self.name.to_string())
+    }
+}
+
+impl<T: Structure + ?Sized> Structure for &T
+{
+    fn pipeline(&self) -> Pipeline
+    {
+        T::pipeline(self)
+    }
+}
+
+impl std::fmt::Display for Pipeline
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            Pipeline::Function(name) => f.write_str(name),
+            Pipeline::Composition(g, inner) => match **inner
+            {
+                Pipeline::Composition(..) => write!(f, "{g} ∘ ({inner})"),
+                Pipeline::Function(_) => write!(f, "{g} ∘ {inner}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::symbol::sym;
+
+    use super::*;
+
+    #[test]
+    fn test_named()
+    {
+        let a = named("a", |x: f32| x.sqrt());
+
+        assert_eq!(a(4.0), 2.0);
+        assert_eq!(format!("{a:?}"), "a");
+        assert_eq!(a.to_string(), "a");
+    }
+
+    #[test]
+    fn test_pipeline()
+    {
+        let a = named("a", |x: f32, y: f32| x + y);
+        let b = named("b", |x: f32, y: f32| x*y);
+        let c = named("c", |x: u8| x as f32);
+        let d = named("d", |x: u8| x as f32*2.0);
+        let e = named("e", |x: f32, y: f32| x - y);
+        let f = named("f", |x: u8| x as f32/2.0);
+        let g = named("g", |x: u8| x as f32 + 1.0);
+
+        let bald_eagle = crate::ê(a, b, c, d, e, f, g);
+
+        assert_eq!(Pipeline::of(&bald_eagle).to_string(), "a ∘ (b ∘ c ∘ d) ∘ (e ∘ f ∘ g)");
+        assert_eq!(Pipeline::of(&bald_eagle).functions(), ["a", "b", "c", "d", "e", "f", "g"]);
+        for i in 0..=255
+        {
+            assert_eq!(bald_eagle(i, i, i, i), a(b(c(i), d(i)), e(f(i), g(i))))
+        }
+
+        let [a, b, c, d, e] = ["a", "b", "c", "d", "e"].map(sym);
+
+        assert_eq!(Pipeline::of(&crate::b(a.clone(), b.clone(), c.clone())).to_string(), "a ∘ (b ∘ c)");
+        assert_eq!(Pipeline::of(&crate::e(a.clone(), b.clone(), c.clone(), d.clone(), e.clone())).to_string(), "a ∘ b ∘ (c ∘ d ∘ e)");
+        assert_eq!(Pipeline::of(&named("f", crate::e(a, b, c, d, e))).to_string(), "f");
+    }
+
+    #[test]
+    fn test_names()
+    {
+        // the names are never parsed, so they may look like anything
+        let [a, b, c] = ["a {", "\"b\" (\n", "c, f: "].map(sym);
+
+        assert_eq!(Pipeline::of(&crate::b(a, b, c)).functions(), ["a {", "\"b\" (\n", "c, f: "]);
+
+        // by reference
+        let [a, b] = [named("a", |x: u8| x + 1), named("b", |x: u8| x*2)];
+        let f = crate::shared::m2_ref(&a, &b);
+
+        assert_eq!(Pipeline::of(&f).to_string(), "a ∘ b ∘ (a ∘ b)");
+        assert_eq!(format!("{a:?}"), "a");
+    }
+
+    #[test]
+    fn test_dot()
+    {
+        let pipeline = Pipeline::of(&crate::b(sym("a"), sym("b"), sym("c")));
+
+        assert_eq!(pipeline.to_dot(), "digraph {
+    0 [label=\"∘\"];
+    1 [label=\"a\"];
+    0 -> 1;
+    2 [label=\"∘\"];
+    3 [label=\"b\"];
+    2 -> 3;
+    4 [label=\"c\"];
+    2 -> 4;
+    0 -> 2;
+}")
+    }
+}
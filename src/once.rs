@@ -0,0 +1,735 @@
+//! Single-use birds
+//!
+//! Here each function is [FnOnce](FnOnce), such as a builder or a closure consuming a resource, and a bird composes them into an [FnOnce](FnOnce).
+//!
+//! A bird reads its λ-body by the [first of the possible interpretations](crate#interpretations).
+//! The arguments are evaluated from left to right, so the closures are called in the order given for each bird.
+//!
+//! Only the birds which use each of their arguments at most once are here.
+//! A bird which duplicates an argument, such as W or S, would need to call it twice, so it doesn't exist for [FnOnce](FnOnce).
+//!
+//! ```rust
+//! use birbs::once::once_c;
+//!
+//! let greeting = String::from("hello");
+//! let name = String::from("world");
+//!
+//! let f = once_c(move |x: String, y: String| x + ", " + &y, move |_: ()| name, move |_: ()| greeting);
+//!
+//! assert_eq!(f((), ()), "hello, world");
+//! ```
+//!
+//! A closure giving away what it owns only implements [FnOnce](FnOnce), so it can be passed to a bird here:
+//!
+//! ```rust
+//! use birbs::once::once_b;
+//!
+//! let name = String::from("world");
+//! let give = move |_: ()| name;
+//!
+//! let f = once_b(|x: String| x.len(), |x: String| x + "!", give);
+//!
+//! assert_eq!(f(()), 6);
+//! ```
+//!
+//! but not to the same bird for [FnMut](FnMut), which may call it again:
+//!
+//! ```rust,compile_fail,E0525
+//! use birbs::mutable::mut_b;
+//!
+//! let name = String::from("world");
+//! let give = move |_: ()| name;
+//!
+//! let f = mut_b(|x: String| x.len(), |x: String| x + "!", give);
+//! ```
+
+/// B = Bluebird
+/// 
+/// λabc.a(bc)
+/// 
+/// returns a(b(c(x1))), calling c, b, a in that order
+pub fn once_b<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl FnOnce(X1) -> R
+where
+    A: FnOnce(O2) -> R,
+    B: FnOnce(O1) -> O2,
+    C: FnOnce(X1) -> O1
+{
+    move |x1| a(b(c(x1)))
+}
+
+/// B¹ = Blackbird
+/// 
+/// λabcd.a(bcd)
+/// 
+/// returns a(b(c(x1), d(x2))), calling c, d, b, a in that order
+pub fn once_b1<A, B, C, D, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl FnOnce(X1, X2) -> R
+where
+    A: FnOnce(O3) -> R,
+    B: FnOnce(O1, O2) -> O3,
+    C: FnOnce(X1) -> O1,
+    D: FnOnce(X2) -> O2
+{
+    move |x1, x2| a(b(c(x1), d(x2)))
+}
+
+/// B² = Bunting
+/// 
+/// λabcde.a(bcde)
+/// 
+/// returns a(b(c(x1), d(x2), e(x3))), calling c, d, e, b, a in that order
+pub fn once_b2<A, B, C, D, E, X1, X2, X3, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl FnOnce(X1, X2, X3) -> R
+where
+    A: FnOnce(O4) -> R,
+    B: FnOnce(O1, O2, O3) -> O4,
+    C: FnOnce(X1) -> O1,
+    D: FnOnce(X2) -> O2,
+    E: FnOnce(X3) -> O3
+{
+    move |x1, x2, x3| a(b(c(x1), d(x2), e(x3)))
+}
+
+/// B³ = Becard
+/// 
+/// λabcd.a(b(cd))
+/// 
+/// returns a(b(c(d(x1)))), calling d, c, b, a in that order
+pub fn once_b3<A, B, C, D, X1, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl FnOnce(X1) -> R
+where
+    A: FnOnce(O3) -> R,
+    B: FnOnce(O2) -> O3,
+    C: FnOnce(O1) -> O2,
+    D: FnOnce(X1) -> O1
+{
+    move |x1| a(b(c(d(x1))))
+}
+
+/// C = Cardinal
+/// 
+/// λabc.acb
+/// 
+/// returns a(c(x1), b(x2)), calling c, b, a in that order
+pub fn once_c<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl FnOnce(X1, X2) -> R
+where
+    A: FnOnce(O1, O2) -> R,
+    B: FnOnce(X2) -> O2,
+    C: FnOnce(X1) -> O1
+{
+    move |x1, x2| a(c(x1), b(x2))
+}
+
+/// D = Dove
+/// 
+/// λabcd.ab(cd)
+/// 
+/// returns a(b(x1), c(d(x2))), calling b, d, c, a in that order
+pub fn once_d<A, B, C, D, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl FnOnce(X1, X2) -> R
+where
+    A: FnOnce(O1, O3) -> R,
+    B: FnOnce(X1) -> O1,
+    C: FnOnce(O2) -> O3,
+    D: FnOnce(X2) -> O2
+{
+    move |x1, x2| a(b(x1), c(d(x2)))
+}
+
+/// D¹ = Dickcissel
+/// 
+/// λabcde.abc(de)
+/// 
+/// returns a(b(x1), c(x2), d(e(x3))), calling b, c, e, d, a in that order
+pub fn once_d1<A, B, C, D, E, X1, X2, X3, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl FnOnce(X1, X2, X3) -> R
+where
+    A: FnOnce(O1, O2, O4) -> R,
+    B: FnOnce(X1) -> O1,
+    C: FnOnce(X2) -> O2,
+    D: FnOnce(O3) -> O4,
+    E: FnOnce(X3) -> O3
+{
+    move |x1, x2, x3| a(b(x1), c(x2), d(e(x3)))
+}
+
+/// D² = Dovekies
+/// 
+/// λabcde.a(bc)(de)
+/// 
+/// returns a(b(c(x1)), d(e(x2))), calling c, b, e, d, a in that order
+pub fn once_d2<A, B, C, D, E, X1, X2, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl FnOnce(X1, X2) -> R
+where
+    A: FnOnce(O2, O4) -> R,
+    B: FnOnce(O1) -> O2,
+    C: FnOnce(X1) -> O1,
+    D: FnOnce(O3) -> O4,
+    E: FnOnce(X2) -> O3
+{
+    move |x1, x2| a(b(c(x1)), d(e(x2)))
+}
+
+/// E = Eagle
+/// 
+/// λabcde.ab(cde)
+/// 
+/// returns a(b(x1), c(d(x2), e(x3))), calling b, d, e, c, a in that order
+pub fn once_e<A, B, C, D, E, X1, X2, X3, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl FnOnce(X1, X2, X3) -> R
+where
+    A: FnOnce(O1, O4) -> R,
+    B: FnOnce(X1) -> O1,
+    C: FnOnce(O2, O3) -> O4,
+    D: FnOnce(X2) -> O2,
+    E: FnOnce(X3) -> O3
+{
+    move |x1, x2, x3| a(b(x1), c(d(x2), e(x3)))
+}
+
+/// Ê = Bald Eagle
+/// 
+/// λabcdefg.a(bcd)(efg)
+/// 
+/// returns a(b(c(x1), d(x2)), e(f(x3), g(x4))), calling c, d, b, f, g, e, a in that order
+pub fn once_ê<A, B, C, D, E, F, G, X1, X2, X3, X4, O1, O2, O3, O4, O5, O6, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E,
+    f: F,
+    g: G
+) -> impl FnOnce(X1, X2, X3, X4) -> R
+where
+    A: FnOnce(O3, O6) -> R,
+    B: FnOnce(O1, O2) -> O3,
+    C: FnOnce(X1) -> O1,
+    D: FnOnce(X2) -> O2,
+    E: FnOnce(O4, O5) -> O6,
+    F: FnOnce(X3) -> O4,
+    G: FnOnce(X4) -> O5
+{
+    move |x1, x2, x3, x4| a(b(c(x1), d(x2)), e(f(x3), g(x4)))
+}
+
+/// F = Finch
+/// 
+/// λabc.cba
+/// 
+/// returns c(b(x1), a(x2)), calling b, a, c in that order
+pub fn once_f<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl FnOnce(X1, X2) -> R
+where
+    A: FnOnce(X2) -> O2,
+    B: FnOnce(X1) -> O1,
+    C: FnOnce(O1, O2) -> R
+{
+    move |x1, x2| c(b(x1), a(x2))
+}
+
+/// G = Goldfinch
+/// 
+/// λabcd.ad(bc)
+/// 
+/// returns a(d(x1), b(c(x2))), calling d, c, b, a in that order
+pub fn once_g<A, B, C, D, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl FnOnce(X1, X2) -> R
+where
+    A: FnOnce(O1, O3) -> R,
+    B: FnOnce(O2) -> O3,
+    C: FnOnce(X2) -> O2,
+    D: FnOnce(X1) -> O1
+{
+    move |x1, x2| a(d(x1), b(c(x2)))
+}
+
+/// I = Identity Bird aka Idiot
+/// 
+/// λa.a
+/// 
+/// returns a(x1), calling a in that order
+pub fn once_i<A, X1, R>(
+    a: A
+) -> impl FnOnce(X1) -> R
+where
+    A: FnOnce(X1) -> R
+{
+    move |x1| a(x1)
+}
+
+/// K = Kestrel (True)
+/// 
+/// λab.a
+/// 
+/// returns a(x1), calling a in that order
+#[allow(unused)]
+pub fn once_k<A, B, X1, R>(
+    a: A,
+    b: B
+) -> impl FnOnce(X1) -> R
+where
+    A: FnOnce(X1) -> R
+{
+    move |x1| a(x1)
+}
+
+/// Q = Queer Bird
+/// 
+/// λabc.b(ac)
+/// 
+/// returns b(a(c(x1))), calling c, a, b in that order
+pub fn once_q<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl FnOnce(X1) -> R
+where
+    A: FnOnce(O1) -> O2,
+    B: FnOnce(O2) -> R,
+    C: FnOnce(X1) -> O1
+{
+    move |x1| b(a(c(x1)))
+}
+
+/// Q¹ = Quixotic Bird
+/// 
+/// λabc.a(cb)
+/// 
+/// returns a(c(b(x1))), calling b, c, a in that order
+pub fn once_q1<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl FnOnce(X1) -> R
+where
+    A: FnOnce(O2) -> R,
+    B: FnOnce(X1) -> O1,
+    C: FnOnce(O1) -> O2
+{
+    move |x1| a(c(b(x1)))
+}
+
+/// Q² = Quizzical Bird
+/// 
+/// λabc.b(ca)
+/// 
+/// returns b(c(a(x1))), calling a, c, b in that order
+pub fn once_q2<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl FnOnce(X1) -> R
+where
+    A: FnOnce(X1) -> O1,
+    B: FnOnce(O2) -> R,
+    C: FnOnce(O1) -> O2
+{
+    move |x1| b(c(a(x1)))
+}
+
+/// Q³ = Quirky Bird
+/// 
+/// λabc.c(ab)
+/// 
+/// returns c(a(b(x1))), calling b, a, c in that order
+pub fn once_q3<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl FnOnce(X1) -> R
+where
+    A: FnOnce(O1) -> O2,
+    B: FnOnce(X1) -> O1,
+    C: FnOnce(O2) -> R
+{
+    move |x1| c(a(b(x1)))
+}
+
+/// Q⁴ = Quacky Bird
+/// 
+/// λabc.c(ba)
+/// 
+/// returns c(b(a(x1))), calling a, b, c in that order
+pub fn once_q4<A, B, C, X1, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl FnOnce(X1) -> R
+where
+    A: FnOnce(X1) -> O1,
+    B: FnOnce(O1) -> O2,
+    C: FnOnce(O2) -> R
+{
+    move |x1| c(b(a(x1)))
+}
+
+/// R = Robin
+/// 
+/// λabc.bca
+/// 
+/// returns b(c(x1), a(x2)), calling c, a, b in that order
+pub fn once_r<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl FnOnce(X1, X2) -> R
+where
+    A: FnOnce(X2) -> O2,
+    B: FnOnce(O1, O2) -> R,
+    C: FnOnce(X1) -> O1
+{
+    move |x1, x2| b(c(x1), a(x2))
+}
+
+/// T = Thrush
+/// 
+/// λab.ba
+/// 
+/// returns b(a(x1)), calling a, b in that order
+pub fn once_t<A, B, X1, O1, R>(
+    a: A,
+    b: B
+) -> impl FnOnce(X1) -> R
+where
+    A: FnOnce(X1) -> O1,
+    B: FnOnce(O1) -> R
+{
+    move |x1| b(a(x1))
+}
+
+/// V = Vireo aka Pairing
+/// 
+/// λabc.cab
+/// 
+/// returns c(a(x1), b(x2)), calling a, b, c in that order
+pub fn once_v<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl FnOnce(X1, X2) -> R
+where
+    A: FnOnce(X1) -> O1,
+    B: FnOnce(X2) -> O2,
+    C: FnOnce(O1, O2) -> R
+{
+    move |x1, x2| c(a(x1), b(x2))
+}
+
+/// I* = Identity Bird Once Removed
+/// 
+/// λab.ab
+/// 
+/// returns a(b(x1)), calling b, a in that order
+pub fn once_i_star<A, B, X1, O1, R>(
+    a: A,
+    b: B
+) -> impl FnOnce(X1) -> R
+where
+    A: FnOnce(O1) -> R,
+    B: FnOnce(X1) -> O1
+{
+    move |x1| a(b(x1))
+}
+
+/// C* = Cardinal Once Removed
+/// 
+/// λabcd.abdc
+/// 
+/// returns a(b(x1), d(x2), c(x3)), calling b, d, c, a in that order
+pub fn once_c_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl FnOnce(X1, X2, X3) -> R
+where
+    A: FnOnce(O1, O2, O3) -> R,
+    B: FnOnce(X1) -> O1,
+    C: FnOnce(X3) -> O3,
+    D: FnOnce(X2) -> O2
+{
+    move |x1, x2, x3| a(b(x1), d(x2), c(x3))
+}
+
+/// R* = Robin Once Removed
+/// 
+/// λabcd.acdb
+/// 
+/// returns a(c(x1), d(x2), b(x3)), calling c, d, b, a in that order
+pub fn once_r_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl FnOnce(X1, X2, X3) -> R
+where
+    A: FnOnce(O1, O2, O3) -> R,
+    B: FnOnce(X3) -> O3,
+    C: FnOnce(X1) -> O1,
+    D: FnOnce(X2) -> O2
+{
+    move |x1, x2, x3| a(c(x1), d(x2), b(x3))
+}
+
+/// F* = Finch Once Removed
+/// 
+/// λabcd.adcb
+/// 
+/// returns a(d(x1), c(x2), b(x3)), calling d, c, b, a in that order
+pub fn once_f_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl FnOnce(X1, X2, X3) -> R
+where
+    A: FnOnce(O1, O2, O3) -> R,
+    B: FnOnce(X3) -> O3,
+    C: FnOnce(X2) -> O2,
+    D: FnOnce(X1) -> O1
+{
+    move |x1, x2, x3| a(d(x1), c(x2), b(x3))
+}
+
+/// V* = Vireo Once Removed
+/// 
+/// λabcd.acbd
+/// 
+/// returns a(c(x1), b(x2), d(x3)), calling c, b, d, a in that order
+pub fn once_v_star<A, B, C, D, X1, X2, X3, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl FnOnce(X1, X2, X3) -> R
+where
+    A: FnOnce(O1, O2, O3) -> R,
+    B: FnOnce(X2) -> O2,
+    C: FnOnce(X1) -> O1,
+    D: FnOnce(X3) -> O3
+{
+    move |x1, x2, x3| a(c(x1), b(x2), d(x3))
+}
+
+/// I** = Identity Bird Twice Removed
+/// 
+/// λabc.abc
+/// 
+/// returns a(b(x1), c(x2)), calling b, c, a in that order
+pub fn once_i_star_star<A, B, C, X1, X2, O1, O2, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl FnOnce(X1, X2) -> R
+where
+    A: FnOnce(O1, O2) -> R,
+    B: FnOnce(X1) -> O1,
+    C: FnOnce(X2) -> O2
+{
+    move |x1, x2| a(b(x1), c(x2))
+}
+
+/// C** = Cardinal Twice Removed
+/// 
+/// λabcde.abced
+/// 
+/// returns a(b(x1), c(x2), e(x3), d(x4)), calling b, c, e, d, a in that order
+pub fn once_c_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl FnOnce(X1, X2, X3, X4) -> R
+where
+    A: FnOnce(O1, O2, O3, O4) -> R,
+    B: FnOnce(X1) -> O1,
+    C: FnOnce(X2) -> O2,
+    D: FnOnce(X4) -> O4,
+    E: FnOnce(X3) -> O3
+{
+    move |x1, x2, x3, x4| a(b(x1), c(x2), e(x3), d(x4))
+}
+
+/// R** = Robin Twice Removed
+/// 
+/// λabcde.abdec
+/// 
+/// returns a(b(x1), d(x2), e(x3), c(x4)), calling b, d, e, c, a in that order
+pub fn once_r_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl FnOnce(X1, X2, X3, X4) -> R
+where
+    A: FnOnce(O1, O2, O3, O4) -> R,
+    B: FnOnce(X1) -> O1,
+    C: FnOnce(X4) -> O4,
+    D: FnOnce(X2) -> O2,
+    E: FnOnce(X3) -> O3
+{
+    move |x1, x2, x3, x4| a(b(x1), d(x2), e(x3), c(x4))
+}
+
+/// F** = Finch Twice Removed
+/// 
+/// λabcde.abedc
+/// 
+/// returns a(b(x1), e(x2), d(x3), c(x4)), calling b, e, d, c, a in that order
+pub fn once_f_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl FnOnce(X1, X2, X3, X4) -> R
+where
+    A: FnOnce(O1, O2, O3, O4) -> R,
+    B: FnOnce(X1) -> O1,
+    C: FnOnce(X4) -> O4,
+    D: FnOnce(X3) -> O3,
+    E: FnOnce(X2) -> O2
+{
+    move |x1, x2, x3, x4| a(b(x1), e(x2), d(x3), c(x4))
+}
+
+/// V** = Vireo Twice Removed
+/// 
+/// λabcde.abecd
+/// 
+/// returns a(b(x1), e(x2), c(x3), d(x4)), calling b, e, c, d, a in that order
+pub fn once_v_star_star<A, B, C, D, E, X1, X2, X3, X4, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl FnOnce(X1, X2, X3, X4) -> R
+where
+    A: FnOnce(O1, O2, O3, O4) -> R,
+    B: FnOnce(X1) -> O1,
+    C: FnOnce(X3) -> O3,
+    D: FnOnce(X4) -> O4,
+    E: FnOnce(X2) -> O2
+{
+    move |x1, x2, x3, x4| a(b(x1), e(x2), c(x3), d(x4))
+}
+
+/// KI = Kite (False)
+/// 
+/// λab.b
+/// 
+/// returns b(x1), calling b in that order
+#[allow(unused)]
+pub fn once_ki<A, B, X1, R>(
+    a: A,
+    b: B
+) -> impl FnOnce(X1) -> R
+where
+    B: FnOnce(X1) -> R
+{
+    move |x1| b(x1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A closure which can only be called once, since it gives away what it owns
+    fn consume(owned: Vec<u8>) -> impl FnOnce(u8) -> Vec<u8>
+    {
+        move |x| {
+            let mut owned = owned;
+            owned.push(x);
+            owned
+        }
+    }
+
+    #[test]
+    fn test_birds()
+    {
+        let f = once_b(|v: Vec<u8>| v.len(), |v: Vec<u8>| [v, vec![0]].concat(), consume(vec![1, 2]));
+        assert_eq!(f(3), 4);
+
+        let f = once_c(|x: Vec<u8>, y: Vec<u8>| [x, y].concat(), consume(vec![1]), consume(vec![2]));
+        assert_eq!(f(3, 4), [2, 3, 1, 4]);
+
+        let f = once_d2(|x: Vec<u8>, y: Vec<u8>| [x, y].concat(), consume(vec![1]), |x: u8| x*2, consume(vec![2]), |x: u8| x + 1);
+        assert_eq!(f(3, 4), [1, 6, 2, 5]);
+
+        let f = once_ê(
+            |x: Vec<u8>, y: Vec<u8>| [x, y].concat(),
+            |x: Vec<u8>, y: u8| [x, vec![y]].concat(),
+            consume(vec![1]),
+            |x: u8| x + 1,
+            |x: u8, y: Vec<u8>| [vec![x], y].concat(),
+            |x: u8| x + 2,
+            consume(vec![3])
+        );
+        assert_eq!(f(10, 20, 30, 40), [1, 10, 21, 32, 3, 40]);
+
+        let f = once_v(consume(vec![1]), consume(vec![2]), |x: Vec<u8>, y: Vec<u8>| [y, x].concat());
+        assert_eq!(f(3, 4), [2, 4, 1, 3]);
+    }
+
+    #[test]
+    fn test_discard()
+    {
+        // what isn't used is dropped without being called
+        let f = once_k(consume(vec![1]), consume(vec![2]));
+        assert_eq!(f(3), [1, 3]);
+
+        let f = once_ki(consume(vec![1]), consume(vec![2]));
+        assert_eq!(f(3), [2, 3]);
+    }
+}
@@ -0,0 +1,292 @@
+//! Parallel birds
+//!
+//! In the branching birds, the two arguments passed to the head function are computed independently of one another.
+//! These birds compute the right-hand branch on a scoped thread, with [scope](std::thread::scope), while the calling
+//! thread computes the left-hand one, then pass both to the head function. They return the same as the birds in the crate root,
+//! and read their λ-body by the [first of the possible interpretations](crate#interpretations).
+//!
+//! The functions of the right-hand branch are shared with the thread, so they must be [Sync](Sync),
+//! and its inputs and output are sent across, so they must be [Send](Send).
+//! A thread is spawned on every call, which only pays off when the branches do a lot of work.
+//!
+//! ```rust
+//! use birbs::parallel::par_s;
+//!
+//! let square = |x: &[f64]| x.iter().map(|x| x*x).collect::<Vec<_>>();
+//! let sum = |x: Vec<f64>| x.iter().sum::<f64>();
+//!
+//! // square(x) and sum(square(y)) are computed on separate threads
+//! let ratio = par_s(|x: Vec<f64>, y: f64| sum(x)/y, sum, square);
+//!
+//! assert_eq!(ratio(&[3.0, 4.0], &[5.0]), 1.0);
+//! ```
+
+use std::panic::resume_unwind;
+use std::thread::scope;
+
+/// Calls `left` on this thread and `right` on a scoped thread, returning both outputs
+/// 
+/// If `right` panics, the panic is resumed on this thread once `left` has returned.
+fn join<L, R>(left: impl FnOnce() -> L, right: impl FnOnce() -> R + Send) -> (L, R)
+where
+    R: Send
+{
+    scope(|scope| {
+        let right = scope.spawn(right);
+        let left = left();
+        match right.join()
+        {
+            Ok(right) => (left, right),
+            Err(panic) => resume_unwind(panic)
+        }
+    })
+}
+
+/// D = Dove
+/// 
+/// λabcd.ab(cd)
+/// 
+/// returns a(b(x1), c(d(x2))), computing c(d(x2)) on another thread
+pub fn par_d<A, B, C, D, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(O1, O3) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(O2) -> O3 + Sync,
+    D: Fn(X2) -> O2 + Sync,
+    X2: Send,
+    O3: Send
+{
+    move |x1, x2| {
+        let (c, d) = (&c, &d);
+        let (y0, y1) = join(
+            || b(x1),
+            move || c(d(x2))
+        );
+        a(y0, y1)
+    }
+}
+
+/// E = Eagle
+/// 
+/// λabcde.ab(cde)
+/// 
+/// returns a(b(x1), c(d(x2), e(x3))), computing c(d(x2), e(x3)) on another thread
+pub fn par_e<A, B, C, D, E, X1, X2, X3, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: Fn(O1, O4) -> R,
+    B: Fn(X1) -> O1,
+    C: Fn(O2, O3) -> O4 + Sync,
+    D: Fn(X2) -> O2 + Sync,
+    E: Fn(X3) -> O3 + Sync,
+    X2: Send,
+    X3: Send,
+    O4: Send
+{
+    move |x1, x2, x3| {
+        let (c, d, e) = (&c, &d, &e);
+        let (y0, y1) = join(
+            || b(x1),
+            move || c(d(x2), e(x3))
+        );
+        a(y0, y1)
+    }
+}
+
+/// Ê = Bald Eagle
+/// 
+/// λabcdefg.a(bcd)(efg)
+/// 
+/// returns a(b(c(x1), d(x2)), e(f(x3), g(x4))), computing e(f(x3), g(x4)) on another thread
+pub fn par_ê<A, B, C, D, E, F, G, X1, X2, X3, X4, O1, O2, O3, O4, O5, O6, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E,
+    f: F,
+    g: G
+) -> impl Fn(X1, X2, X3, X4) -> R
+where
+    A: Fn(O3, O6) -> R,
+    B: Fn(O1, O2) -> O3,
+    C: Fn(X1) -> O1,
+    D: Fn(X2) -> O2,
+    E: Fn(O4, O5) -> O6 + Sync,
+    F: Fn(X3) -> O4 + Sync,
+    G: Fn(X4) -> O5 + Sync,
+    X3: Send,
+    X4: Send,
+    O6: Send
+{
+    move |x1, x2, x3, x4| {
+        let (e, f, g) = (&e, &f, &g);
+        let (y0, y1) = join(
+            || b(c(x1), d(x2)),
+            move || e(f(x3), g(x4))
+        );
+        a(y0, y1)
+    }
+}
+
+/// G = Goldfinch
+/// 
+/// λabcd.ad(bc)
+/// 
+/// returns a(d(x1), b(c(x2))), computing b(c(x2)) on another thread
+pub fn par_g<A, B, C, D, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(O1, O3) -> R,
+    B: Fn(O2) -> O3 + Sync,
+    C: Fn(X2) -> O2 + Sync,
+    D: Fn(X1) -> O1,
+    X2: Send,
+    O3: Send
+{
+    move |x1, x2| {
+        let (b, c) = (&b, &c);
+        let (y0, y1) = join(
+            || d(x1),
+            move || b(c(x2))
+        );
+        a(y0, y1)
+    }
+}
+
+/// J = Jay
+/// 
+/// λabcd.ab(adc)
+/// 
+/// returns a(b(x1), a(d(x2), c(x3))), computing a(d(x2), c(x3)) on another thread
+pub fn par_j<A, B, C, D, X1, X2, X3, O1, O2, O3, O4, R>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> impl Fn(X1, X2, X3) -> R
+where
+    A: Fn(O2, O3) -> O4 + Fn(O1, O4) -> R + Sync,
+    B: Fn(X1) -> O1,
+    C: Fn(X3) -> O3 + Sync,
+    D: Fn(X2) -> O2 + Sync,
+    X2: Send,
+    X3: Send,
+    O4: Send
+{
+    move |x1, x2, x3| {
+        let (a, c, d) = (&a, &c, &d);
+        let (y0, y1) = join(
+            || b(x1),
+            move || a(d(x2), c(x3))
+        );
+        a(y0, y1)
+    }
+}
+
+/// S = Starling
+/// 
+/// λabc.ac(bc)
+/// 
+/// returns a(c(x1), b(c(x2))), computing b(c(x2)) on another thread
+pub fn par_s<A, B, C, X1, X2, O1, O2, O3, R>(
+    a: A,
+    b: B,
+    c: C
+) -> impl Fn(X1, X2) -> R
+where
+    A: Fn(O1, O3) -> R,
+    B: Fn(O2) -> O3 + Sync,
+    C: Fn(X1) -> O1 + Fn(X2) -> O2 + Sync,
+    X2: Send,
+    O3: Send
+{
+    move |x1, x2| {
+        let (b, c) = (&b, &c);
+        let (y0, y1) = join(
+            || c(x1),
+            move || b(c(x2))
+        );
+        a(y0, y1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Barrier;
+    use std::thread::ThreadId;
+
+    use super::*;
+
+    #[test]
+    fn test_birds()
+    {
+        let a = |x: u8, y: u8| x.wrapping_mul(3).wrapping_add(y);
+        let b = |x: u8| x.wrapping_mul(5);
+        let c = |x: u8| x.wrapping_add(7);
+        let d = |x: u8| x.rotate_left(1);
+
+        let starling = par_s(a, b, c);
+        let goldfinch = par_g(a, b, c, d);
+        let dove = par_d(a, b, c, d);
+        let eagle = par_e(a, b, a, c, d);
+        let jay = par_j(a, b, c, d);
+        let bald_eagle = par_ê(a, a, b, c, a, d, b);
+
+        for i in (0..=255).step_by(5)
+        {
+            for j in (0..=255).step_by(15)
+            {
+                assert_eq!(starling(i, j), a(c(i), b(c(j))));
+                assert_eq!(goldfinch(i, j), a(d(i), b(c(j))));
+                assert_eq!(dove(i, j), a(b(i), c(d(j))));
+                assert_eq!(eagle(i, j, i), a(b(i), a(c(j), d(i))));
+                assert_eq!(jay(i, j, i), a(b(i), a(d(j), c(i))));
+                assert_eq!(bald_eagle(i, j, j, i), a(a(b(i), c(j)), a(d(j), b(i))));
+            }
+        }
+    }
+
+    #[test]
+    fn test_threads()
+    {
+        // both branches wait for each other, so they can only finish if they run at the same time
+        let barrier = Barrier::new(2);
+        let thread = |x: u8| {
+            barrier.wait();
+            (x, std::thread::current().id())
+        };
+        let pair = |x: (u8, ThreadId), y: (u8, ThreadId)| (x, y);
+
+        let starling = par_s(pair, |x: (u8, ThreadId)| x, thread);
+
+        let ((x, left), (y, right)) = starling(1, 2);
+
+        assert_eq!((x, y), (1, 2));
+        assert_eq!(left, std::thread::current().id());
+        assert_ne!(left, right);
+    }
+
+    #[test]
+    #[should_panic(expected = "right")]
+    fn test_panic()
+    {
+        let goldfinch = par_g(|x: u8, y: u8| x + y, |x: u8| x, |_: u8| -> u8 { panic!("right") }, |x: u8| x);
+
+        goldfinch(1, 2);
+    }
+}
@@ -0,0 +1,153 @@
+//! Functions reading a shared environment
+//!
+//! A function from an environment `E`, such as a configuration, is a reader, and readers of the same environment
+//! form an applicative functor and a monad, whose operations are birds:
+//! [pure](pure) is K, [ap](ap) is S, [join](join) is W, and [local](local) is B.
+//! They are written out as closures over a single environment, rather than composed from the birds of the crate root,
+//! which take the environment once for each function using it; the tests check that they agree.
+//!
+//! The functions are uncurried, so a function which takes the environment before another argument takes both, as in `f(e, a)`.
+//! The environment is cloned when it is passed to more than one function, so a large one is best passed by reference.
+//!
+//! ```rust
+//! use birbs::reader::lift_a2;
+//!
+//! struct Config
+//! {
+//!     name: &'static str,
+//!     excited: bool
+//! }
+//!
+//! let config = Config {
+//!     name: "world",
+//!     excited: true
+//! };
+//!
+//! let greeting = |config: &Config| format!("hello, {}", config.name);
+//! let punctuation = |config: &Config| if config.excited {"!"} else {"."};
+//!
+//! let message = lift_a2(|x: String, y: &str| x + y, greeting, punctuation);
+//!
+//! assert_eq!(message(&config), "hello, world!");
+//! ```
+
+/// K = Kestrel
+/// 
+/// returns e ↦ a, ignoring the environment
+pub fn pure<E, A: Clone>(value: A) -> impl Fn(E) -> A
+{
+    move |_| value.clone()
+}
+
+/// I = Identity
+/// 
+/// returns e ↦ e, the environment itself
+pub fn ask<E>() -> impl Fn(E) -> E
+{
+    |e| e
+}
+
+/// S = Starling
+/// 
+/// returns e ↦ f(e, g(e))
+pub fn ap<E: Clone, A, B>(f: impl Fn(E, A) -> B, g: impl Fn(E) -> A) -> impl Fn(E) -> B
+{
+    move |e| f(e.clone(), g(e))
+}
+
+/// returns e ↦ h(f(e), g(e)), combining two readers of the same environment
+pub fn lift_a2<E: Clone, A, B, C>(h: impl Fn(A, B) -> C, f: impl Fn(E) -> A, g: impl Fn(E) -> B) -> impl Fn(E) -> C
+{
+    move |e| h(f(e.clone()), g(e))
+}
+
+/// returns e ↦ k(m(e), e), passing on the environment to the reader which depends on the output of the first
+pub fn bind<E: Clone, A, B>(m: impl Fn(E) -> A, k: impl Fn(A, E) -> B) -> impl Fn(E) -> B
+{
+    move |e| k(m(e.clone()), e)
+}
+
+/// W = Warbler
+/// 
+/// returns e ↦ f(e, e)
+pub fn join<E: Clone, A>(f: impl Fn(E, E) -> A) -> impl Fn(E) -> A
+{
+    move |e| f(e.clone(), e)
+}
+
+/// B = Bluebird
+/// 
+/// returns e ↦ m(f(e)), running the reader in an environment changed by the function
+pub fn local<E, F, A>(f: impl Fn(E) -> F, m: impl Fn(F) -> A) -> impl Fn(E) -> A
+{
+    move |e| m(f(e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_birds()
+    {
+        let f = |e: u8, x: u16| e as u16*x;
+        let g = |e: u8| e as u16 + 1;
+        let i = |e: u8| e;
+
+        for e in 0..=255
+        {
+            assert_eq!(pure::<u8, _>(7)(e), crate::k(7, e));
+            assert_eq!(ask()(e), crate::i(e));
+            assert_eq!(ap(f, g)(e), crate::s(f, g, i)(e, e));
+            assert_eq!(join(|x: u8, y: u8| x ^ y.rotate_left(1))(e), crate::w(|x: u8, y: u8| x ^ y.rotate_left(1), i)(e, e));
+            assert_eq!(local(i, g)(e), crate::b(g, i, i)(e));
+        }
+    }
+
+    #[test]
+    fn test_applicative()
+    {
+        /// pure(f ↦ f(9)), uncurried as the first function passed to [ap] is
+        fn apply_to_9(_: u8, f: impl Fn(u8) -> u8) -> u8
+        {
+            f(9)
+        }
+
+        let u = |e: u8, x: u8| e ^ x;
+        let v = |e: u8| e.wrapping_mul(3);
+        let w = |e: u8| e.rotate_left(3);
+        let h = |x: u8| x.wrapping_add(5);
+
+        for e in 0..=255
+        {
+            // identity
+            assert_eq!(ap(|_, x| x, v)(e), v(e));
+            // homomorphism
+            assert_eq!(ap(|_: u8, x| h(x), pure(9))(e), pure::<u8, _>(h(9))(e));
+            // interchange, with u as a reader of the functions x ↦ u(e, x)
+            assert_eq!(ap(u, pure(9))(e), ap(apply_to_9, |e: u8| move |x: u8| u(e, x))(e));
+            // lift_a2 is ap after map
+            assert_eq!(lift_a2(|x: u8, y: u8| x.wrapping_sub(y), v, w)(e), ap(|e, y| v(e).wrapping_sub(y), w)(e));
+        }
+    }
+
+    #[test]
+    fn test_monad()
+    {
+        let m = |e: u8| e.wrapping_mul(3);
+        let k = |x: u8, e: u8| x ^ e;
+        let h = |x: u8, e: u8| x.wrapping_add(e);
+
+        for e in 0..=255
+        {
+            // left identity
+            assert_eq!(bind(pure(9), k)(e), k(9, e));
+            // right identity
+            assert_eq!(bind(m, |x, _| x)(e), m(e));
+            // associativity
+            assert_eq!(bind(bind(m, k), h)(e), bind(m, |x, e| h(k(x, e), e))(e));
+            // join is bind with the environment
+            assert_eq!(join(k)(e), bind(ask(), k)(e));
+        }
+    }
+}
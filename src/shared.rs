@@ -0,0 +1,1106 @@
+//! Duplicating birds without [Copy](Copy)
+//!
+//! J, L, M, M², H, O, S, U, W, W¹, W*, W**, KM and C(KM) use an argument twice, so the crate root requires it to be [Copy](Copy),
+//! which no closure capturing a [String](String), a [Vec](Vec) or a [Box](Box) is.
+//! Each of these birds has four variants here, which duplicate the argument differently:
+//!
+//! * `_clone` clones it, once for every use but the last.
+//! * `_rc` and `_arc` move it behind a [Shared](Shared) pointer, so that only the pointer is cloned.
+//! * `_ref` borrows it, as references are [Copy](Copy).
+//!
+//! ```rust
+//! use birbs::shared::{w_clone, w_rc, w_ref};
+//!
+//! let suffix = String::from("!");
+//! let a = |x: String, y: String| x + &y;
+//! let b = move |x: String| x + &suffix;
+//!
+//! assert_eq!(w_ref(a, &b)("a".into(), "b".into()), "a!b!");
+//! assert_eq!(w_rc(a, b.clone())("a".into(), "b".into()), "a!b!");
+//! assert_eq!(w_clone(a, b)("a".into(), "b".into()), "a!b!");
+//! ```
+
+use std::marker::Tuple;
+use std::ops::Deref;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use currycompose::*;
+
+/// A function behind a pointer, such as [Rc](Rc) or [Arc](Arc), which can be called through it
+///
+/// Cloning it only clones the pointer, so every clone calls the same function.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Shared<P>(pub P);
+
+impl<P: std::fmt::Debug> std::fmt::Debug for Shared<P>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        self.0.fmt(f)
+    }
+}
+
+impl<P: Deref, X: Tuple> FnOnce<X> for Shared<P>
+where
+    P::Target: Fn<X>
+{
+    type Output = <P::Target as FnOnce<X>>::Output;
+
+    extern "rust-call" fn call_once(self, args: X) -> Self::Output
+    {
+        self.0.call(args)
+    }
+}
+
+impl<P: Deref, X: Tuple> FnMut<X> for Shared<P>
+where
+    P::Target: Fn<X>
+{
+    extern "rust-call" fn call_mut(&mut self, args: X) -> Self::Output
+    {
+        self.0.call(args)
+    }
+}
+
+impl<P: Deref, X: Tuple> Fn<X> for Shared<P>
+where
+    P::Target: Fn<X>
+{
+    extern "rust-call" fn call(&self, args: X) -> Self::Output
+    {
+        self.0.call(args)
+    }
+}
+
+/// J = Jay
+/// 
+/// λabcd.ab(adc)
+/// 
+/// [j](crate::j), duplicating `a` by [Clone](Clone)
+pub fn j_clone<A, B, C, D, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> Composition<Composition<A, B, X1A, X1B>, Composition<Composition<A, D, X2A, X2B>, C, X3A, X3B>, X4A, X4B>
+where
+    A: Compose<B, X1A, X1B> + Compose<D, X2A, X2B> + Clone,
+    Composition<A, D, X2A, X2B>: Compose<C, X3A, X3B>,
+    Composition<A, B, X1A, X1B>: Compose<Composition<Composition<A, D, X2A, X2B>, C, X3A, X3B>, X4A, X4B>
+{
+    a.clone().compose(b).compose(a.compose(d).compose(c))
+}
+
+/// L = Lark
+/// 
+/// λab.a(bb)
+/// 
+/// [l](crate::l), duplicating `b` by [Clone](Clone)
+pub fn l_clone<A, B, X1A, X1B, X2A, X2B>(
+    a: A,
+    b: B
+) -> Composition<A, Composition<B, B, X1A, X1B>, X2A, X2B>
+where
+    B: Compose<B, X1A, X1B> + Clone,
+    A: Compose<Composition<B, B, X1A, X1B>, X2A, X2B>
+{
+    a.compose(b.clone().compose(b))
+}
+
+/// M = Mockingbird
+/// 
+/// λa.aa
+/// 
+/// [m](crate::m), duplicating `a` by [Clone](Clone)
+pub fn m_clone<A, X1A, X1B>(
+    a: A
+) -> Composition<A, A, X1A, X1B>
+where
+    A: Compose<A, X1A, X1B> + Clone
+{
+    a.clone().compose(a)
+}
+
+/// M² = Double Mockingbird
+/// 
+/// λab.ab(ab)
+/// 
+/// [m2](crate::m2), duplicating `a` and `b` by [Clone](Clone)
+pub fn m2_clone<A, B, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B
+) -> Composition<Composition<A, B, X1A, X1B>, Composition<A, B, X2A, X2B>, X3A, X3B>
+where
+    A: Compose<B, X1A, X1B> + Compose<B, X2A, X2B> + Clone,
+    B: Clone,
+    Composition<A, B, X1A, X1B>: Compose<Composition<A, B, X2A, X2B>, X3A, X3B>
+{
+    a.clone().compose(b.clone()).compose(a.compose(b))
+}
+
+/// H = Hummingbird
+/// 
+/// λabc.abcb
+/// 
+/// [h](crate::h), duplicating `b` by [Clone](Clone)
+pub fn h_clone<A, B, C, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B,
+    c: C
+) -> Composition<Composition<Composition<A, B, X1A, X1B>, C, X2A, X2B>, B, X3A, X3B>
+where
+    B: Clone,
+    A: Compose<B, X1A, X1B>,
+    Composition<A, B, X1A, X1B>: Compose<C, X2A, X2B>,
+    Composition<Composition<A, B, X1A, X1B>, C, X2A, X2B>: Compose<B, X3A, X3B>
+{
+    a.compose(b.clone()).compose(c).compose(b)
+}
+
+/// U = Turing
+/// 
+/// λab.b(aab)
+/// 
+/// [u](crate::u), duplicating `a` and `b` by [Clone](Clone)
+pub fn u_clone<A, B, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B
+) -> Composition<B, Composition<Composition<A, A, X1A, X1B>, B, X2A, X2B>, X3A, X3B>
+where
+    A: Compose<A, X1A, X1B> + Clone,
+    Composition<A, A, X1A, X1B>: Compose<B, X2A, X2B>,
+    B: Compose<Composition<Composition<A, A, X1A, X1B>, B, X2A, X2B>, X3A, X3B> + Clone
+{
+    b.clone().compose(a.clone().compose(a).compose(b))
+}
+
+/// W = Warbler
+/// 
+/// λab.abb
+/// 
+/// [w](crate::w), duplicating `b` by [Clone](Clone)
+pub fn w_clone<A, B, X1A, X1B, X2A, X2B>(
+    a: A,
+    b: B
+) -> Composition<Composition<A, B, X1A, X1B>, B, X2A, X2B>
+where
+    B: Clone,
+    A: Compose<B, X1A, X1B>,
+    Composition<A, B, X1A, X1B>: Compose<B, X2A, X2B>
+{
+    a.compose(b.clone()).compose(b)
+}
+
+/// W¹ = Converse Warbler
+/// 
+/// λab.baa
+/// 
+/// [w1](crate::w1), duplicating `a` by [Clone](Clone)
+pub fn w1_clone<A, B, X1A, X1B, X2A, X2B>(
+    a: A,
+    b: B
+) -> Composition<Composition<B, A, X1A, X1B>, A, X2A, X2B>
+where
+    A: Clone,
+    B: Compose<A, X1A, X1B>,
+    Composition<B, A, X1A, X1B>: Compose<A, X2A, X2B>
+{
+    b.compose(a.clone()).compose(a)
+}
+
+/// W* = Warbled Once Removed
+/// 
+/// λabc.abcc
+/// 
+/// [w_star](crate::w_star), duplicating `c` by [Clone](Clone)
+pub fn w_star_clone<A, B, C, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B,
+    c: C
+) -> Composition<Composition<Composition<A, B, X1A, X1B>, C, X2A, X2B>, C, X3A, X3B>
+where
+    C: Clone,
+    A: Compose<B, X1A, X1B>,
+    Composition<A, B, X1A, X1B>: Compose<C, X2A, X2B>,
+    Composition<Composition<A, B, X1A, X1B>, C, X2A, X2B>: Compose<C, X3A, X3B>
+{
+    a.compose(b).compose(c.clone()).compose(c)
+}
+
+/// W** = Warbler Twice Removed
+/// 
+/// λabcd.abcdd
+/// 
+/// [w_star_star](crate::w_star_star), duplicating `d` by [Clone](Clone)
+pub fn w_star_star_clone<A, B, C, D, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> Composition<Composition<Composition<Composition<A, B, X1A, X1B>, C, X2A, X2B>, D, X3A, X3B>, D, X4A, X4B>
+where
+    D: Clone,
+    A: Compose<B, X1A, X1B>,
+    Composition<A, B, X1A, X1B>: Compose<C, X2A, X2B>,
+    Composition<Composition<A, B, X1A, X1B>, C, X2A, X2B>: Compose<D, X3A, X3B>,
+    Composition<Composition<Composition<A, B, X1A, X1B>, C, X2A, X2B>, D, X3A, X3B>: Compose<D, X4A, X4B>
+{
+    a.compose(b).compose(c).compose(d.clone()).compose(d)
+}
+
+/// KM = Constant Mocker
+/// 
+/// λab.bb
+/// 
+/// [km](crate::km), duplicating `b` by [Clone](Clone)
+#[allow(unused)]
+pub fn km_clone<A, B, X1A, X1B>(
+    a: A,
+    b: B
+) -> Composition<B, B, X1A, X1B>
+where
+    B: Compose<B, X1A, X1B> + Clone
+{
+    b.clone().compose(b)
+}
+
+/// C(KM) = Crossed Constant Mocker
+/// 
+/// λab.aa
+/// 
+/// [ckm](crate::ckm), duplicating `a` by [Clone](Clone)
+#[allow(unused)]
+pub fn ckm_clone<A, B, X1A, X1B>(
+    a: A,
+    b: B
+) -> Composition<A, A, X1A, X1B>
+where
+    A: Compose<A, X1A, X1B> + Clone
+{
+    a.clone().compose(a)
+}
+
+/// O = Owl
+/// 
+/// λab.b(ab)
+/// 
+/// [o](crate::o), duplicating `b` by [Clone](Clone)
+pub fn o_clone<A, B, X1A, X1B, X2A, X2B>(
+    a: A,
+    b: B
+) -> Composition<B, Composition<A, B, X1A, X1B>, X2A, X2B>
+where
+    A: Compose<B, X1A, X1B>,
+    B: Compose<Composition<A, B, X1A, X1B>, X2A, X2B> + Clone
+{
+    b.clone().compose(a.compose(b))
+}
+
+/// S = Starling
+/// 
+/// λabc.ac(bc)
+/// 
+/// [s](crate::s), duplicating `c` by [Clone](Clone)
+pub fn s_clone<A, B, C, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B,
+    c: C
+) -> Composition<Composition<A, C, X1A, X1B>, Composition<B, C, X2A, X2B>, X3A, X3B>
+where
+    A: Compose<C, X1A, X1B>,
+    B: Compose<C, X2A, X2B>,
+    Composition<A, C, X1A, X1B>: Compose<Composition<B, C, X2A, X2B>, X3A, X3B>,
+    C: Clone
+{
+    a.compose(c.clone()).compose(b.compose(c))
+}
+
+/// J = Jay
+/// 
+/// λabcd.ab(adc)
+/// 
+/// [j](crate::j), duplicating `a` by sharing it through [Rc](Rc)
+pub fn j_rc<A, B, C, D, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> Composition<Composition<Shared<Rc<A>>, B, X1A, X1B>, Composition<Composition<Shared<Rc<A>>, D, X2A, X2B>, C, X3A, X3B>, X4A, X4B>
+where
+    Shared<Rc<A>>: Compose<B, X1A, X1B> + Compose<D, X2A, X2B>,
+    Composition<Shared<Rc<A>>, D, X2A, X2B>: Compose<C, X3A, X3B>,
+    Composition<Shared<Rc<A>>, B, X1A, X1B>: Compose<Composition<Composition<Shared<Rc<A>>, D, X2A, X2B>, C, X3A, X3B>, X4A, X4B>
+{
+    j_clone(Shared(Rc::new(a)), b, c, d)
+}
+
+/// L = Lark
+/// 
+/// λab.a(bb)
+/// 
+/// [l](crate::l), duplicating `b` by sharing it through [Rc](Rc)
+pub fn l_rc<A, B, X1A, X1B, X2A, X2B>(
+    a: A,
+    b: B
+) -> Composition<A, Composition<Shared<Rc<B>>, Shared<Rc<B>>, X1A, X1B>, X2A, X2B>
+where
+    Shared<Rc<B>>: Compose<Shared<Rc<B>>, X1A, X1B>,
+    A: Compose<Composition<Shared<Rc<B>>, Shared<Rc<B>>, X1A, X1B>, X2A, X2B>
+{
+    l_clone(a, Shared(Rc::new(b)))
+}
+
+/// M = Mockingbird
+/// 
+/// λa.aa
+/// 
+/// [m](crate::m), duplicating `a` by sharing it through [Rc](Rc)
+pub fn m_rc<A, X1A, X1B>(
+    a: A
+) -> Composition<Shared<Rc<A>>, Shared<Rc<A>>, X1A, X1B>
+where
+    Shared<Rc<A>>: Compose<Shared<Rc<A>>, X1A, X1B>
+{
+    m_clone(Shared(Rc::new(a)))
+}
+
+/// M² = Double Mockingbird
+/// 
+/// λab.ab(ab)
+/// 
+/// [m2](crate::m2), duplicating `a` and `b` by sharing them through [Rc](Rc)
+pub fn m2_rc<A, B, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B
+) -> Composition<Composition<Shared<Rc<A>>, Shared<Rc<B>>, X1A, X1B>, Composition<Shared<Rc<A>>, Shared<Rc<B>>, X2A, X2B>, X3A, X3B>
+where
+    Shared<Rc<A>>: Compose<Shared<Rc<B>>, X1A, X1B> + Compose<Shared<Rc<B>>, X2A, X2B>,
+    Composition<Shared<Rc<A>>, Shared<Rc<B>>, X1A, X1B>: Compose<Composition<Shared<Rc<A>>, Shared<Rc<B>>, X2A, X2B>, X3A, X3B>
+{
+    m2_clone(Shared(Rc::new(a)), Shared(Rc::new(b)))
+}
+
+/// H = Hummingbird
+/// 
+/// λabc.abcb
+/// 
+/// [h](crate::h), duplicating `b` by sharing it through [Rc](Rc)
+pub fn h_rc<A, B, C, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B,
+    c: C
+) -> Composition<Composition<Composition<A, Shared<Rc<B>>, X1A, X1B>, C, X2A, X2B>, Shared<Rc<B>>, X3A, X3B>
+where
+    A: Compose<Shared<Rc<B>>, X1A, X1B>,
+    Composition<A, Shared<Rc<B>>, X1A, X1B>: Compose<C, X2A, X2B>,
+    Composition<Composition<A, Shared<Rc<B>>, X1A, X1B>, C, X2A, X2B>: Compose<Shared<Rc<B>>, X3A, X3B>
+{
+    h_clone(a, Shared(Rc::new(b)), c)
+}
+
+/// U = Turing
+/// 
+/// λab.b(aab)
+/// 
+/// [u](crate::u), duplicating `a` and `b` by sharing them through [Rc](Rc)
+pub fn u_rc<A, B, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B
+) -> Composition<Shared<Rc<B>>, Composition<Composition<Shared<Rc<A>>, Shared<Rc<A>>, X1A, X1B>, Shared<Rc<B>>, X2A, X2B>, X3A, X3B>
+where
+    Shared<Rc<A>>: Compose<Shared<Rc<A>>, X1A, X1B>,
+    Composition<Shared<Rc<A>>, Shared<Rc<A>>, X1A, X1B>: Compose<Shared<Rc<B>>, X2A, X2B>,
+    Shared<Rc<B>>: Compose<Composition<Composition<Shared<Rc<A>>, Shared<Rc<A>>, X1A, X1B>, Shared<Rc<B>>, X2A, X2B>, X3A, X3B>
+{
+    u_clone(Shared(Rc::new(a)), Shared(Rc::new(b)))
+}
+
+/// W = Warbler
+/// 
+/// λab.abb
+/// 
+/// [w](crate::w), duplicating `b` by sharing it through [Rc](Rc)
+pub fn w_rc<A, B, X1A, X1B, X2A, X2B>(
+    a: A,
+    b: B
+) -> Composition<Composition<A, Shared<Rc<B>>, X1A, X1B>, Shared<Rc<B>>, X2A, X2B>
+where
+    A: Compose<Shared<Rc<B>>, X1A, X1B>,
+    Composition<A, Shared<Rc<B>>, X1A, X1B>: Compose<Shared<Rc<B>>, X2A, X2B>
+{
+    w_clone(a, Shared(Rc::new(b)))
+}
+
+/// W¹ = Converse Warbler
+/// 
+/// λab.baa
+/// 
+/// [w1](crate::w1), duplicating `a` by sharing it through [Rc](Rc)
+pub fn w1_rc<A, B, X1A, X1B, X2A, X2B>(
+    a: A,
+    b: B
+) -> Composition<Composition<B, Shared<Rc<A>>, X1A, X1B>, Shared<Rc<A>>, X2A, X2B>
+where
+    B: Compose<Shared<Rc<A>>, X1A, X1B>,
+    Composition<B, Shared<Rc<A>>, X1A, X1B>: Compose<Shared<Rc<A>>, X2A, X2B>
+{
+    w1_clone(Shared(Rc::new(a)), b)
+}
+
+/// W* = Warbled Once Removed
+/// 
+/// λabc.abcc
+/// 
+/// [w_star](crate::w_star), duplicating `c` by sharing it through [Rc](Rc)
+pub fn w_star_rc<A, B, C, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B,
+    c: C
+) -> Composition<Composition<Composition<A, B, X1A, X1B>, Shared<Rc<C>>, X2A, X2B>, Shared<Rc<C>>, X3A, X3B>
+where
+    A: Compose<B, X1A, X1B>,
+    Composition<A, B, X1A, X1B>: Compose<Shared<Rc<C>>, X2A, X2B>,
+    Composition<Composition<A, B, X1A, X1B>, Shared<Rc<C>>, X2A, X2B>: Compose<Shared<Rc<C>>, X3A, X3B>
+{
+    w_star_clone(a, b, Shared(Rc::new(c)))
+}
+
+/// W** = Warbler Twice Removed
+/// 
+/// λabcd.abcdd
+/// 
+/// [w_star_star](crate::w_star_star), duplicating `d` by sharing it through [Rc](Rc)
+pub fn w_star_star_rc<A, B, C, D, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> Composition<Composition<Composition<Composition<A, B, X1A, X1B>, C, X2A, X2B>, Shared<Rc<D>>, X3A, X3B>, Shared<Rc<D>>, X4A, X4B>
+where
+    A: Compose<B, X1A, X1B>,
+    Composition<A, B, X1A, X1B>: Compose<C, X2A, X2B>,
+    Composition<Composition<A, B, X1A, X1B>, C, X2A, X2B>: Compose<Shared<Rc<D>>, X3A, X3B>,
+    Composition<Composition<Composition<A, B, X1A, X1B>, C, X2A, X2B>, Shared<Rc<D>>, X3A, X3B>: Compose<Shared<Rc<D>>, X4A, X4B>
+{
+    w_star_star_clone(a, b, c, Shared(Rc::new(d)))
+}
+
+/// KM = Constant Mocker
+/// 
+/// λab.bb
+/// 
+/// [km](crate::km), duplicating `b` by sharing it through [Rc](Rc)
+#[allow(unused)]
+pub fn km_rc<A, B, X1A, X1B>(
+    a: A,
+    b: B
+) -> Composition<Shared<Rc<B>>, Shared<Rc<B>>, X1A, X1B>
+where
+    Shared<Rc<B>>: Compose<Shared<Rc<B>>, X1A, X1B>
+{
+    km_clone(a, Shared(Rc::new(b)))
+}
+
+/// C(KM) = Crossed Constant Mocker
+/// 
+/// λab.aa
+/// 
+/// [ckm](crate::ckm), duplicating `a` by sharing it through [Rc](Rc)
+#[allow(unused)]
+pub fn ckm_rc<A, B, X1A, X1B>(
+    a: A,
+    b: B
+) -> Composition<Shared<Rc<A>>, Shared<Rc<A>>, X1A, X1B>
+where
+    Shared<Rc<A>>: Compose<Shared<Rc<A>>, X1A, X1B>
+{
+    ckm_clone(Shared(Rc::new(a)), b)
+}
+
+/// O = Owl
+/// 
+/// λab.b(ab)
+/// 
+/// [o](crate::o), duplicating `b` by sharing it through [Rc](Rc)
+pub fn o_rc<A, B, X1A, X1B, X2A, X2B>(
+    a: A,
+    b: B
+) -> Composition<Shared<Rc<B>>, Composition<A, Shared<Rc<B>>, X1A, X1B>, X2A, X2B>
+where
+    A: Compose<Shared<Rc<B>>, X1A, X1B>,
+    Shared<Rc<B>>: Compose<Composition<A, Shared<Rc<B>>, X1A, X1B>, X2A, X2B>
+{
+    o_clone(a, Shared(Rc::new(b)))
+}
+
+/// S = Starling
+/// 
+/// λabc.ac(bc)
+/// 
+/// [s](crate::s), duplicating `c` by sharing it through [Rc](Rc)
+pub fn s_rc<A, B, C, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B,
+    c: C
+) -> Composition<Composition<A, Shared<Rc<C>>, X1A, X1B>, Composition<B, Shared<Rc<C>>, X2A, X2B>, X3A, X3B>
+where
+    A: Compose<Shared<Rc<C>>, X1A, X1B>,
+    B: Compose<Shared<Rc<C>>, X2A, X2B>,
+    Composition<A, Shared<Rc<C>>, X1A, X1B>: Compose<Composition<B, Shared<Rc<C>>, X2A, X2B>, X3A, X3B>
+{
+    s_clone(a, b, Shared(Rc::new(c)))
+}
+
+/// J = Jay
+/// 
+/// λabcd.ab(adc)
+/// 
+/// [j](crate::j), duplicating `a` by sharing it through [Arc](Arc)
+pub fn j_arc<A, B, C, D, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> Composition<Composition<Shared<Arc<A>>, B, X1A, X1B>, Composition<Composition<Shared<Arc<A>>, D, X2A, X2B>, C, X3A, X3B>, X4A, X4B>
+where
+    Shared<Arc<A>>: Compose<B, X1A, X1B> + Compose<D, X2A, X2B>,
+    Composition<Shared<Arc<A>>, D, X2A, X2B>: Compose<C, X3A, X3B>,
+    Composition<Shared<Arc<A>>, B, X1A, X1B>: Compose<Composition<Composition<Shared<Arc<A>>, D, X2A, X2B>, C, X3A, X3B>, X4A, X4B>
+{
+    j_clone(Shared(Arc::new(a)), b, c, d)
+}
+
+/// L = Lark
+/// 
+/// λab.a(bb)
+/// 
+/// [l](crate::l), duplicating `b` by sharing it through [Arc](Arc)
+pub fn l_arc<A, B, X1A, X1B, X2A, X2B>(
+    a: A,
+    b: B
+) -> Composition<A, Composition<Shared<Arc<B>>, Shared<Arc<B>>, X1A, X1B>, X2A, X2B>
+where
+    Shared<Arc<B>>: Compose<Shared<Arc<B>>, X1A, X1B>,
+    A: Compose<Composition<Shared<Arc<B>>, Shared<Arc<B>>, X1A, X1B>, X2A, X2B>
+{
+    l_clone(a, Shared(Arc::new(b)))
+}
+
+/// M = Mockingbird
+/// 
+/// λa.aa
+/// 
+/// [m](crate::m), duplicating `a` by sharing it through [Arc](Arc)
+pub fn m_arc<A, X1A, X1B>(
+    a: A
+) -> Composition<Shared<Arc<A>>, Shared<Arc<A>>, X1A, X1B>
+where
+    Shared<Arc<A>>: Compose<Shared<Arc<A>>, X1A, X1B>
+{
+    m_clone(Shared(Arc::new(a)))
+}
+
+/// M² = Double Mockingbird
+/// 
+/// λab.ab(ab)
+/// 
+/// [m2](crate::m2), duplicating `a` and `b` by sharing them through [Arc](Arc)
+pub fn m2_arc<A, B, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B
+) -> Composition<Composition<Shared<Arc<A>>, Shared<Arc<B>>, X1A, X1B>, Composition<Shared<Arc<A>>, Shared<Arc<B>>, X2A, X2B>, X3A, X3B>
+where
+    Shared<Arc<A>>: Compose<Shared<Arc<B>>, X1A, X1B> + Compose<Shared<Arc<B>>, X2A, X2B>,
+    Composition<Shared<Arc<A>>, Shared<Arc<B>>, X1A, X1B>: Compose<Composition<Shared<Arc<A>>, Shared<Arc<B>>, X2A, X2B>, X3A, X3B>
+{
+    m2_clone(Shared(Arc::new(a)), Shared(Arc::new(b)))
+}
+
+/// H = Hummingbird
+/// 
+/// λabc.abcb
+/// 
+/// [h](crate::h), duplicating `b` by sharing it through [Arc](Arc)
+pub fn h_arc<A, B, C, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B,
+    c: C
+) -> Composition<Composition<Composition<A, Shared<Arc<B>>, X1A, X1B>, C, X2A, X2B>, Shared<Arc<B>>, X3A, X3B>
+where
+    A: Compose<Shared<Arc<B>>, X1A, X1B>,
+    Composition<A, Shared<Arc<B>>, X1A, X1B>: Compose<C, X2A, X2B>,
+    Composition<Composition<A, Shared<Arc<B>>, X1A, X1B>, C, X2A, X2B>: Compose<Shared<Arc<B>>, X3A, X3B>
+{
+    h_clone(a, Shared(Arc::new(b)), c)
+}
+
+/// U = Turing
+/// 
+/// λab.b(aab)
+/// 
+/// [u](crate::u), duplicating `a` and `b` by sharing them through [Arc](Arc)
+pub fn u_arc<A, B, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B
+) -> Composition<Shared<Arc<B>>, Composition<Composition<Shared<Arc<A>>, Shared<Arc<A>>, X1A, X1B>, Shared<Arc<B>>, X2A, X2B>, X3A, X3B>
+where
+    Shared<Arc<A>>: Compose<Shared<Arc<A>>, X1A, X1B>,
+    Composition<Shared<Arc<A>>, Shared<Arc<A>>, X1A, X1B>: Compose<Shared<Arc<B>>, X2A, X2B>,
+    Shared<Arc<B>>: Compose<Composition<Composition<Shared<Arc<A>>, Shared<Arc<A>>, X1A, X1B>, Shared<Arc<B>>, X2A, X2B>, X3A, X3B>
+{
+    u_clone(Shared(Arc::new(a)), Shared(Arc::new(b)))
+}
+
+/// W = Warbler
+/// 
+/// λab.abb
+/// 
+/// [w](crate::w), duplicating `b` by sharing it through [Arc](Arc)
+pub fn w_arc<A, B, X1A, X1B, X2A, X2B>(
+    a: A,
+    b: B
+) -> Composition<Composition<A, Shared<Arc<B>>, X1A, X1B>, Shared<Arc<B>>, X2A, X2B>
+where
+    A: Compose<Shared<Arc<B>>, X1A, X1B>,
+    Composition<A, Shared<Arc<B>>, X1A, X1B>: Compose<Shared<Arc<B>>, X2A, X2B>
+{
+    w_clone(a, Shared(Arc::new(b)))
+}
+
+/// W¹ = Converse Warbler
+/// 
+/// λab.baa
+/// 
+/// [w1](crate::w1), duplicating `a` by sharing it through [Arc](Arc)
+pub fn w1_arc<A, B, X1A, X1B, X2A, X2B>(
+    a: A,
+    b: B
+) -> Composition<Composition<B, Shared<Arc<A>>, X1A, X1B>, Shared<Arc<A>>, X2A, X2B>
+where
+    B: Compose<Shared<Arc<A>>, X1A, X1B>,
+    Composition<B, Shared<Arc<A>>, X1A, X1B>: Compose<Shared<Arc<A>>, X2A, X2B>
+{
+    w1_clone(Shared(Arc::new(a)), b)
+}
+
+/// W* = Warbled Once Removed
+/// 
+/// λabc.abcc
+/// 
+/// [w_star](crate::w_star), duplicating `c` by sharing it through [Arc](Arc)
+pub fn w_star_arc<A, B, C, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B,
+    c: C
+) -> Composition<Composition<Composition<A, B, X1A, X1B>, Shared<Arc<C>>, X2A, X2B>, Shared<Arc<C>>, X3A, X3B>
+where
+    A: Compose<B, X1A, X1B>,
+    Composition<A, B, X1A, X1B>: Compose<Shared<Arc<C>>, X2A, X2B>,
+    Composition<Composition<A, B, X1A, X1B>, Shared<Arc<C>>, X2A, X2B>: Compose<Shared<Arc<C>>, X3A, X3B>
+{
+    w_star_clone(a, b, Shared(Arc::new(c)))
+}
+
+/// W** = Warbler Twice Removed
+/// 
+/// λabcd.abcdd
+/// 
+/// [w_star_star](crate::w_star_star), duplicating `d` by sharing it through [Arc](Arc)
+pub fn w_star_star_arc<A, B, C, D, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> Composition<Composition<Composition<Composition<A, B, X1A, X1B>, C, X2A, X2B>, Shared<Arc<D>>, X3A, X3B>, Shared<Arc<D>>, X4A, X4B>
+where
+    A: Compose<B, X1A, X1B>,
+    Composition<A, B, X1A, X1B>: Compose<C, X2A, X2B>,
+    Composition<Composition<A, B, X1A, X1B>, C, X2A, X2B>: Compose<Shared<Arc<D>>, X3A, X3B>,
+    Composition<Composition<Composition<A, B, X1A, X1B>, C, X2A, X2B>, Shared<Arc<D>>, X3A, X3B>: Compose<Shared<Arc<D>>, X4A, X4B>
+{
+    w_star_star_clone(a, b, c, Shared(Arc::new(d)))
+}
+
+/// KM = Constant Mocker
+/// 
+/// λab.bb
+/// 
+/// [km](crate::km), duplicating `b` by sharing it through [Arc](Arc)
+#[allow(unused)]
+pub fn km_arc<A, B, X1A, X1B>(
+    a: A,
+    b: B
+) -> Composition<Shared<Arc<B>>, Shared<Arc<B>>, X1A, X1B>
+where
+    Shared<Arc<B>>: Compose<Shared<Arc<B>>, X1A, X1B>
+{
+    km_clone(a, Shared(Arc::new(b)))
+}
+
+/// C(KM) = Crossed Constant Mocker
+/// 
+/// λab.aa
+/// 
+/// [ckm](crate::ckm), duplicating `a` by sharing it through [Arc](Arc)
+#[allow(unused)]
+pub fn ckm_arc<A, B, X1A, X1B>(
+    a: A,
+    b: B
+) -> Composition<Shared<Arc<A>>, Shared<Arc<A>>, X1A, X1B>
+where
+    Shared<Arc<A>>: Compose<Shared<Arc<A>>, X1A, X1B>
+{
+    ckm_clone(Shared(Arc::new(a)), b)
+}
+
+/// O = Owl
+/// 
+/// λab.b(ab)
+/// 
+/// [o](crate::o), duplicating `b` by sharing it through [Arc](Arc)
+pub fn o_arc<A, B, X1A, X1B, X2A, X2B>(
+    a: A,
+    b: B
+) -> Composition<Shared<Arc<B>>, Composition<A, Shared<Arc<B>>, X1A, X1B>, X2A, X2B>
+where
+    A: Compose<Shared<Arc<B>>, X1A, X1B>,
+    Shared<Arc<B>>: Compose<Composition<A, Shared<Arc<B>>, X1A, X1B>, X2A, X2B>
+{
+    o_clone(a, Shared(Arc::new(b)))
+}
+
+/// S = Starling
+/// 
+/// λabc.ac(bc)
+/// 
+/// [s](crate::s), duplicating `c` by sharing it through [Arc](Arc)
+pub fn s_arc<A, B, C, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B,
+    c: C
+) -> Composition<Composition<A, Shared<Arc<C>>, X1A, X1B>, Composition<B, Shared<Arc<C>>, X2A, X2B>, X3A, X3B>
+where
+    A: Compose<Shared<Arc<C>>, X1A, X1B>,
+    B: Compose<Shared<Arc<C>>, X2A, X2B>,
+    Composition<A, Shared<Arc<C>>, X1A, X1B>: Compose<Composition<B, Shared<Arc<C>>, X2A, X2B>, X3A, X3B>
+{
+    s_clone(a, b, Shared(Arc::new(c)))
+}
+
+/// J = Jay
+/// 
+/// λabcd.ab(adc)
+/// 
+/// [j](crate::j), duplicating `a` by reference
+pub fn j_ref<'f, A, B, C, D, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    a: &'f A,
+    b: B,
+    c: C,
+    d: D
+) -> Composition<Composition<&'f A, B, X1A, X1B>, Composition<Composition<&'f A, D, X2A, X2B>, C, X3A, X3B>, X4A, X4B>
+where
+    &'f A: Compose<B, X1A, X1B> + Compose<D, X2A, X2B>,
+    Composition<&'f A, D, X2A, X2B>: Compose<C, X3A, X3B>,
+    Composition<&'f A, B, X1A, X1B>: Compose<Composition<Composition<&'f A, D, X2A, X2B>, C, X3A, X3B>, X4A, X4B>
+{
+    crate::j(a, b, c, d)
+}
+
+/// L = Lark
+/// 
+/// λab.a(bb)
+/// 
+/// [l](crate::l), duplicating `b` by reference
+pub fn l_ref<'f, A, B, X1A, X1B, X2A, X2B>(
+    a: A,
+    b: &'f B
+) -> Composition<A, Composition<&'f B, &'f B, X1A, X1B>, X2A, X2B>
+where
+    &'f B: Compose<&'f B, X1A, X1B>,
+    A: Compose<Composition<&'f B, &'f B, X1A, X1B>, X2A, X2B>
+{
+    crate::l(a, b)
+}
+
+/// M = Mockingbird
+/// 
+/// λa.aa
+/// 
+/// [m](crate::m), duplicating `a` by reference
+pub fn m_ref<'f, A, X1A, X1B>(
+    a: &'f A
+) -> Composition<&'f A, &'f A, X1A, X1B>
+where
+    &'f A: Compose<&'f A, X1A, X1B>
+{
+    crate::m(a)
+}
+
+/// M² = Double Mockingbird
+/// 
+/// λab.ab(ab)
+/// 
+/// [m2](crate::m2), duplicating `a` and `b` by reference
+pub fn m2_ref<'f, A, B, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: &'f A,
+    b: &'f B
+) -> Composition<Composition<&'f A, &'f B, X1A, X1B>, Composition<&'f A, &'f B, X2A, X2B>, X3A, X3B>
+where
+    &'f A: Compose<&'f B, X1A, X1B> + Compose<&'f B, X2A, X2B>,
+    Composition<&'f A, &'f B, X1A, X1B>: Compose<Composition<&'f A, &'f B, X2A, X2B>, X3A, X3B>
+{
+    crate::m2(a, b)
+}
+
+/// H = Hummingbird
+/// 
+/// λabc.abcb
+/// 
+/// [h](crate::h), duplicating `b` by reference
+pub fn h_ref<'f, A, B, C, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: &'f B,
+    c: C
+) -> Composition<Composition<Composition<A, &'f B, X1A, X1B>, C, X2A, X2B>, &'f B, X3A, X3B>
+where
+    A: Compose<&'f B, X1A, X1B>,
+    Composition<A, &'f B, X1A, X1B>: Compose<C, X2A, X2B>,
+    Composition<Composition<A, &'f B, X1A, X1B>, C, X2A, X2B>: Compose<&'f B, X3A, X3B>
+{
+    crate::h(a, b, c)
+}
+
+/// U = Turing
+/// 
+/// λab.b(aab)
+/// 
+/// [u](crate::u), duplicating `a` and `b` by reference
+pub fn u_ref<'f, A, B, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: &'f A,
+    b: &'f B
+) -> Composition<&'f B, Composition<Composition<&'f A, &'f A, X1A, X1B>, &'f B, X2A, X2B>, X3A, X3B>
+where
+    &'f A: Compose<&'f A, X1A, X1B>,
+    Composition<&'f A, &'f A, X1A, X1B>: Compose<&'f B, X2A, X2B>,
+    &'f B: Compose<Composition<Composition<&'f A, &'f A, X1A, X1B>, &'f B, X2A, X2B>, X3A, X3B>
+{
+    crate::u(a, b)
+}
+
+/// W = Warbler
+/// 
+/// λab.abb
+/// 
+/// [w](crate::w), duplicating `b` by reference
+pub fn w_ref<'f, A, B, X1A, X1B, X2A, X2B>(
+    a: A,
+    b: &'f B
+) -> Composition<Composition<A, &'f B, X1A, X1B>, &'f B, X2A, X2B>
+where
+    A: Compose<&'f B, X1A, X1B>,
+    Composition<A, &'f B, X1A, X1B>: Compose<&'f B, X2A, X2B>
+{
+    crate::w(a, b)
+}
+
+/// W¹ = Converse Warbler
+/// 
+/// λab.baa
+/// 
+/// [w1](crate::w1), duplicating `a` by reference
+pub fn w1_ref<'f, A, B, X1A, X1B, X2A, X2B>(
+    a: &'f A,
+    b: B
+) -> Composition<Composition<B, &'f A, X1A, X1B>, &'f A, X2A, X2B>
+where
+    B: Compose<&'f A, X1A, X1B>,
+    Composition<B, &'f A, X1A, X1B>: Compose<&'f A, X2A, X2B>
+{
+    crate::w1(a, b)
+}
+
+/// W* = Warbled Once Removed
+/// 
+/// λabc.abcc
+/// 
+/// [w_star](crate::w_star), duplicating `c` by reference
+pub fn w_star_ref<'f, A, B, C, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B,
+    c: &'f C
+) -> Composition<Composition<Composition<A, B, X1A, X1B>, &'f C, X2A, X2B>, &'f C, X3A, X3B>
+where
+    A: Compose<B, X1A, X1B>,
+    Composition<A, B, X1A, X1B>: Compose<&'f C, X2A, X2B>,
+    Composition<Composition<A, B, X1A, X1B>, &'f C, X2A, X2B>: Compose<&'f C, X3A, X3B>
+{
+    crate::w_star(a, b, c)
+}
+
+/// W** = Warbler Twice Removed
+/// 
+/// λabcd.abcdd
+/// 
+/// [w_star_star](crate::w_star_star), duplicating `d` by reference
+pub fn w_star_star_ref<'f, A, B, C, D, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    a: A,
+    b: B,
+    c: C,
+    d: &'f D
+) -> Composition<Composition<Composition<Composition<A, B, X1A, X1B>, C, X2A, X2B>, &'f D, X3A, X3B>, &'f D, X4A, X4B>
+where
+    A: Compose<B, X1A, X1B>,
+    Composition<A, B, X1A, X1B>: Compose<C, X2A, X2B>,
+    Composition<Composition<A, B, X1A, X1B>, C, X2A, X2B>: Compose<&'f D, X3A, X3B>,
+    Composition<Composition<Composition<A, B, X1A, X1B>, C, X2A, X2B>, &'f D, X3A, X3B>: Compose<&'f D, X4A, X4B>
+{
+    crate::w_star_star(a, b, c, d)
+}
+
+/// KM = Constant Mocker
+/// 
+/// λab.bb
+/// 
+/// [km](crate::km), duplicating `b` by reference
+#[allow(unused)]
+pub fn km_ref<'f, A, B, X1A, X1B>(
+    a: A,
+    b: &'f B
+) -> Composition<&'f B, &'f B, X1A, X1B>
+where
+    &'f B: Compose<&'f B, X1A, X1B>
+{
+    crate::km(a, b)
+}
+
+/// C(KM) = Crossed Constant Mocker
+/// 
+/// λab.aa
+/// 
+/// [ckm](crate::ckm), duplicating `a` by reference
+#[allow(unused)]
+pub fn ckm_ref<'f, A, B, X1A, X1B>(
+    a: &'f A,
+    b: B
+) -> Composition<&'f A, &'f A, X1A, X1B>
+where
+    &'f A: Compose<&'f A, X1A, X1B>
+{
+    crate::ckm(a, b)
+}
+
+/// O = Owl
+/// 
+/// λab.b(ab)
+/// 
+/// [o](crate::o), duplicating `b` by reference
+pub fn o_ref<'f, A, B, X1A, X1B, X2A, X2B>(
+    a: A,
+    b: &'f B
+) -> Composition<&'f B, Composition<A, &'f B, X1A, X1B>, X2A, X2B>
+where
+    A: Compose<&'f B, X1A, X1B>,
+    &'f B: Compose<Composition<A, &'f B, X1A, X1B>, X2A, X2B>
+{
+    crate::o(a, b)
+}
+
+/// S = Starling
+/// 
+/// λabc.ac(bc)
+/// 
+/// [s](crate::s), duplicating `c` by reference
+pub fn s_ref<'f, A, B, C, X1A, X1B, X2A, X2B, X3A, X3B>(
+    a: A,
+    b: B,
+    c: &'f C
+) -> Composition<Composition<A, &'f C, X1A, X1B>, Composition<B, &'f C, X2A, X2B>, X3A, X3B>
+where
+    A: Compose<&'f C, X1A, X1B>,
+    B: Compose<&'f C, X2A, X2B>,
+    Composition<A, &'f C, X1A, X1B>: Compose<Composition<B, &'f C, X2A, X2B>, X3A, X3B>
+{
+    crate::s(a, b, c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shared()
+    {
+        let suffix = String::from("!");
+        let f = Shared(Rc::new(move |x: &str| x.to_string() + &suffix));
+        let g = f.clone();
+
+        assert_eq!(f("a"), "a!");
+        assert_eq!(g("b"), "b!");
+        assert_eq!(Rc::strong_count(&f.0), 2);
+    }
+
+    #[test]
+    fn test_warbler()
+    {
+        let suffix = String::from("!");
+        let a = |x: String, y: String| x + &y;
+        let b = move |x: String| x + &suffix;
+
+        let expected = |x: &str, y: &str| format!("{x}!{y}!");
+
+        for (x, y) in [("a", "b"), ("", "c"), ("d", "")]
+        {
+            assert_eq!(w_clone(a, b.clone())(x.into(), y.into()), expected(x, y));
+            assert_eq!(w_rc(a, b.clone())(x.into(), y.into()), expected(x, y));
+            assert_eq!(w_arc(a, b.clone())(x.into(), y.into()), expected(x, y));
+            assert_eq!(w_ref(a, &b)(x.into(), y.into()), expected(x, y));
+        }
+
+        // shared through an Arc, the composition can be sent to another thread
+        let f = w_arc(a, b);
+        let result = std::thread::spawn(move || f("a".into(), "b".into()))
+            .join()
+            .unwrap();
+        assert_eq!(result, "a!b!");
+    }
+
+    #[test]
+    fn test_birds()
+    {
+        let suffix = String::from("!");
+        let a = move |x: String| x + &suffix;
+        let b = |x: String| x.to_uppercase();
+
+        // a ∘ a
+        assert_eq!(m_clone(a.clone())("x".into()), "x!!");
+        assert_eq!(m_rc(a.clone())("x".into()), "x!!");
+        assert_eq!(m_ref(&a)("x".into()), "x!!");
+
+        // a ∘ (b ∘ b)
+        assert_eq!(l_clone(a.clone(), b)("x".into()), "X!");
+        assert_eq!(l_arc(a.clone(), b)("x".into()), "X!");
+
+        // b ∘ a ∘ a
+        let pair = |x: String, y: String| format!("({x}, {y})");
+        assert_eq!(w1_clone(a.clone(), pair)("x".into(), "y".into()), "(x!, y!)");
+        assert_eq!(w1_ref(&a, pair)("x".into(), "y".into()), "(x!, y!)");
+
+        // a ∘ b ∘ (a ∘ d ∘ c)
+        let join = move |x: String, y: String| x + "+" + &y;
+        let jay = |x: &str, y: &str, z: &str| format!("{x}!+{y}+{z}");
+        assert_eq!(j_clone(join.clone(), a.clone(), b, a.clone())("x".into(), "y".into(), "z".into()), "x!+y!+Z");
+        assert_eq!(j_rc(join.clone(), a.clone(), b, a.clone())("x".into(), "y".into(), "z".into()), jay("x", "y!", "Z"));
+        assert_eq!(j_ref(&join, a.clone(), b, a)("x".into(), "y".into(), "z".into()), jay("x", "y!", "Z"));
+
+        // a ∘ c ∘ (b ∘ c)
+        assert_eq!(s_clone(join.clone(), b, a.clone())("x".into(), "y".into()), "x!+Y!");
+        assert_eq!(s_rc(join.clone(), b, a.clone())("x".into(), "y".into()), "x!+Y!");
+        assert_eq!(s_ref(join, b, &a)("x".into(), "y".into()), "x!+Y!");
+
+        // b ∘ (a ∘ b)
+        assert_eq!(o_clone(b, a.clone())("x".into()), "X!!");
+        assert_eq!(o_arc(b, a.clone())("x".into()), "X!!");
+        assert_eq!(o_ref(b, &a)("x".into()), "X!!");
+    }
+}
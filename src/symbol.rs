@@ -0,0 +1,215 @@
+//! Symbolic placeholder functions
+//!
+//! A [Symbol](Symbol) is a unary function which doesn't compute anything, but writes down how it was applied.
+//! Passing symbols into a bird, and applying the result to another symbol, shows exactly what the bird composed.
+//!
+//! ```rust
+//! use birbs::symbol::sym;
+//!
+//! let f = birbs::b(sym("a"), sym("b"), sym("c"));
+//!
+//! assert_eq!(f(sym("x")).to_string(), "a(b(c(x)))");
+//! ```
+//!
+//! Unlike testing a bird on numbers, this can't be fooled by arguments which happen to commute.
+
+use std::sync::Arc;
+
+use crate::lambda::Lambda;
+use crate::named::{Pipeline, Structure};
+
+/// A placeholder for a unary function, or for a value
+///
+/// Applying a symbol to another gives a symbol for the application, such as `a(x)`.
+///
+/// Symbols aren't [Copy](Copy), so birds like S and W, which duplicate an argument, take them through their
+/// [shared](crate::shared) variants, such as [s_clone](crate::shared::s_clone). Cloning a symbol only clones a pointer to its text.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Symbol(Arc<str>);
+
+/// A symbol with the given text
+pub fn sym(text: &str) -> Symbol
+{
+    Symbol(text.into())
+}
+
+impl Symbol
+{
+    /// The text of the symbol, such as `a(b(x))`
+    pub fn as_str(&self) -> &str
+    {
+        &self.0
+    }
+
+    /// The symbol a closed λ-term stands for, when every variable is a unary function and the term is applied to `arg`
+    ///
+    /// Juxtaposition becomes composition, so λabc.a(bc) applied to x stands for `a(b(c(x)))`,
+    /// which is what the Bluebird composes. Each variable is written as its own name.
+    ///
+    /// Returns [None](None) if the body of the term contains an abstraction, as with the λ-definitions of Y or Θ.
+    ///
+    /// ```rust
+    /// use birbs::lambda::Lambda;
+    /// use birbs::symbol::{sym, Symbol};
+    ///
+    /// let starling = Lambda::parse("λabc.ac(bc)").unwrap();
+    ///
+    /// assert_eq!(Symbol::of_lambda(&starling, sym("x")), Some(sym("a(c(b(c(x))))")));
+    /// ```
+    pub fn of_lambda(lambda: &Lambda, arg: Symbol) -> Option<Symbol>
+    {
+        fn compose(term: &Lambda, arg: Symbol) -> Option<Symbol>
+        {
+            match term
+            {
+                Lambda::Var(name) => Some(sym(name)(arg)),
+                Lambda::Abs(..) => None,
+                Lambda::App(fun, last) => compose(fun, compose(last, arg)?)
+            }
+        }
+
+        let mut body = lambda;
+        while let Lambda::Abs(_, next) = body
+        {
+            body = next
+        }
+        compose(body, arg)
+    }
+}
+
+impl std::fmt::Debug for Symbol
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::fmt::Display for Symbol
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        f.write_str(&self.0)
+    }
+}
+
+impl Structure for Symbol
+{
+    fn pipeline(&self) -> Pipeline
+    {
+        Pipeline::Function(self.0.to_string())
+    }
+}
+
+impl FnOnce<(Symbol,)> for Symbol
+{
+    type Output = Symbol;
+
+    extern "rust-call" fn call_once(self, args: (Symbol,)) -> Self::Output
+    {
+        self.call(args)
+    }
+}
+
+impl FnMut<(Symbol,)> for Symbol
+{
+    extern "rust-call" fn call_mut(&mut self, args: (Symbol,)) -> Self::Output
+    {
+        self.call(args)
+    }
+}
+
+impl Fn<(Symbol,)> for Symbol
+{
+    extern "rust-call" fn call(&self, (arg,): (Symbol,)) -> Self::Output
+    {
+        sym(&format!("{self}({arg})"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::shared::*;
+    use crate::term::Bird;
+    use crate::*;
+
+    use super::*;
+
+    /// Applies each bird to symbols named after its arguments, and compares the result to its λ-definition
+    macro_rules! check {
+        ($($bird:ident($($arg:ident),*) = $variant:ident;)*) => {
+            $(
+                {
+                    let lambda = Lambda::parse(Bird::$variant.lambda()).unwrap();
+                    let expected = Symbol::of_lambda(&lambda, sym("x"));
+                    let actual = $bird($(sym(stringify!($arg))),*)(sym("x"));
+                    assert_eq!(Some(actual), expected, "{}", stringify!($bird))
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn test_sym()
+    {
+        let [a, b, x] = ["a", "b", "x"].map(sym);
+
+        assert_eq!(a(b(x.clone())), sym("a(b(x))"));
+        assert_eq!(a(b(x.clone())).as_str(), "a(b(x))");
+        assert_ne!(a(b(x.clone())), b(a(x)));
+    }
+
+    #[test]
+    fn test_birds()
+    {
+        check! {
+            b(a, b, c) = B;
+            b1(a, b, c, d) = B1;
+            b2(a, b, c, d, e) = B2;
+            b3(a, b, c, d) = B3;
+            c(a, b, c) = C;
+            d(a, b, c, d) = D;
+            d1(a, b, c, d, e) = D1;
+            d2(a, b, c, d, e) = D2;
+            e(a, b, c, d, e) = E;
+            ê(a, b, c, d, e, f, g) = Ê;
+            f(a, b, c) = F;
+            g(a, b, c, d) = G;
+            h_clone(a, b, c) = H;
+            i(a) = I;
+            j_clone(a, b, c, d) = J;
+            k(a, b) = K;
+            l_clone(a, b) = L;
+            m_clone(a) = M;
+            m2_clone(a, b) = M2;
+            o_clone(a, b) = O;
+            q(a, b, c) = Q;
+            q1(a, b, c) = Q1;
+            q2(a, b, c) = Q2;
+            q3(a, b, c) = Q3;
+            q4(a, b, c) = Q4;
+            r(a, b, c) = R;
+            s_clone(a, b, c) = S;
+            t(a, b) = T;
+            u_clone(a, b) = U;
+            v(a, b, c) = V;
+            w_clone(a, b) = W;
+            w1_clone(a, b) = W1;
+            i_star(a, b) = IStar;
+            w_star_clone(a, b, c) = WStar;
+            c_star(a, b, c, d) = CStar;
+            r_star(a, b, c, d) = RStar;
+            f_star(a, b, c, d) = FStar;
+            v_star(a, b, c, d) = VStar;
+            i_star_star(a, b, c) = IStarStar;
+            w_star_star_clone(a, b, c, d) = WStarStar;
+            c_star_star(a, b, c, d, e) = CStarStar;
+            r_star_star(a, b, c, d, e) = RStarStar;
+            f_star_star(a, b, c, d, e) = FStarStar;
+            v_star_star(a, b, c, d, e) = VStarStar;
+            ki(a, b) = KI;
+            km_clone(a, b) = KM;
+            ckm_clone(a, b) = CKM;
+        }
+    }
+}
@@ -0,0 +1,268 @@
+//! Combinator terms
+//!
+//! The birds in the crate root compose rust functions, so a combination of birds only exists as a closure.
+//! Here the same birds are primitives of an untyped term, which may be inspected and reduced symbolically.
+//!
+//! ```rust
+//! use birbs::term::{Bird, Term};
+//!
+//! // S(KS)K is the Bluebird
+//! let [s, k] = [Bird::S, Bird::K].map(Term::Bird);
+//! let bluebird = s.clone().app(k.clone().app(s)).app(k);
+//!
+//! let [x, y, z] = ["x", "y", "z"].map(Term::var);
+//!
+//! assert_eq!(
+//!     bluebird.apply([x.clone(), y.clone(), z.clone()]).normalize(),
+//!     x.app(y.app(z))
+//! );
+//! ```
+
+mod bird;
+mod graph;
+mod parse;
+mod reduce;
+mod trace;
+
+pub use bird::*;
+pub use graph::*;
+pub use parse::*;
+pub use reduce::*;
+pub use trace::*;
+
+/// An untyped combinator term
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Term
+{
+    /// A free variable
+    Var(String),
+    /// A bird, as a primitive combinator
+    Bird(Bird),
+    /// Application of a term to another
+    App(Box<Term>, Box<Term>)
+}
+
+impl Term
+{
+    /// A free variable
+    pub fn var(name: impl Into<String>) -> Term
+    {
+        Term::Var(name.into())
+    }
+
+    /// Applies the term to an argument
+    ///
+    /// returns (self arg)
+    pub fn app(self, arg: Term) -> Term
+    {
+        Term::App(Box::new(self), Box::new(arg))
+    }
+
+    /// Applies the term to several arguments, from left to right
+    ///
+    /// returns (self arg1 arg2 ...)
+    pub fn apply(self, args: impl IntoIterator<Item = Term>) -> Term
+    {
+        args.into_iter()
+            .fold(self, Term::app)
+    }
+
+    /// The number of atoms (birds and variables) in the term
+    pub fn size(&self) -> usize
+    {
+        match self
+        {
+            Term::Var(_) | Term::Bird(_) => 1,
+            Term::App(fun, arg) => fun.size() + arg.size()
+        }
+    }
+
+    /// Whether the variable occurs in the term
+    pub fn is_free(&self, var: &str) -> bool
+    {
+        match self
+        {
+            Term::Var(name) => name == var,
+            Term::Bird(_) => false,
+            Term::App(fun, arg) => fun.is_free(var) || arg.is_free(var)
+        }
+    }
+
+    /// Splits the term into the head of its spine and the arguments applied to it
+    ///
+    /// Sxyz has the head S and the arguments x, y and z.
+    pub fn spine(&self) -> (&Term, Vec<&Term>)
+    {
+        let mut head = self;
+        let mut args = vec![];
+        while let Term::App(fun, arg) = head
+        {
+            args.push(&**arg);
+            head = fun;
+        }
+        args.reverse();
+        (head, args)
+    }
+
+    /// Performs a single leftmost-outermost rewrite
+    ///
+    /// Returns [None](None) if the term is in normal form.
+    pub fn step(&self) -> Option<Term>
+    {
+        let (head, args) = self.spine();
+        if let Term::Bird(bird) = head
+        {
+            let arity = bird.arity();
+            if args.len() >= arity
+            {
+                let (now, later) = args.split_at(arity);
+                let result = bird.rewrite(now.iter().map(|&arg| arg.clone()).collect());
+                return Some(result.apply(later.iter().map(|&arg| arg.clone())))
+            }
+        }
+        for (i, arg) in args.iter().enumerate()
+        {
+            if let Some(arg) = arg.step()
+            {
+                return Some(
+                    head.clone()
+                        .apply(args[..i].iter().map(|&arg| arg.clone()))
+                        .app(arg)
+                        .apply(args[i + 1..].iter().map(|&arg| arg.clone()))
+                )
+            }
+        }
+        None
+    }
+
+    /// Rewrites the term until it is in normal form
+    ///
+    /// Just like their function counterparts, terms such as Ω or Yx never stop rewriting.
+    pub fn normalize(self) -> Term
+    {
+        let mut term = self;
+        while let Some(next) = term.step()
+        {
+            term = next
+        }
+        term
+    }
+}
+
+impl From<Bird> for Term
+{
+    fn from(bird: Bird) -> Self
+    {
+        Term::Bird(bird)
+    }
+}
+
+impl std::fmt::Display for Term
+{
+    /// Writes the term as compactly as possible (e.g. `S(KS)K`), unless that would be read back differently by [Term::parse](Term::parse).
+    ///
+    /// Otherwise every application is spaced out (e.g. `K I`, since `KI` is the Kite).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let mut compact = String::new();
+        self.render(&mut compact, false);
+        if Term::parse(&compact).as_ref() == Ok(self)
+        {
+            return f.write_str(&compact)
+        }
+        let mut spaced = String::new();
+        self.render(&mut spaced, true);
+        f.write_str(&spaced)
+    }
+}
+
+impl Term
+{
+    fn render(&self, out: &mut String, spaced: bool)
+    {
+        match self
+        {
+            Term::Var(name) => out.push_str(name),
+            Term::Bird(bird) => out.push_str(bird.symbol()),
+            Term::App(fun, arg) => {
+                fun.render(out, spaced);
+                if spaced
+                {
+                    out.push(' ')
+                }
+                if let Term::App(..) = **arg
+                {
+                    out.push('(');
+                    arg.render(out, spaced);
+                    out.push(')')
+                }
+                else
+                {
+                    arg.render(out, spaced)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars<const N: usize>() -> [Term; N]
+    {
+        std::array::from_fn(|i| Term::var(((b'a' + i as u8) as char).to_string()))
+    }
+
+    #[test]
+    fn test_rewrite()
+    {
+        let [a, b, c, d, e, f, g] = vars();
+
+        let rules = [
+            (Bird::B, a.clone().app(b.clone().app(c.clone()))),
+            (Bird::B3, a.clone().app(b.clone().app(c.clone().app(d.clone())))),
+            (Bird::C, a.clone().apply([c.clone(), b.clone()])),
+            (Bird::D2, a.clone().app(b.clone().app(c.clone())).app(d.clone().app(e.clone()))),
+            (Bird::Ê, a.clone().app(b.clone().apply([c.clone(), d.clone()])).app(e.clone().apply([f.clone(), g.clone()]))),
+            (Bird::J, a.clone().app(b.clone()).app(a.clone().apply([d.clone(), c.clone()]))),
+            (Bird::S, a.clone().app(c.clone()).app(b.clone().app(c.clone()))),
+            (Bird::U, b.clone().app(a.clone().apply([a.clone(), b.clone()]))),
+            (Bird::KI, b.clone()),
+            (Bird::CKM, a.clone().app(a.clone()))
+        ];
+
+        for (bird, result) in rules
+        {
+            let args = vars::<7>()[..bird.arity()].to_vec();
+            assert_eq!(bird.rewrite(args), result, "{bird}")
+        }
+    }
+
+    #[test]
+    fn test_normalize()
+    {
+        let [s, k, i] = [Bird::S, Bird::K, Bird::I].map(Term::Bird);
+        let [x, y] = vars();
+
+        // SKK = I
+        let skk = s.clone().apply([k.clone(), k.clone()]);
+        assert_eq!(skk.clone().app(x.clone()).normalize(), x);
+
+        // S(K(SI))K = T
+        let thrush = s.clone().apply([k.clone().app(s.clone().app(i)), k]);
+        assert_eq!(thrush.apply([x.clone(), y.clone()]).normalize(), y.clone().app(x.clone()));
+
+        // normalizes under free variables
+        assert_eq!(x.clone().app(skk.app(y.clone())).normalize(), x.app(y));
+    }
+
+    #[test]
+    fn test_display()
+    {
+        let [b, c, w] = [Bird::B, Bird::C, Bird::W].map(Term::Bird);
+        let term = b.clone().app(b.clone().app(w)).app(b.app(c));
+        assert_eq!(term.to_string(), "B(BW)(BC)");
+        assert_eq!(Term::Bird(Bird::B1).app(Term::var("x")).to_string(), "B¹x");
+    }
+}
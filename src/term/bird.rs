@@ -0,0 +1,290 @@
+use super::Term;
+
+/// Builds the right hand side of a rewrite rule from its juxtaposition notation.
+///
+/// Lowercase identifiers refer to the arguments of the rule, `[X]` refers to the bird `X`.
+/// Every node is made by the [Build](Build) `$b`, one at a time, from left to right.
+macro_rules! body {
+    (@app $b:ident $acc:expr;) => {
+        $acc
+    };
+    (@app $b:ident $acc:expr; ($($inner:tt)+) $($rest:tt)*) => {
+        body!(@app $b {
+            let fun = $acc;
+            let arg = body!($b; $($inner)+);
+            $b.app(fun, arg)
+        }; $($rest)*)
+    };
+    (@app $b:ident $acc:expr; [$bird:ident] $($rest:tt)*) => {
+        body!(@app $b {
+            let fun = $acc;
+            let arg = $b.bird(Bird::$bird);
+            $b.app(fun, arg)
+        }; $($rest)*)
+    };
+    (@app $b:ident $acc:expr; $x:ident $($rest:tt)*) => {
+        body!(@app $b {
+            let fun = $acc;
+            $b.app(fun, $x.clone())
+        }; $($rest)*)
+    };
+    ($b:ident; ($($inner:tt)+) $($rest:tt)*) => {
+        body!(@app $b body!($b; $($inner)+); $($rest)*)
+    };
+    ($b:ident; [$bird:ident] $($rest:tt)*) => {
+        body!(@app $b $b.bird(Bird::$bird); $($rest)*)
+    };
+    ($b:ident; $x:ident $($rest:tt)*) => {
+        body!(@app $b $x.clone(); $($rest)*)
+    };
+}
+
+/// Something the right hand side of a rewrite rule can be built in, such as a [Term](Term) or a graph of shared nodes.
+pub(crate) trait Build
+{
+    type Node: Clone;
+
+    /// Applies a node to another
+    fn app(&mut self, fun: Self::Node, arg: Self::Node) -> Self::Node;
+
+    /// A bird, as a node of its own
+    fn bird(&mut self, bird: Bird) -> Self::Node;
+}
+
+/// Builds plain terms
+struct Terms;
+
+impl Build for Terms
+{
+    type Node = Term;
+
+    fn app(&mut self, fun: Term, arg: Term) -> Term
+    {
+        fun.app(arg)
+    }
+
+    fn bird(&mut self, bird: Bird) -> Term
+    {
+        Term::Bird(bird)
+    }
+}
+
+macro_rules! count {
+    () => {
+        0
+    };
+    ($x:ident $($rest:ident)*) => {
+        1 + count!($($rest)*)
+    };
+}
+
+macro_rules! birds {
+    (
+        $(
+            $(#[$meta:meta])*
+            $bird:ident = $symbol:literal, $name:literal, $lambda:literal { $($x:ident)* => $($body:tt)+ }
+        )*
+    ) => {
+        /// Every bird of the aviary as a primitive combinator.
+        ///
+        /// Each bird carries the same λ-definition as its function counterpart in the crate root.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum Bird
+        {
+            $(
+                $(#[$meta])*
+                $bird,
+            )*
+        }
+
+        impl Bird
+        {
+            /// All the birds, in the order they are defined in the crate root.
+            pub const ALL: &'static [Bird] = &[$(Bird::$bird),*];
+
+            /// The symbol of the bird, as written in combinatory logic (e.g. `B¹`).
+            pub const fn symbol(self) -> &'static str
+            {
+                match self
+                {
+                    $(Bird::$bird => $symbol,)*
+                }
+            }
+
+            /// The common name of the bird (e.g. `Blackbird`).
+            pub const fn name(self) -> &'static str
+            {
+                match self
+                {
+                    $(Bird::$bird => $name,)*
+                }
+            }
+
+            /// The λ-definition of the bird as a closed λ-term (e.g. `λabcd.a(bcd)`).
+            pub const fn lambda(self) -> &'static str
+            {
+                match self
+                {
+                    $(Bird::$bird => $lambda,)*
+                }
+            }
+
+            /// The number of arguments the bird needs before it can be rewritten.
+            pub const fn arity(self) -> usize
+            {
+                match self
+                {
+                    $(Bird::$bird => count!($($x)*),)*
+                }
+            }
+
+            /// Rewrites the bird applied to exactly [arity](Bird::arity) arguments.
+            ///
+            /// ```rust
+            /// use birbs::term::{Bird, Term};
+            ///
+            /// let [x, y, z] = ["x", "y", "z"].map(Term::var);
+            ///
+            /// assert_eq!(Bird::B.rewrite(vec![x.clone(), y.clone(), z.clone()]), x.app(y.app(z)));
+            /// ```
+            pub fn rewrite(self, args: Vec<Term>) -> Term
+            {
+                self.build(args, &mut Terms)
+            }
+
+            /// Rewrites the bird applied to exactly [arity](Bird::arity) arguments, building the result with `b`.
+            #[allow(unused_variables, unused_mut, clippy::redundant_clone)]
+            pub(crate) fn build<B: Build>(self, args: Vec<B::Node>, b: &mut B) -> B::Node
+            {
+                assert_eq!(args.len(), self.arity(), "{} takes {} arguments", self, self.arity());
+                let mut args = args.into_iter();
+                match self
+                {
+                    $(
+                        Bird::$bird => {
+                            $(let $x = args.next().unwrap();)*
+                            body!(b; $($body)+)
+                        }
+                    )*
+                }
+            }
+        }
+    };
+}
+
+birds! {
+    /// B = Bluebird
+    B = "B", "Bluebird", "λabc.a(bc)" { a b c => a (b c) }
+    /// B¹ = Blackbird
+    B1 = "B¹", "Blackbird", "λabcd.a(bcd)" { a b c d => a (b c d) }
+    /// B² = Bunting
+    B2 = "B²", "Bunting", "λabcde.a(bcde)" { a b c d e => a (b c d e) }
+    /// B³ = Becard
+    B3 = "B³", "Becard", "λabcd.a(b(cd))" { a b c d => a (b (c d)) }
+    /// C = Cardinal
+    C = "C", "Cardinal", "λabc.acb" { a b c => a c b }
+    /// D = Dove
+    D = "D", "Dove", "λabcd.ab(cd)" { a b c d => a b (c d) }
+    /// D¹ = Dickcissel
+    D1 = "D¹", "Dickcissel", "λabcde.abc(de)" { a b c d e => a b c (d e) }
+    /// D² = Dovekies
+    D2 = "D²", "Dovekies", "λabcde.a(bc)(de)" { a b c d e => a (b c) (d e) }
+    /// E = Eagle
+    E = "E", "Eagle", "λabcde.ab(cde)" { a b c d e => a b (c d e) }
+    /// Ê = Bald Eagle
+    Ê = "Ê", "Bald Eagle", "λabcdefg.a(bcd)(efg)" { a b c d e f g => a (b c d) (e f g) }
+    /// F = Finch
+    F = "F", "Finch", "λabc.cba" { a b c => c b a }
+    /// G = Goldfinch
+    G = "G", "Goldfinch", "λabcd.ad(bc)" { a b c d => a d (b c) }
+    /// H = Hummingbird
+    H = "H", "Hummingbird", "λabc.abcb" { a b c => a b c b }
+    /// I = Identity Bird aka Idiot
+    I = "I", "Identity Bird", "λa.a" { a => a }
+    /// J = Jay
+    J = "J", "Jay", "λabcd.ab(adc)" { a b c d => a b (a d c) }
+    /// K = Kestrel (True)
+    K = "K", "Kestrel", "λab.a" { a b => a }
+    /// L = Lark
+    L = "L", "Lark", "λab.a(bb)" { a b => a (b b) }
+    /// M = Mockingbird
+    M = "M", "Mockingbird", "λa.aa" { a => a a }
+    /// M² = Double Mockingbird
+    M2 = "M²", "Double Mockingbird", "λab.ab(ab)" { a b => a b (a b) }
+    /// O = Owl
+    O = "O", "Owl", "λab.b(ab)" { a b => b (a b) }
+    /// Q = Queer Bird
+    Q = "Q", "Queer Bird", "λabc.b(ac)" { a b c => b (a c) }
+    /// Q¹ = Quixotic Bird
+    Q1 = "Q¹", "Quixotic Bird", "λabc.a(cb)" { a b c => a (c b) }
+    /// Q² = Quizzical Bird
+    Q2 = "Q²", "Quizzical Bird", "λabc.b(ca)" { a b c => b (c a) }
+    /// Q³ = Quirky Bird
+    Q3 = "Q³", "Quirky Bird", "λabc.c(ab)" { a b c => c (a b) }
+    /// Q⁴ = Quacky Bird
+    Q4 = "Q⁴", "Quacky Bird", "λabc.c(ba)" { a b c => c (b a) }
+    /// R = Robin
+    R = "R", "Robin", "λabc.bca" { a b c => b c a }
+    /// S = Starling
+    S = "S", "Starling", "λabc.ac(bc)" { a b c => a c (b c) }
+    /// T = Thrush
+    T = "T", "Thrush", "λab.ba" { a b => b a }
+    /// U = Turing
+    U = "U", "Turing", "λab.b(aab)" { a b => b (a a b) }
+    /// V = Vireo aka Pairing
+    V = "V", "Vireo", "λabc.cab" { a b c => c a b }
+    /// W = Warbler
+    W = "W", "Warbler", "λab.abb" { a b => a b b }
+    /// W¹ = Converse Warbler
+    W1 = "W¹", "Converse Warbler", "λab.baa" { a b => b a a }
+    /// Y = Why Bird
+    ///
+    /// Ya rewrites to a(Ya), so it never reaches a normal form on its own.
+    Y = "Y", "Why Bird", "λa.(λb.a(bb))(λb.a(bb))" { a => a ([Y] a) }
+    /// I* = Identity Bird Once Removed
+    IStar = "I*", "Identity Bird Once Removed", "λab.ab" { a b => a b }
+    /// W* = Warbler Once Removed
+    WStar = "W*", "Warbler Once Removed", "λabc.abcc" { a b c => a b c c }
+    /// C* = Cardinal Once Removed
+    CStar = "C*", "Cardinal Once Removed", "λabcd.abdc" { a b c d => a b d c }
+    /// R* = Robin Once Removed
+    RStar = "R*", "Robin Once Removed", "λabcd.acdb" { a b c d => a c d b }
+    /// F* = Finch Once Removed
+    FStar = "F*", "Finch Once Removed", "λabcd.adcb" { a b c d => a d c b }
+    /// V* = Vireo Once Removed
+    VStar = "V*", "Vireo Once Removed", "λabcd.acbd" { a b c d => a c b d }
+    /// I** = Identity Bird Twice Removed
+    IStarStar = "I**", "Identity Bird Twice Removed", "λabc.abc" { a b c => a b c }
+    /// W** = Warbler Twice Removed
+    WStarStar = "W**", "Warbler Twice Removed", "λabcd.abcdd" { a b c d => a b c d d }
+    /// C** = Cardinal Twice Removed
+    CStarStar = "C**", "Cardinal Twice Removed", "λabcde.abced" { a b c d e => a b c e d }
+    /// R** = Robin Twice Removed
+    RStarStar = "R**", "Robin Twice Removed", "λabcde.abdec" { a b c d e => a b d e c }
+    /// F** = Finch Twice Removed
+    FStarStar = "F**", "Finch Twice Removed", "λabcde.abedc" { a b c d e => a b e d c }
+    /// V** = Vireo Twice Removed
+    VStarStar = "V**", "Vireo Twice Removed", "λabcde.abecd" { a b c d e => a b e c d }
+    /// KI = Kite (False)
+    KI = "KI", "Kite", "λab.b" { a b => b }
+    /// Ω = Omega
+    ///
+    /// Ω rewrites to MM, which rewrites to itself.
+    Ω = "Ω", "Omega", "(λa.aa)(λa.aa)" { => [M] [M] }
+    /// KM = Constant Mocker
+    KM = "KM", "Constant Mocker", "λab.bb" { a b => b b }
+    /// C(KM) = Crossed Constant Mocker
+    CKM = "C(KM)", "Crossed Constant Mocker", "λab.aa" { a b => a a }
+    /// Θ = Theta
+    ///
+    /// Θa rewrites to a(Θa), so it never reaches a normal form on its own.
+    Θ = "Θ", "Theta", "(λab.b(aab))(λab.b(aab))" { a => a ([Θ] a) }
+}
+
+impl std::fmt::Display for Bird
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        f.write_str(self.symbol())
+    }
+}
@@ -0,0 +1,291 @@
+use std::collections::HashSet;
+
+use super::bird::Build;
+use super::{Bird, Term};
+
+/// A node of a [Graph](Graph), referred to by its index.
+#[derive(Clone, Debug)]
+enum Node
+{
+    Var(String),
+    Bird(Bird),
+    App(usize, usize),
+    /// A redex which has been rewritten to another, already existing node
+    Ind(usize)
+}
+
+/// A combinator term as a graph, reduced by in-place updates.
+///
+/// Unlike [Term::normalize](Term::normalize), which copies an argument every time a bird such as S, W or M duplicates it,
+/// the graph only duplicates references to it. Once a shared argument is rewritten, every reference sees the result,
+/// so no rewrite is ever done twice.
+///
+/// Reduction is lazy: an argument is only rewritten once the head of the term is stuck, or when asked for the normal form.
+///
+/// ```rust
+/// use birbs::term::{Graph, Term};
+///
+/// let term = Term::parse("S(KS)K x y z").unwrap();
+///
+/// let mut graph = Graph::new(&term);
+/// graph.normalize();
+///
+/// assert_eq!(graph.to_term(), Term::parse("x(yz)").unwrap());
+/// assert_eq!(graph.steps(), 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Graph
+{
+    nodes: Vec<Node>,
+    root: usize,
+    initial: usize,
+    steps: usize
+}
+
+impl Graph
+{
+    /// Builds the graph of a term, without any sharing yet.
+    pub fn new(term: &Term) -> Graph
+    {
+        let mut graph = Graph {
+            nodes: vec![],
+            root: 0,
+            initial: 0,
+            steps: 0
+        };
+        graph.root = graph.insert(term);
+        graph.initial = graph.nodes.len();
+        graph
+    }
+
+    /// The number of rewrites done so far
+    pub fn steps(&self) -> usize
+    {
+        self.steps
+    }
+
+    /// The number of nodes allocated by the rewrites done so far, not counting the nodes of the initial term
+    pub fn allocations(&self) -> usize
+    {
+        self.nodes.len() - self.initial
+    }
+
+    /// Rewrites the term until its head can't be rewritten any further (weak head normal form).
+    ///
+    /// The arguments are left as they are.
+    pub fn whnf(&mut self)
+    {
+        self.reduce(self.root)
+    }
+
+    /// Rewrites the term until it is in normal form
+    ///
+    /// Terms without a normal form, such as Ω or Yx, never stop rewriting.
+    pub fn normalize(&mut self)
+    {
+        let mut normal = HashSet::new();
+        let mut pending = vec![self.root];
+        while let Some(node) = pending.pop()
+        {
+            let node = self.follow(node);
+            if !normal.insert(node)
+            {
+                continue
+            }
+            self.reduce(node);
+            // the redex may have been rewritten to an indirection to one of its arguments
+            let node = self.follow(node);
+            normal.insert(node);
+            let mut head = node;
+            while let Node::App(fun, arg) = self.nodes[head]
+            {
+                pending.push(arg);
+                head = self.follow(fun)
+            }
+        }
+    }
+
+    /// Reads the term back, copying every shared node wherever it is referred to
+    pub fn to_term(&self) -> Term
+    {
+        self.read(self.root)
+    }
+
+    fn insert(&mut self, term: &Term) -> usize
+    {
+        let node = match term
+        {
+            Term::Var(name) => Node::Var(name.clone()),
+            Term::Bird(bird) => Node::Bird(*bird),
+            Term::App(fun, arg) => {
+                let fun = self.insert(fun);
+                let arg = self.insert(arg);
+                Node::App(fun, arg)
+            }
+        };
+        self.alloc(node)
+    }
+
+    fn alloc(&mut self, node: Node) -> usize
+    {
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+
+    /// Skips past indirections
+    fn follow(&self, mut node: usize) -> usize
+    {
+        while let Node::Ind(next) = self.nodes[node]
+        {
+            node = next
+        }
+        node
+    }
+
+    /// Unwinds the spine of the node, rewriting its head until it is stuck
+    fn reduce(&mut self, node: usize)
+    {
+        loop
+        {
+            let node = self.follow(node);
+            let mut spine = vec![];
+            let mut head = node;
+            while let Node::App(fun, _) = self.nodes[head]
+            {
+                spine.push(head);
+                head = self.follow(fun)
+            }
+            let bird = match self.nodes[head]
+            {
+                Node::Bird(bird) if spine.len() >= bird.arity() => bird,
+                _ => return
+            };
+
+            // The innermost applications hold the first arguments
+            let arity = bird.arity();
+            let redex = match arity
+            {
+                0 => head,
+                _ => spine[spine.len() - arity]
+            };
+            let args = spine[spine.len() - arity..].iter()
+                .rev()
+                .map(|&app| match self.nodes[app]
+                {
+                    Node::App(_, arg) => arg,
+                    _ => unreachable!()
+                })
+                .collect();
+
+            let fresh = self.nodes.len();
+            let result = bird.build(args, self);
+            self.nodes[redex] = if result >= fresh
+            {
+                // The result was made by the rewrite, so it can simply take the place of the redex
+                self.nodes[result].clone()
+            }
+            else
+            {
+                // The result is an argument, which may be shared, so it must not be copied
+                Node::Ind(result)
+            };
+            self.steps += 1
+        }
+    }
+
+    fn read(&self, node: usize) -> Term
+    {
+        match &self.nodes[self.follow(node)]
+        {
+            Node::Var(name) => Term::var(name.clone()),
+            Node::Bird(bird) => Term::Bird(*bird),
+            Node::App(fun, arg) => self.read(*fun).app(self.read(*arg)),
+            Node::Ind(_) => unreachable!()
+        }
+    }
+}
+
+impl Build for Graph
+{
+    type Node = usize;
+
+    fn app(&mut self, fun: usize, arg: usize) -> usize
+    {
+        self.alloc(Node::App(fun, arg))
+    }
+
+    fn bird(&mut self, bird: Bird) -> usize
+    {
+        self.alloc(Node::Bird(bird))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalize(term: &Term) -> Graph
+    {
+        let mut graph = Graph::new(term);
+        graph.normalize();
+        graph
+    }
+
+    #[test]
+    fn test_normalize()
+    {
+        for source in ["S K K x", "S(K(SI))K x y", "x(SKK y)", "B(BW)(BC) x y z", "J x y z w", "H x y z", "L x y", "U x y", "Y(K x)", "KI Ω x"]
+        {
+            let term = Term::parse(source).unwrap();
+            assert_eq!(normalize(&term).to_term(), term.clone().normalize(), "{source}")
+        }
+    }
+
+    #[test]
+    fn test_normalize_indirection()
+    {
+        // I rewrites to an indirection to its argument, whose own arguments must still be normalized
+        for source in ["I (x (I y))", "K (x (I y)) z", "KI z (x (I y) (K I z))"]
+        {
+            let term = Term::parse(source).unwrap();
+            assert_eq!(normalize(&term).to_term(), term.clone().normalize(), "{source}")
+        }
+    }
+
+    #[test]
+    fn test_whnf()
+    {
+        // only the head is rewritten
+        let mut graph = Graph::new(&Term::parse("K x (I y) (I z)").unwrap());
+        graph.whnf();
+        assert_eq!(graph.to_term(), Term::parse("x (I z)").unwrap());
+
+        // the diverging argument is never looked at
+        let mut graph = Graph::new(&Term::parse("KI Ω x").unwrap());
+        graph.whnf();
+        assert_eq!(graph.to_term(), Term::var("x"));
+        assert_eq!(graph.steps(), 1);
+    }
+
+    #[test]
+    fn test_sharing()
+    {
+        // xₙ₊₁ = W f xₙ duplicates xₙ, so the tree doubles at every level
+        let n = 10;
+        let term = (0..n).fold(Term::var("x"), |term, _| Term::Bird(Bird::W).apply([Term::var("f"), Term::Bird(Bird::I).app(term)]));
+
+        let graph = normalize(&term);
+        assert_eq!(graph.steps(), 2*n);
+        assert!(graph.allocations() <= 2*n);
+
+        let mut tree = term;
+        let mut steps = 0;
+        while let Some(next) = tree.step()
+        {
+            tree = next;
+            steps += 1
+        }
+        assert!(steps >= 1 << n);
+        assert_eq!(graph.to_term(), tree)
+    }
+}
@@ -0,0 +1,294 @@
+use std::str::FromStr;
+
+use super::{Bird, Term};
+
+/// Alternative names of birds, besides the ones given by [Bird::name](Bird::name).
+const ALIASES: &[(&str, Bird)] = &[
+    ("Idiot", Bird::I),
+    ("Pairing", Bird::V),
+    ("Warbled Once Removed", Bird::WStar)
+];
+
+/// An error encountered while parsing a [Term](Term).
+///
+/// Positions are byte offsets into the parsed string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError
+{
+    /// There was no term where one was expected
+    Empty(usize),
+    /// A character which does not begin a bird, a variable or a group
+    UnexpectedChar(usize, char),
+    /// A parenthesis which was opened but never closed
+    UnclosedParen(usize),
+    /// A parenthesis which was closed but never opened
+    UnopenedParen(usize)
+}
+
+impl std::fmt::Display for ParseError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            ParseError::Empty(i) => write!(f, "expected a term at {i}"),
+            ParseError::UnexpectedChar(i, c) => write!(f, "unexpected character {c:?} at {i}"),
+            ParseError::UnclosedParen(i) => write!(f, "unclosed parenthesis at {i}"),
+            ParseError::UnopenedParen(i) => write!(f, "unopened parenthesis at {i}")
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl Bird
+{
+    /// Finds the bird with the given symbol (e.g. `B¹`, `C*` or `C(KM)`).
+    pub fn from_symbol(symbol: &str) -> Option<Bird>
+    {
+        Bird::ALL.iter()
+            .copied()
+            .find(|bird| bird.symbol() == symbol)
+    }
+
+    /// Finds the bird with the given name (e.g. `Blackbird` or `Cardinal once removed`).
+    ///
+    /// Names are case-insensitive, and their words may be separated by any whitespace.
+    pub fn from_name(name: &str) -> Option<Bird>
+    {
+        match_name(name.trim())
+            .filter(|&(_, len)| len == name.trim().len())
+            .map(|(bird, _)| bird)
+    }
+}
+
+impl Term
+{
+    /// Parses a term written in the notation of combinatory logic
+    ///
+    /// * Application is juxtaposition, and associates to the left. `Sxyz` means `((Sx)y)z`.
+    /// * Birds are written as their symbols (`B`, `B¹`, `Ê`, `W**`, `C(KM)`, `Θ`) or as their names (`Bluebird`, `Cardinal once removed`).
+    /// * Variables are a lowercase letter, optionally followed by digits and primes (`x`, `x1`, `y'`).
+    ///
+    /// The longest symbol is always chosen, so `KI` is the Kite, while `K I` is the Kestrel applied to the Identity Bird.
+    /// A name is tried before the symbols, when it starts with a capital letter, so `OWL` is the Owl, while `O W L` is three birds.
+    ///
+    /// ```rust
+    /// use birbs::term::{Bird, Term};
+    ///
+    /// let [b, c, w] = [Bird::B, Bird::C, Bird::W].map(Term::Bird);
+    ///
+    /// assert_eq!(
+    ///     Term::parse("B(BW)(BC)"),
+    ///     Ok(b.clone().app(b.clone().app(w)).app(b.app(c)))
+    /// );
+    /// assert_eq!(Term::parse("Bluebird"), Ok(Term::Bird(Bird::B)));
+    /// ```
+    pub fn parse(source: &str) -> Result<Term, ParseError>
+    {
+        let mut parser = Parser {
+            source,
+            position: 0
+        };
+        let term = parser.sequence()?
+            .ok_or(ParseError::Empty(parser.position))?;
+        match parser.peek()
+        {
+            Some(_) => Err(ParseError::UnopenedParen(parser.position)),
+            None => Ok(term)
+        }
+    }
+}
+
+impl FromStr for Term
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        Term::parse(s)
+    }
+}
+
+/// Matches the longest bird name at the start of the string, returning the bird and the length of the match.
+fn match_name(source: &str) -> Option<(Bird, usize)>
+{
+    Bird::ALL.iter()
+        .map(|&bird| (bird.name(), bird))
+        .chain(ALIASES.iter().copied())
+        .filter_map(|(name, bird)| {
+            let mut rest = source;
+            for word in name.split(' ')
+            {
+                rest = rest.trim_start();
+                let len = rest.char_indices()
+                    .find(|&(_, c)| !c.is_alphanumeric())
+                    .map_or(rest.len(), |(i, _)| i);
+                if !rest[..len].eq_ignore_ascii_case(word)
+                {
+                    return None
+                }
+                rest = &rest[len..];
+            }
+            Some((bird, source.len() - rest.len()))
+        })
+        .max_by_key(|&(_, len)| len)
+}
+
+/// Matches the longest bird symbol at the start of the string, returning the bird and the length of the match.
+fn match_symbol(source: &str) -> Option<(Bird, usize)>
+{
+    Bird::ALL.iter()
+        .filter(|bird| source.starts_with(bird.symbol()))
+        .map(|&bird| (bird, bird.symbol().len()))
+        .max_by_key(|&(_, len)| len)
+}
+
+struct Parser<'a>
+{
+    source: &'a str,
+    position: usize
+}
+
+impl<'a> Parser<'a>
+{
+    fn rest(&self) -> &'a str
+    {
+        &self.source[self.position..]
+    }
+
+    /// Skips whitespace, and returns the next character
+    fn peek(&mut self) -> Option<char>
+    {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+        self.rest().chars().next()
+    }
+
+    /// Parses atoms until the end of the source or a closing parenthesis
+    fn sequence(&mut self) -> Result<Option<Term>, ParseError>
+    {
+        let mut term: Option<Term> = None;
+        while let Some(atom) = self.atom()?
+        {
+            term = Some(match term
+            {
+                Some(term) => term.app(atom),
+                None => atom
+            })
+        }
+        Ok(term)
+    }
+
+    fn atom(&mut self) -> Result<Option<Term>, ParseError>
+    {
+        let start = match self.peek()
+        {
+            None | Some(')') => return Ok(None),
+            Some('(') => {
+                let open = self.position;
+                self.position += 1;
+                let term = self.sequence()?
+                    .ok_or(ParseError::Empty(self.position))?;
+                if self.peek() != Some(')')
+                {
+                    return Err(ParseError::UnclosedParen(open))
+                }
+                self.position += 1;
+                return Ok(Some(term))
+            },
+            Some(c) => c
+        };
+        let bird = match_name(self.rest())
+            .filter(|_| start.is_uppercase())
+            .or_else(|| match_symbol(self.rest()));
+        if let Some((bird, len)) = bird
+        {
+            self.position += len;
+            return Ok(Some(Term::Bird(bird)))
+        }
+        if start.is_ascii_lowercase()
+        {
+            let len = self.rest()[1..].find(|c: char| !c.is_ascii_digit() && c != '\'')
+                .map_or(self.rest().len(), |i| i + 1);
+            let name = &self.rest()[..len];
+            self.position += len;
+            return Ok(Some(Term::var(name)))
+        }
+        Err(ParseError::UnexpectedChar(self.position, start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse()
+    {
+        let [b, c, w, s, k, i] = [Bird::B, Bird::C, Bird::W, Bird::S, Bird::K, Bird::I].map(Term::Bird);
+        let [x, y, z] = ["x", "y", "z"].map(Term::var);
+
+        assert_eq!(Term::parse("B(BW)(BC)"), Ok(b.clone().app(b.clone().app(w.clone())).app(b.clone().app(c.clone()))));
+        assert_eq!(Term::parse("S K K"), Ok(s.clone().apply([k.clone(), k.clone()])));
+        assert_eq!(Term::parse("Bxyz"), Ok(b.clone().apply([x.clone(), y.clone(), z.clone()])));
+        assert_eq!(Term::parse("ÊΘΩ"), Ok(Term::Bird(Bird::Ê).apply([Term::Bird(Bird::Θ), Term::Bird(Bird::Ω)])));
+        assert_eq!(Term::parse("B¹ C* W**"), Ok(Term::Bird(Bird::B1).apply([Term::Bird(Bird::CStar), Term::Bird(Bird::WStarStar)])));
+        assert_eq!(Term::parse("C(KM)x"), Ok(Term::Bird(Bird::CKM).app(x.clone())));
+        assert_eq!(Term::parse("C(K M)x"), Ok(c.clone().app(k.clone().app(Term::Bird(Bird::M))).app(x.clone())));
+        assert_eq!(Term::parse("KI"), Ok(Term::Bird(Bird::KI)));
+        assert_eq!(Term::parse("K I"), Ok(k.clone().app(i.clone())));
+        assert_eq!(Term::parse("(((x)))"), Ok(x.clone()));
+        assert_eq!(Term::parse("x1 y'"), Ok(Term::var("x1").app(Term::var("y'"))));
+        assert_eq!(Term::parse("owl"), Ok(Term::var("o").apply([Term::var("w"), Term::var("l")])));
+    }
+
+    #[test]
+    fn test_parse_names()
+    {
+        assert_eq!(Term::parse("Bluebird"), Ok(Term::Bird(Bird::B)));
+        assert_eq!(Term::parse("Cardinal Once Removed"), Ok(Term::Bird(Bird::CStar)));
+        assert_eq!(Term::parse("Cardinal once removed"), Ok(Term::Bird(Bird::CStar)));
+        assert_eq!(Term::parse("Cardinal  Once\nRemoved x"), Ok(Term::Bird(Bird::CStar).app(Term::var("x"))));
+        assert_eq!(Term::parse("Cardinal Cardinal"), Ok(Term::Bird(Bird::C).app(Term::Bird(Bird::C))));
+        assert_eq!(Term::parse("Identity Bird Twice Removed"), Ok(Term::Bird(Bird::IStarStar)));
+        assert_eq!(Term::parse("Starling(Kestrel Idiot)"), Ok(Term::Bird(Bird::S).app(Term::Bird(Bird::K).app(Term::Bird(Bird::I)))));
+        assert_eq!(Bird::from_name("Bald Eagle"), Some(Bird::Ê));
+        assert_eq!(Bird::from_name("bald eagle"), Some(Bird::Ê));
+        assert_eq!(Bird::from_name("Bald"), None);
+        // a name is tried first, but only from a capital letter
+        assert_eq!(Term::parse("OWL"), Ok(Term::Bird(Bird::O)));
+        assert_eq!(Term::parse("O W L"), Ok(Term::Bird(Bird::O).apply([Term::Bird(Bird::W), Term::Bird(Bird::L)])));
+        assert_eq!(Term::parse("oWL"), Ok(Term::var("o").apply([Term::Bird(Bird::W), Term::Bird(Bird::L)])));
+
+        for &bird in Bird::ALL
+        {
+            assert_eq!(Bird::from_name(bird.name()), Some(bird));
+            assert_eq!(Bird::from_symbol(bird.symbol()), Some(bird));
+            assert_eq!(Term::parse(bird.name()), Ok(Term::Bird(bird)));
+            assert_eq!(Term::parse(bird.symbol()), Ok(Term::Bird(bird)));
+        }
+    }
+
+    #[test]
+    fn test_parse_errors()
+    {
+        assert_eq!(Term::parse(""), Err(ParseError::Empty(0)));
+        assert_eq!(Term::parse("S()"), Err(ParseError::Empty(2)));
+        assert_eq!(Term::parse("S(K"), Err(ParseError::UnclosedParen(1)));
+        assert_eq!(Term::parse("SK)"), Err(ParseError::UnopenedParen(2)));
+        assert_eq!(Term::parse("S X"), Err(ParseError::UnexpectedChar(2, 'X')));
+    }
+
+    #[test]
+    fn test_round_trip()
+    {
+        for source in ["B(BW)(BC)", "S K K", "ÊΘΩ", "B¹ C* W**", "K I", "C(K M)", "C(KM)", "Owl", "O w l", "S(KS)K x1 y'"]
+        {
+            let term = Term::parse(source).unwrap();
+            assert_eq!(Term::parse(&term.to_string()), Ok(term))
+        }
+        assert_eq!(Term::parse("S K K").unwrap().to_string(), "SKK");
+        assert_eq!(Term::parse("K I").unwrap().to_string(), "K I");
+    }
+}
@@ -0,0 +1,245 @@
+use std::collections::HashSet;
+
+use super::{Bird, Position, Side, Term};
+
+/// The order in which the redexes of a term are rewritten
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Strategy
+{
+    /// The leftmost-outermost redex first, as [Term::step](Term::step) does.
+    ///
+    /// Reaches the normal form whenever there is one.
+    NormalOrder,
+    /// The arguments of a redex are reduced before it is rewritten, just like a bird of the crate root only gets called with evaluated arguments.
+    ///
+    /// A partially applied bird is a value, like a closure, so nothing inside it is reduced.
+    ApplicativeOrder,
+    /// The head redex, until the head is a variable or a partially applied bird (head normal form).
+    ///
+    /// The arguments are never reduced, not even those of a partially applied bird.
+    Head,
+    /// The head redex, until the head is a variable or a partially applied bird (weak head normal form).
+    ///
+    /// A combinator term has no abstraction to reduce under, so this stops where [Head](Strategy::Head) does.
+    WeakHead,
+    /// The leftmost of the redexes which contain no other redex.
+    ///
+    /// Reaches the normal form only if every argument has one, even those a bird would throw away.
+    LeftmostInnermost
+}
+
+/// How far a reduction may go before it is given up on
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Fuel
+{
+    /// The maximum number of rewrites
+    pub steps: usize,
+    /// The maximum [size](Term::size) of a term along the way
+    pub size: usize
+}
+
+impl Default for Fuel
+{
+    fn default() -> Self
+    {
+        Fuel {
+            steps: 10_000,
+            size: 1_000
+        }
+    }
+}
+
+/// Why a reduction was given up on, with the term it was given up at
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReductionError
+{
+    /// The term was still not reduced after the maximum number of steps
+    OutOfFuel(Term),
+    /// The term grew larger than the maximum size, as Yx does
+    TermTooLarge(Term),
+    /// The term rewrote to itself, as MM does, so it will never stop rewriting
+    Cycle(Term)
+}
+
+impl std::fmt::Display for ReductionError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            ReductionError::OutOfFuel(term) => write!(f, "ran out of fuel at {term}"),
+            ReductionError::TermTooLarge(term) => write!(f, "term grew too large at {term}"),
+            ReductionError::Cycle(term) => write!(f, "reduction cycled back to {term}")
+        }
+    }
+}
+
+impl std::error::Error for ReductionError {}
+
+impl Term
+{
+    /// Finds the redex the strategy would rewrite next
+    ///
+    /// Returns [None](None) if the strategy is done with the term.
+    pub fn redex(&self, strategy: Strategy) -> Option<Position>
+    {
+        let (head, args) = self.spine();
+        let saturated = match head
+        {
+            Term::Bird(bird) if args.len() >= bird.arity() => Some(*bird),
+            _ => None
+        };
+        // The redex of a saturated head spans the head and exactly as many arguments as it needs
+        let fire = |bird: Bird| Position(vec![Side::Fun; args.len() - bird.arity()]);
+        let in_args = |range: std::ops::Range<usize>| range.into_iter()
+            .find_map(|i| args[i].redex(strategy).map(|inner| {
+                let mut position = vec![Side::Fun; args.len() - 1 - i];
+                position.push(Side::Arg);
+                position.extend(inner.0);
+                Position(position)
+            }));
+        match (strategy, saturated)
+        {
+            (Strategy::NormalOrder | Strategy::Head | Strategy::WeakHead, Some(bird)) => Some(fire(bird)),
+            (Strategy::NormalOrder, None) => in_args(0..args.len()),
+            (Strategy::Head | Strategy::WeakHead, None) => None,
+            (Strategy::ApplicativeOrder | Strategy::LeftmostInnermost, Some(bird)) => {
+                in_args(0..bird.arity())
+                    .or_else(|| Some(fire(bird)))
+            },
+            (Strategy::ApplicativeOrder, None) => match head
+            {
+                Term::Bird(_) => None,
+                _ => in_args(0..args.len())
+            },
+            (Strategy::LeftmostInnermost, None) => in_args(0..args.len())
+        }
+    }
+
+    /// Performs a single rewrite chosen by the strategy
+    ///
+    /// Returns [None](None) if the strategy is done with the term.
+    pub fn step_with(&self, strategy: Strategy) -> Option<Term>
+    {
+        self.redex(strategy)
+            .and_then(|position| self.rewrite_at(&position))
+            .map(|(_, term)| term)
+    }
+
+    /// Rewrites the term with the strategy until it is done, or the fuel runs out
+    ///
+    /// Every term seen along the way is remembered, so a reduction which comes back to an earlier term fails with [Cycle](ReductionError::Cycle).
+    ///
+    /// ```rust
+    /// use birbs::term::{Fuel, ReductionError, Strategy, Term};
+    ///
+    /// let term = Term::parse("K x Ω").unwrap();
+    ///
+    /// assert_eq!(term.clone().reduce(Strategy::NormalOrder, Fuel::default()), Ok(Term::var("x")));
+    /// assert_eq!(
+    ///     term.reduce(Strategy::ApplicativeOrder, Fuel::default()),
+    ///     Err(ReductionError::Cycle(Term::parse("K x (M M)").unwrap()))
+    /// );
+    /// ```
+    pub fn reduce(self, strategy: Strategy, fuel: Fuel) -> Result<Term, ReductionError>
+    {
+        let mut seen = HashSet::new();
+        let mut term = self;
+        let mut steps = 0;
+        loop
+        {
+            if term.size() > fuel.size
+            {
+                return Err(ReductionError::TermTooLarge(term))
+            }
+            if seen.contains(&term)
+            {
+                return Err(ReductionError::Cycle(term))
+            }
+            let next = match term.step_with(strategy)
+            {
+                Some(next) => next,
+                None => return Ok(term)
+            };
+            if steps == fuel.steps
+            {
+                return Err(ReductionError::OutOfFuel(term))
+            }
+            steps += 1;
+            seen.insert(std::mem::replace(&mut term, next));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reduce(source: &str, strategy: Strategy) -> Result<Term, ReductionError>
+    {
+        Term::parse(source).unwrap().reduce(strategy, Fuel::default())
+    }
+
+    #[test]
+    fn test_strategies()
+    {
+        let parse = |source| Term::parse(source).unwrap();
+
+        let cases = [
+            ("K (I x)", ["K x", "K (I x)", "K (I x)", "K (I x)", "K x"]),
+            ("x (I y)", ["x y", "x y", "x (I y)", "x (I y)", "x y"]),
+            ("I (K (I x))", ["K x", "K (I x)", "K (I x)", "K (I x)", "K x"]),
+            ("S K K (I x)", ["x", "x", "x", "x", "x"])
+        ];
+        let strategies = [Strategy::NormalOrder, Strategy::ApplicativeOrder, Strategy::Head, Strategy::WeakHead, Strategy::LeftmostInnermost];
+
+        for (source, results) in cases
+        {
+            for (strategy, result) in strategies.into_iter().zip(results)
+            {
+                assert_eq!(reduce(source, strategy), Ok(parse(result)), "{source} {strategy:?}")
+            }
+        }
+    }
+
+    #[test]
+    fn test_order()
+    {
+        // the innermost redex goes first
+        let term = Term::parse("K (I x) (I y)").unwrap();
+        assert_eq!(term.step_with(Strategy::NormalOrder), Some(Term::parse("I x").unwrap()));
+        assert_eq!(term.step_with(Strategy::LeftmostInnermost), Some(Term::parse("K x (I y)").unwrap()));
+        assert_eq!(term.step_with(Strategy::ApplicativeOrder), Some(Term::parse("K x (I y)").unwrap()))
+    }
+
+    #[test]
+    fn test_head()
+    {
+        // K with one argument is in head normal form, so its argument is left alone
+        let term = Term::parse("K (I x)").unwrap();
+        assert_eq!(term.redex(Strategy::Head), None);
+        assert_eq!(term.step_with(Strategy::Head), None);
+        assert_eq!(reduce("K (I x) y", Strategy::Head), Ok(Term::var("x")));
+        assert_eq!(reduce("x (I y)", Strategy::Head), Ok(Term::parse("x (I y)").unwrap()));
+    }
+
+    #[test]
+    fn test_errors()
+    {
+        let parse = |source| Term::parse(source).unwrap();
+
+        assert_eq!(reduce("M M", Strategy::NormalOrder), Err(ReductionError::Cycle(parse("M M"))));
+        assert_eq!(reduce("Ω", Strategy::WeakHead), Err(ReductionError::Cycle(parse("M M"))));
+        assert_eq!(reduce("K x Ω", Strategy::LeftmostInnermost), Err(ReductionError::Cycle(parse("K x (M M)"))));
+        assert_eq!(reduce("K x Ω", Strategy::WeakHead), Ok(parse("x")));
+
+        // Yx only grows
+        assert!(matches!(reduce("Y x", Strategy::NormalOrder), Err(ReductionError::TermTooLarge(_))));
+        let fuel = Fuel {
+            steps: 10,
+            size: usize::MAX
+        };
+        let result = parse("Y x").reduce(Strategy::NormalOrder, fuel);
+        assert!(matches!(result, Err(ReductionError::OutOfFuel(term)) if term.size() == 12));
+    }
+}
@@ -0,0 +1,488 @@
+use std::collections::HashSet;
+
+use super::{Bird, Strategy, Term};
+
+/// A direction taken from an application towards one of its children
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Side
+{
+    /// The function being applied
+    Fun,
+    /// The argument it is applied to
+    Arg
+}
+
+/// The position of a subterm, as the path of directions taken from the root of the term
+///
+/// The redex `Sxy` in `Sxyz` is at `[Fun]`, and `z` is at `[Arg]`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position(pub Vec<Side>);
+
+impl std::fmt::Display for Position
+{
+    /// Writes the position as a string of `f`s and `a`s, or `ε` for the root
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        if self.0.is_empty()
+        {
+            return f.write_str("ε")
+        }
+        for side in &self.0
+        {
+            f.write_str(match side
+            {
+                Side::Fun => "f",
+                Side::Arg => "a"
+            })?
+        }
+        Ok(())
+    }
+}
+
+/// A single rewrite
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Event
+{
+    /// The bird which fired
+    pub bird: Bird,
+    /// Where the redex was
+    pub position: Position,
+    /// The redex
+    pub before: Term,
+    /// What the redex was rewritten to
+    pub after: Term
+}
+
+impl Event
+{
+    /// Applies the rewrite to the term
+    ///
+    /// Returns [None](None) if the term does not have the redex at the position.
+    pub fn apply(&self, term: &Term) -> Option<Term>
+    {
+        match term.at(&self.position)
+        {
+            Some(redex) if *redex == self.before => Some(term.replace_at(&self.position, self.after.clone())),
+            _ => None
+        }
+    }
+
+    /// Reverts the rewrite, turning the term back into the one it was applied to
+    ///
+    /// Returns [None](None) if the term does not have the result of the rewrite at the position.
+    pub fn undo(&self, term: &Term) -> Option<Term>
+    {
+        match term.at(&self.position)
+        {
+            Some(result) if *result == self.after => Some(term.replace_at(&self.position, self.before.clone())),
+            _ => None
+        }
+    }
+}
+
+impl std::fmt::Display for Event
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(f, "{} at {}: {} → {}", self.bird, self.position, self.before, self.after)
+    }
+}
+
+impl Term
+{
+    /// The subterm at the position, if there is one
+    pub fn at(&self, position: &Position) -> Option<&Term>
+    {
+        position.0.iter()
+            .try_fold(self, |term, side| match (term, side)
+            {
+                (Term::App(fun, _), Side::Fun) => Some(&**fun),
+                (Term::App(_, arg), Side::Arg) => Some(&**arg),
+                _ => None
+            })
+    }
+
+    /// Replaces the subterm at the position
+    ///
+    /// # Panics
+    ///
+    /// If there is no subterm at the position.
+    pub fn replace_at(&self, position: &Position, with: Term) -> Term
+    {
+        fn replace(term: &Term, path: &[Side], with: Term) -> Term
+        {
+            match (term, path)
+            {
+                (_, []) => with,
+                (Term::App(fun, arg), [Side::Fun, rest @ ..]) => replace(fun, rest, with).app((**arg).clone()),
+                (Term::App(fun, arg), [Side::Arg, rest @ ..]) => (**fun).clone().app(replace(arg, rest, with)),
+                _ => panic!("no subterm of {term} at {}", Position(path.to_vec()))
+            }
+        }
+
+        replace(self, &position.0, with)
+    }
+
+    /// Rewrites the redex at the position, returning the rewrite and the rewritten term
+    ///
+    /// Returns [None](None) if there is no redex at the position.
+    pub fn rewrite_at(&self, position: &Position) -> Option<(Event, Term)>
+    {
+        let before = self.at(position)?;
+        let (head, args) = before.spine();
+        let bird = match head
+        {
+            Term::Bird(bird) if args.len() == bird.arity() => *bird,
+            _ => return None
+        };
+        let event = Event {
+            bird,
+            position: position.clone(),
+            before: before.clone(),
+            after: bird.rewrite(args.into_iter().cloned().collect())
+        };
+        let term = self.replace_at(position, event.after.clone());
+        Some((event, term))
+    }
+
+    /// Reduces the term with the strategy, yielding every rewrite as it happens
+    ///
+    /// ```rust
+    /// use birbs::term::{Bird, Strategy, Term};
+    ///
+    /// let birds: Vec<Bird> = Term::parse("B(BW)(BC) x y z").unwrap()
+    ///     .trace(Strategy::NormalOrder)
+    ///     .map(|event| event.bird)
+    ///     .collect();
+    ///
+    /// assert_eq!(birds, [Bird::B, Bird::B, Bird::W, Bird::B, Bird::C]);
+    /// ```
+    pub fn trace(self, strategy: Strategy) -> Trace
+    {
+        Trace {
+            term: self,
+            strategy
+        }
+    }
+}
+
+/// An iterator over the rewrites of a reduction, see [Term::trace](Term::trace).
+#[derive(Clone, Debug)]
+pub struct Trace
+{
+    term: Term,
+    strategy: Strategy
+}
+
+impl Trace
+{
+    /// The term as it is after the rewrites yielded so far
+    pub fn term(&self) -> &Term
+    {
+        &self.term
+    }
+}
+
+impl Iterator for Trace
+{
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Event>
+    {
+        let position = self.term.redex(self.strategy)?;
+        let (event, term) = self.term.rewrite_at(&position)?;
+        self.term = term;
+        Some(event)
+    }
+}
+
+/// A recorded reduction, which can be replayed without reducing again
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Recording
+{
+    /// The term the reduction started from
+    pub start: Term,
+    /// The rewrites, in order
+    pub events: Vec<Event>
+}
+
+impl Recording
+{
+    /// Records at most `steps` rewrites of the reduction of the term
+    pub fn record(term: Term, strategy: Strategy, steps: usize) -> Recording
+    {
+        Recording {
+            start: term.clone(),
+            events: term.trace(strategy)
+                .take(steps)
+                .collect()
+        }
+    }
+
+    /// Every term of the reduction, after the start
+    ///
+    /// Stops early if a rewrite does not apply, which only happens if the recording was edited.
+    pub fn replay(&self) -> impl Iterator<Item = Term> + '_
+    {
+        self.events.iter()
+            .scan(self.start.clone(), |term, event| {
+                *term = event.apply(term)?;
+                Some(term.clone())
+            })
+    }
+
+    /// A zipper over the recording, at its start
+    ///
+    /// Once it is past the recorded rewrites, the zipper carries on reducing with the strategy.
+    pub fn zipper(&self, strategy: Strategy) -> Zipper
+    {
+        let mut zipper = Zipper::new(self.start.clone(), strategy);
+        zipper.future = self.events.iter()
+            .rev()
+            .cloned()
+            .collect();
+        zipper
+    }
+}
+
+/// Steps back and forth through a reduction
+///
+/// The rewrites done so far are kept, so any of them can be undone, and then redone.
+/// Breakpoints on birds make [resume](Zipper::resume) stop whenever one of them fires.
+///
+/// ```rust
+/// use birbs::term::{Bird, Strategy, Term, Zipper};
+///
+/// let mut zipper = Zipper::new(Term::parse("B(BW)(BC) x y z").unwrap(), Strategy::NormalOrder);
+/// zipper.break_on(Bird::W);
+///
+/// let event = zipper.resume().unwrap();
+/// assert_eq!(event.before, Term::parse("W(BCxy)z").unwrap());
+///
+/// // back to just before it fired
+/// zipper.backward();
+/// assert_eq!(zipper.term(), &Term::parse("W(BCxy)z").unwrap());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Zipper
+{
+    term: Term,
+    past: Vec<Event>,
+    future: Vec<Event>,
+    strategy: Strategy,
+    breakpoints: HashSet<Bird>
+}
+
+impl Zipper
+{
+    /// A zipper at the start of the reduction of the term
+    pub fn new(term: Term, strategy: Strategy) -> Zipper
+    {
+        Zipper {
+            term,
+            past: vec![],
+            future: vec![],
+            strategy,
+            breakpoints: HashSet::new()
+        }
+    }
+
+    /// The term at the current point of the reduction
+    pub fn term(&self) -> &Term
+    {
+        &self.term
+    }
+
+    /// The rewrites leading up to the current point, in order
+    pub fn past(&self) -> &[Event]
+    {
+        &self.past
+    }
+
+    /// Stops [resume](Zipper::resume) whenever the bird fires
+    pub fn break_on(&mut self, bird: Bird)
+    {
+        self.breakpoints.insert(bird);
+    }
+
+    /// Removes the breakpoint on the bird
+    pub fn clear(&mut self, bird: Bird)
+    {
+        self.breakpoints.remove(&bird);
+    }
+
+    /// Performs the next rewrite, redoing an undone one if there is any
+    ///
+    /// Returns [None](None) if the reduction is done, or if the next recorded rewrite does not apply,
+    /// which only happens if the recording was edited. The rest of the recording is then dropped,
+    /// so stepping forward again carries on reducing with the strategy.
+    pub fn forward(&mut self) -> Option<&Event>
+    {
+        let event = match self.future.pop()
+        {
+            Some(event) => match event.apply(&self.term)
+            {
+                Some(term) => {
+                    self.term = term;
+                    event
+                },
+                None => {
+                    self.future.clear();
+                    return None
+                }
+            },
+            None => {
+                let position = self.term.redex(self.strategy)?;
+                let (event, term) = self.term.rewrite_at(&position)?;
+                self.term = term;
+                event
+            }
+        };
+        self.past.push(event);
+        self.past.last()
+    }
+
+    /// Undoes the last rewrite
+    ///
+    /// Returns [None](None) if the zipper is at the start, or if the last rewrite does not undo on the current term.
+    pub fn backward(&mut self) -> Option<&Event>
+    {
+        self.term = self.past.last()?.undo(&self.term)?;
+        let event = self.past.pop()?;
+        self.future.push(event);
+        self.future.last()
+    }
+
+    /// Steps forward until a bird with a breakpoint fires, returning that rewrite
+    ///
+    /// Returns [None](None) if the reduction is done first. Just like [Term::normalize](Term::normalize),
+    /// this never returns if the reduction diverges without ever hitting a breakpoint.
+    pub fn resume(&mut self) -> Option<&Event>
+    {
+        loop
+        {
+            let bird = self.forward()?.bird;
+            if self.breakpoints.contains(&bird)
+            {
+                return self.past.last()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positions()
+    {
+        let term = Term::parse("Sxyz").unwrap();
+        let position = Position(vec![Side::Fun, Side::Arg]);
+
+        assert_eq!(term.at(&position), Some(&Term::var("y")));
+        assert_eq!(term.at(&Position(vec![Side::Arg, Side::Fun])), None);
+        assert_eq!(term.replace_at(&position, Term::var("w")), Term::parse("Sxwz").unwrap());
+        assert_eq!(position.to_string(), "fa");
+    }
+
+    #[test]
+    fn test_trace()
+    {
+        let parse = |source| Term::parse(source).unwrap();
+
+        let events: Vec<Event> = parse("x (K (I y) z)").trace(Strategy::NormalOrder).collect();
+        assert_eq!(events, [
+            Event {
+                bird: Bird::K,
+                position: Position(vec![Side::Arg]),
+                before: parse("K (I y) z"),
+                after: parse("I y")
+            },
+            Event {
+                bird: Bird::I,
+                position: Position(vec![Side::Arg]),
+                before: parse("I y"),
+                after: parse("y")
+            }
+        ]);
+
+        // every strategy's trace ends where its reduction does
+        let term = parse("S K K (I x) (K (I y))");
+        for strategy in [Strategy::NormalOrder, Strategy::ApplicativeOrder, Strategy::Head, Strategy::WeakHead, Strategy::LeftmostInnermost]
+        {
+            let mut trace = term.clone().trace(strategy);
+            trace.by_ref().for_each(drop);
+            assert_eq!(Ok(trace.term().clone()), term.clone().reduce(strategy, Default::default()), "{strategy:?}")
+        }
+    }
+
+    #[test]
+    fn test_replay()
+    {
+        let term = Term::parse("B(BW)(BC) x y z").unwrap();
+        let recording = Recording::record(term.clone(), Strategy::NormalOrder, 100);
+
+        let replayed: Vec<Term> = recording.replay().collect();
+        let mut reduced = vec![];
+        let mut next = term.clone();
+        while let Some(step) = next.step()
+        {
+            reduced.push(step.clone());
+            next = step
+        }
+        assert_eq!(replayed, reduced);
+
+        // a recording only replays on the term it was recorded from
+        let mut edited = recording.clone();
+        edited.start = Term::parse("B(BW)(BC) x y y").unwrap();
+        assert_eq!(edited.replay().count(), 2);
+    }
+
+    #[test]
+    fn test_zipper()
+    {
+        let term = Term::parse("B(BW)(BC) x y z").unwrap();
+        let recording = Recording::record(term.clone(), Strategy::NormalOrder, 100);
+
+        let mut zipper = recording.zipper(Strategy::NormalOrder);
+        assert!(zipper.backward().is_none());
+        while zipper.forward().is_some() {}
+        assert_eq!(zipper.past(), recording.events);
+        assert_eq!(zipper.term(), &Term::parse("xyzz").unwrap());
+
+        while zipper.backward().is_some() {}
+        assert_eq!(zipper.term(), &term);
+
+        // breakpoints
+        zipper.break_on(Bird::B);
+        let positions: Vec<Position> = std::iter::from_fn(|| zipper.resume().map(|event| event.position.clone())).collect();
+        assert_eq!(positions.len(), 3);
+        zipper.clear(Bird::B);
+        while zipper.backward().is_some() {}
+        zipper.break_on(Bird::C);
+        assert_eq!(zipper.resume().map(|event| event.bird), Some(Bird::C));
+        assert_eq!(zipper.past().len(), 5);
+        assert!(zipper.resume().is_none())
+    }
+
+    #[test]
+    fn test_zipper_edited()
+    {
+        let mut recording = Recording::record(Term::parse("B(BW)(BC) x y z").unwrap(), Strategy::NormalOrder, 100);
+        recording.start = Term::parse("B(BW)(BC) x y y").unwrap();
+
+        let mut zipper = recording.zipper(Strategy::NormalOrder);
+        assert!(zipper.forward().is_some());
+        assert!(zipper.forward().is_some());
+        // the third rewrite was recorded on z, so the rest of the recording is dropped
+        assert!(zipper.forward().is_none());
+        assert_eq!(zipper.past().len(), 2);
+
+        // and the zipper carries on reducing the term itself
+        while zipper.forward().is_some() {}
+        assert_eq!(zipper.term(), &Term::parse("xyyy").unwrap());
+        while zipper.backward().is_some() {}
+        assert_eq!(zipper.term(), &recording.start)
+    }
+}
@@ -0,0 +1,1345 @@
+//! Tracing what a composition calls
+//!
+//! A [Traced](Traced) function records each of its calls into a [Sink](Sink): its name, its arguments and its output,
+//! as written by their [Debug](std::fmt::Debug), and how deep it was called inside other traced functions.
+//! A function passed as a value to a traced function is written by its name if it is [Named](crate::named::Named).
+//! A sink wrapped in [Opaque](Opaque) records the calls without their arguments or output,
+//! so that functions traced into it may take and return values which aren't [Debug](std::fmt::Debug).
+//!
+//! Each bird has a traced variant here, which traces the whole composition by the symbol of the bird,
+//! and each of its arguments by the variable it is in the λ-body of the bird, such as `a` or `b`.
+//!
+//! ```rust
+//! use std::cell::RefCell;
+//!
+//! use birbs::traced;
+//!
+//! let sink = RefCell::new(vec![]);
+//!
+//! let a = |x: u8, y: u8| x + y;
+//! let b = |x: u8| x*2;
+//! let c = |x: u8| x + 1;
+//!
+//! let f = traced::s(&sink, a, b, c);
+//!
+//! assert_eq!(f(1, 2), 8);
+//!
+//! let log = sink.take()
+//!     .iter()
+//!     .map(ToString::to_string)
+//!     .collect::<Vec<_>>()
+//!     .join("\n");
+//!
+//! assert_eq!(log, "\
+//! S(1, 2)
+//!     c(2)
+//!     c = 3
+//!     b(3)
+//!     b = 6
+//!     c(1)
+//!     c = 2
+//!     a(2, 6)
+//!     a = 8
+//! S = 8");
+//! ```
+//!
+//! Y, Θ and Ω are [missing](crate#interpretations), since they never return.
+
+use std::cell::{Cell, RefCell};
+use std::fmt::Debug;
+use std::marker::Tuple;
+
+use currycompose::*;
+
+thread_local! {
+    /// How many traced functions are running on this thread
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Something recorded by a traced function
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Event
+{
+    /// The function was called, with the arguments as a tuple, unless they are [opaque](Opaque)
+    Call
+    {
+        name: &'static str,
+        depth: usize,
+        args: Option<String>
+    },
+    /// The function returned, with its output, unless it is [opaque](Opaque)
+    Return
+    {
+        name: &'static str,
+        depth: usize,
+        output: Option<String>
+    }
+}
+
+impl Event
+{
+    /// How many traced functions the call was made inside
+    pub fn depth(&self) -> usize
+    {
+        match self
+        {
+            Event::Call {depth, ..} | Event::Return {depth, ..} => *depth
+        }
+    }
+}
+
+impl std::fmt::Display for Event
+{
+    /// Writes the event on a line indented by its depth, such as `    a(1, 2)` or `    a = 3`,
+    /// or `    a(<opaque>)` and `    a = <opaque>` without the values
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(f, "{}", "    ".repeat(self.depth()))?;
+        match self
+        {
+            Event::Call {name, args: Some(args), ..} => write!(f, "{name}{args}"),
+            Event::Call {name, args: None, ..} => write!(f, "{name}(<opaque>)"),
+            Event::Return {name, output: Some(output), ..} => write!(f, "{name} = {output}"),
+            Event::Return {name, output: None, ..} => write!(f, "{name} = <opaque>")
+        }
+    }
+}
+
+/// Where traced functions record their events
+pub trait Sink
+{
+    fn record(&self, event: Event);
+}
+
+impl Sink for RefCell<Vec<Event>>
+{
+    fn record(&self, event: Event)
+    {
+        self.borrow_mut().push(event)
+    }
+}
+
+impl<F: Fn(Event)> Sink for F
+{
+    fn record(&self, event: Event)
+    {
+        self(event)
+    }
+}
+
+/// A sink recording calls without their arguments or output
+///
+/// Functions traced into it needn't take or return values which are [Debug](std::fmt::Debug).
+pub struct Opaque<S: ?Sized>(pub S);
+
+/// Marks a traced function as running, until dropped, even if the function panics
+struct Running(usize);
+
+impl Running
+{
+    fn start() -> Self
+    {
+        let depth = DEPTH.get();
+        DEPTH.set(depth + 1);
+        Running(depth)
+    }
+}
+
+impl Drop for Running
+{
+    fn drop(&mut self)
+    {
+        DEPTH.set(self.0)
+    }
+}
+
+/// Calls a function traced by the given name, recording the arguments as given, and the output as `show` writes it
+fn trace<S: Sink + ?Sized, O>(name: &'static str, sink: &S, args: Option<String>, call: impl FnOnce() -> O, show: impl FnOnce(&O) -> Option<String>) -> O
+{
+    let running = Running::start();
+    let depth = running.0;
+    sink.record(Event::Call {name, depth, args});
+    let output = call();
+    drop(running);
+    sink.record(Event::Return {name, depth, output: show(&output)});
+    output
+}
+
+/// Calls a function traced by the given name, recording its arguments and output by their [Debug](std::fmt::Debug)
+fn run<S: Sink + ?Sized, X: Tuple + Debug, O: Debug>(name: &'static str, sink: &S, args: X, call: impl FnOnce(X) -> O) -> O
+{
+    // a single argument would otherwise be written as `(x,)`
+    let shown = format!("{args:?}");
+    let shown = match shown.strip_suffix(",)")
+    {
+        Some(single) => format!("{single})"),
+        None => shown
+    };
+    trace(name, sink, Some(shown), || call(args), |output| Some(format!("{output:?}")))
+}
+
+/// A function which records each of its calls into a sink
+///
+/// Its [Debug](std::fmt::Debug) only writes the name, as with [Named](crate::named::Named).
+pub struct Traced<'s, F, S: ?Sized>
+{
+    pub name: &'static str,
+    pub function: F,
+    pub sink: &'s S
+}
+
+/// Traces a function by the given name
+pub const fn traced<'s, F, S: ?Sized>(name: &'static str, function: F, sink: &'s S) -> Traced<'s, F, S>
+{
+    Traced {
+        name,
+        function,
+        sink
+    }
+}
+
+impl<'s, F: Clone, S: ?Sized> Clone for Traced<'s, F, S>
+{
+    fn clone(&self) -> Self
+    {
+        Traced {
+            name: self.name,
+            function: self.function.clone(),
+            sink: self.sink
+        }
+    }
+}
+
+impl<'s, F: Copy, S: ?Sized> Copy for Traced<'s, F, S> {}
+
+impl<'s, F, S: ?Sized> std::fmt::Debug for Traced<'s, F, S>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        f.write_str(self.name)
+    }
+}
+
+impl<'s, F: FnOnce<X>, S: Sink + ?Sized, X: Tuple + Debug> FnOnce<X> for Traced<'s, F, S>
+where
+    F::Output: Debug
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(self, args: X) -> Self::Output
+    {
+        run(self.name, self.sink, args, |args| self.function.call_once(args))
+    }
+}
+
+impl<'s, F: FnMut<X>, S: Sink + ?Sized, X: Tuple + Debug> FnMut<X> for Traced<'s, F, S>
+where
+    F::Output: Debug
+{
+    extern "rust-call" fn call_mut(&mut self, args: X) -> Self::Output
+    {
+        run(self.name, self.sink, args, |args| self.function.call_mut(args))
+    }
+}
+
+impl<'s, F: Fn<X>, S: Sink + ?Sized, X: Tuple + Debug> Fn<X> for Traced<'s, F, S>
+where
+    F::Output: Debug
+{
+    extern "rust-call" fn call(&self, args: X) -> Self::Output
+    {
+        run(self.name, self.sink, args, |args| self.function.call(args))
+    }
+}
+
+impl<'s, F: FnOnce<X>, S: Sink + ?Sized, X: Tuple> FnOnce<X> for Traced<'s, F, Opaque<S>>
+{
+    type Output = F::Output;
+
+    extern "rust-call" fn call_once(self, args: X) -> Self::Output
+    {
+        trace(self.name, &self.sink.0, None, || self.function.call_once(args), |_| None)
+    }
+}
+
+impl<'s, F: FnMut<X>, S: Sink + ?Sized, X: Tuple> FnMut<X> for Traced<'s, F, Opaque<S>>
+{
+    extern "rust-call" fn call_mut(&mut self, args: X) -> Self::Output
+    {
+        trace(self.name, &self.sink.0, None, || self.function.call_mut(args), |_| None)
+    }
+}
+
+impl<'s, F: Fn<X>, S: Sink + ?Sized, X: Tuple> Fn<X> for Traced<'s, F, Opaque<S>>
+{
+    extern "rust-call" fn call(&self, args: X) -> Self::Output
+    {
+        trace(self.name, &self.sink.0, None, || self.function.call(args), |_| None)
+    }
+}
+
+/// B = Bluebird
+/// 
+/// λabc.a(bc)
+/// 
+/// [b](crate::b), traced as `B` around `a`, `b` and `c`
+pub fn b<'s, S, A, B, C, X1A, X1B, X2A, X2B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C
+) -> Traced<'s, Composition<Traced<'s, A, S>, Composition<Traced<'s, B, S>, Traced<'s, C, S>, X1A, X1B>, X2A, X2B>, S>
+where
+    S: ?Sized,
+    Traced<'s, B, S>: Compose<Traced<'s, C, S>, X1A, X1B>,
+    Traced<'s, A, S>: Compose<Composition<Traced<'s, B, S>, Traced<'s, C, S>, X1A, X1B>, X2A, X2B>
+{
+    traced("B", crate::b(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink)), sink)
+}
+
+/// B¹ = Blackbird
+/// 
+/// λabcd.a(bcd)
+/// 
+/// [b1](crate::b1), traced as `B¹` around `a`, `b`, `c` and `d`
+pub fn b1<'s, S, A, B, C, D, X1A, X1B, X2A, X2B, X3A, X3B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> Traced<'s, Composition<Traced<'s, A, S>, Composition<Composition<Traced<'s, B, S>, Traced<'s, C, S>, X1A, X1B>, Traced<'s, D, S>, X2A, X2B>, X3A, X3B>, S>
+where
+    S: ?Sized,
+    Traced<'s, B, S>: Compose<Traced<'s, C, S>, X1A, X1B>,
+    Composition<Traced<'s, B, S>, Traced<'s, C, S>, X1A, X1B>: Compose<Traced<'s, D, S>, X2A, X2B>,
+    Traced<'s, A, S>: Compose<Composition<Composition<Traced<'s, B, S>, Traced<'s, C, S>, X1A, X1B>, Traced<'s, D, S>, X2A, X2B>, X3A, X3B>
+{
+    traced("B¹", crate::b1(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink), traced("d", d, sink)), sink)
+}
+
+/// B² = Bunting
+/// 
+/// λabcde.a(bcde)
+/// 
+/// [b2](crate::b2), traced as `B²` around `a`, `b`, `c`, `d` and `e`
+pub fn b2<'s, S, A, B, C, D, E, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> Traced<'s, Composition<Traced<'s, A, S>, Composition<Composition<Composition<Traced<'s, B, S>, Traced<'s, C, S>, X1A, X1B>, Traced<'s, D, S>, X2A, X2B>, Traced<'s, E, S>, X3A, X3B>, X4A, X4B>, S>
+where
+    S: ?Sized,
+    Traced<'s, B, S>: Compose<Traced<'s, C, S>, X1A, X1B>,
+    Composition<Traced<'s, B, S>, Traced<'s, C, S>, X1A, X1B>: Compose<Traced<'s, D, S>, X2A, X2B>,
+    Composition<Composition<Traced<'s, B, S>, Traced<'s, C, S>, X1A, X1B>, Traced<'s, D, S>, X2A, X2B>: Compose<Traced<'s, E, S>, X3A, X3B>,
+    Traced<'s, A, S>: Compose<Composition<Composition<Composition<Traced<'s, B, S>, Traced<'s, C, S>, X1A, X1B>, Traced<'s, D, S>, X2A, X2B>, Traced<'s, E, S>, X3A, X3B>, X4A, X4B>
+{
+    traced("B²", crate::b2(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink), traced("d", d, sink), traced("e", e, sink)), sink)
+}
+
+/// B³ = Becard
+/// 
+/// λabcd.a(b(cd))
+/// 
+/// [b3](crate::b3), traced as `B³` around `a`, `b`, `c` and `d`
+pub fn b3<'s, S, A, B, C, D, X1A, X1B, X2A, X2B, X3A, X3B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> Traced<'s, Composition<Traced<'s, A, S>, Composition<Traced<'s, B, S>, Composition<Traced<'s, C, S>, Traced<'s, D, S>, X1A, X1B>, X2A, X2B>, X3A, X3B>, S>
+where
+    S: ?Sized,
+    Traced<'s, C, S>: Compose<Traced<'s, D, S>, X1A, X1B>,
+    Traced<'s, B, S>: Compose<Composition<Traced<'s, C, S>, Traced<'s, D, S>, X1A, X1B>, X2A, X2B>,
+    Traced<'s, A, S>: Compose<Composition<Traced<'s, B, S>, Composition<Traced<'s, C, S>, Traced<'s, D, S>, X1A, X1B>, X2A, X2B>, X3A, X3B>
+{
+    traced("B³", crate::b3(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink), traced("d", d, sink)), sink)
+}
+
+/// C = Cardinal
+/// 
+/// λabc.acb
+/// 
+/// [c](crate::c), traced as `C` around `a`, `b` and `c`
+pub fn c<'s, S, A, B, C, X1A, X1B, X2A, X2B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C
+) -> Traced<'s, Composition<Composition<Traced<'s, A, S>, Traced<'s, C, S>, X1A, X1B>, Traced<'s, B, S>, X2A, X2B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, C, S>, X1A, X1B>,
+    Composition<Traced<'s, A, S>, Traced<'s, C, S>, X1A, X1B>: Compose<Traced<'s, B, S>, X2A, X2B>
+{
+    traced("C", crate::c(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink)), sink)
+}
+
+/// D = Dove
+/// 
+/// λabcd.ab(cd)
+/// 
+/// [d](crate::d), traced as `D` around `a`, `b`, `c` and `d`
+pub fn d<'s, S, A, B, C, D, X1A, X1B, X2A, X2B, X3A, X3B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> Traced<'s, Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Composition<Traced<'s, C, S>, Traced<'s, D, S>, X2A, X2B>, X3A, X3B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, B, S>, X1A, X1B>,
+    Traced<'s, C, S>: Compose<Traced<'s, D, S>, X2A, X2B>,
+    Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>: Compose<Composition<Traced<'s, C, S>, Traced<'s, D, S>, X2A, X2B>, X3A, X3B>
+{
+    traced("D", crate::d(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink), traced("d", d, sink)), sink)
+}
+
+/// D¹ = Dickcissel
+/// 
+/// λabcde.abc(de)
+/// 
+/// [d1](crate::d1), traced as `D¹` around `a`, `b`, `c`, `d` and `e`
+pub fn d1<'s, S, A, B, C, D, E, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> Traced<'s, Composition<Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, C, S>, X2A, X2B>, Composition<Traced<'s, D, S>, Traced<'s, E, S>, X3A, X3B>, X4A, X4B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, B, S>, X1A, X1B>,
+    Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>: Compose<Traced<'s, C, S>, X2A, X2B>,
+    Traced<'s, D, S>: Compose<Traced<'s, E, S>, X3A, X3B>,
+    Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, C, S>, X2A, X2B>: Compose<Composition<Traced<'s, D, S>, Traced<'s, E, S>, X3A, X3B>, X4A, X4B>
+{
+    traced("D¹", crate::d1(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink), traced("d", d, sink), traced("e", e, sink)), sink)
+}
+
+/// D² = Dovekies
+/// 
+/// λabcde.a(bc)(de)
+/// 
+/// [d2](crate::d2), traced as `D²` around `a`, `b`, `c`, `d` and `e`
+pub fn d2<'s, S, A, B, C, D, E, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> Traced<'s, Composition<Composition<Traced<'s, A, S>, Composition<Traced<'s, B, S>, Traced<'s, C, S>, X1A, X1B>, X2A, X2B>, Composition<Traced<'s, D, S>, Traced<'s, E, S>, X3A, X3B>, X4A, X4B>, S>
+where
+    S: ?Sized,
+    Traced<'s, B, S>: Compose<Traced<'s, C, S>, X1A, X1B>,
+    Traced<'s, A, S>: Compose<Composition<Traced<'s, B, S>, Traced<'s, C, S>, X1A, X1B>, X2A, X2B>,
+    Traced<'s, D, S>: Compose<Traced<'s, E, S>, X3A, X3B>,
+    Composition<Traced<'s, A, S>, Composition<Traced<'s, B, S>, Traced<'s, C, S>, X1A, X1B>, X2A, X2B>: Compose<Composition<Traced<'s, D, S>, Traced<'s, E, S>, X3A, X3B>, X4A, X4B>
+{
+    traced("D²", crate::d2(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink), traced("d", d, sink), traced("e", e, sink)), sink)
+}
+
+/// E = Eagle
+/// 
+/// λabcde.ab(cde)
+/// 
+/// [e](crate::e), traced as `E` around `a`, `b`, `c`, `d` and `e`
+pub fn e<'s, S, A, B, C, D, E, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> Traced<'s, Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Composition<Composition<Traced<'s, C, S>, Traced<'s, D, S>, X2A, X2B>, Traced<'s, E, S>, X3A, X3B>, X4A, X4B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, B, S>, X1A, X1B>,
+    Traced<'s, C, S>: Compose<Traced<'s, D, S>, X2A, X2B>,
+    Composition<Traced<'s, C, S>, Traced<'s, D, S>, X2A, X2B>: Compose<Traced<'s, E, S>, X3A, X3B>,
+    Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>: Compose<Composition<Composition<Traced<'s, C, S>, Traced<'s, D, S>, X2A, X2B>, Traced<'s, E, S>, X3A, X3B>, X4A, X4B>
+{
+    traced("E", crate::e(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink), traced("d", d, sink), traced("e", e, sink)), sink)
+}
+
+/// Ê = Bald Eagle
+/// 
+/// λabcdefg.a(bcd)(efg)
+/// 
+/// [ê](crate::ê), traced as `Ê` around `a`, `b`, `c`, `d`, `e`, `f` and `g`
+pub fn ê<'s, S, A, B, C, D, E, F, G, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B, X5A, X5B, X6A, X6B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E,
+    f: F,
+    g: G
+) -> Traced<'s, Composition<Composition<Traced<'s, A, S>, Composition<Composition<Traced<'s, B, S>, Traced<'s, C, S>, X1A, X1B>, Traced<'s, D, S>, X2A, X2B>, X3A, X3B>, Composition<Composition<Traced<'s, E, S>, Traced<'s, F, S>, X4A, X4B>, Traced<'s, G, S>, X5A, X5B>, X6A, X6B>, S>
+where
+    S: ?Sized,
+    Traced<'s, B, S>: Compose<Traced<'s, C, S>, X1A, X1B>,
+    Composition<Traced<'s, B, S>, Traced<'s, C, S>, X1A, X1B>: Compose<Traced<'s, D, S>, X2A, X2B>,
+    Traced<'s, A, S>: Compose<Composition<Composition<Traced<'s, B, S>, Traced<'s, C, S>, X1A, X1B>, Traced<'s, D, S>, X2A, X2B>, X3A, X3B>,
+    Traced<'s, E, S>: Compose<Traced<'s, F, S>, X4A, X4B>,
+    Composition<Traced<'s, E, S>, Traced<'s, F, S>, X4A, X4B>: Compose<Traced<'s, G, S>, X5A, X5B>,
+    Composition<Traced<'s, A, S>, Composition<Composition<Traced<'s, B, S>, Traced<'s, C, S>, X1A, X1B>, Traced<'s, D, S>, X2A, X2B>, X3A, X3B>: Compose<Composition<Composition<Traced<'s, E, S>, Traced<'s, F, S>, X4A, X4B>, Traced<'s, G, S>, X5A, X5B>, X6A, X6B>
+{
+    traced("Ê", crate::ê(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink), traced("d", d, sink), traced("e", e, sink), traced("f", f, sink), traced("g", g, sink)), sink)
+}
+
+/// F = Finch
+/// 
+/// λabc.cba
+/// 
+/// [f](crate::f), traced as `F` around `a`, `b` and `c`
+pub fn f<'s, S, A, B, C, X1A, X1B, X2A, X2B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C
+) -> Traced<'s, Composition<Composition<Traced<'s, C, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, A, S>, X2A, X2B>, S>
+where
+    S: ?Sized,
+    Traced<'s, C, S>: Compose<Traced<'s, B, S>, X1A, X1B>,
+    Composition<Traced<'s, C, S>, Traced<'s, B, S>, X1A, X1B>: Compose<Traced<'s, A, S>, X2A, X2B>
+{
+    traced("F", crate::f(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink)), sink)
+}
+
+/// G = Goldfinch
+/// 
+/// λabcd.ad(bc)
+/// 
+/// [g](crate::g), traced as `G` around `a`, `b`, `c` and `d`
+pub fn g<'s, S, A, B, C, D, X1A, X1B, X2A, X2B, X3A, X3B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> Traced<'s, Composition<Composition<Traced<'s, A, S>, Traced<'s, D, S>, X1A, X1B>, Composition<Traced<'s, B, S>, Traced<'s, C, S>, X2A, X2B>, X3A, X3B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, D, S>, X1A, X1B>,
+    Traced<'s, B, S>: Compose<Traced<'s, C, S>, X2A, X2B>,
+    Composition<Traced<'s, A, S>, Traced<'s, D, S>, X1A, X1B>: Compose<Composition<Traced<'s, B, S>, Traced<'s, C, S>, X2A, X2B>, X3A, X3B>
+{
+    traced("G", crate::g(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink), traced("d", d, sink)), sink)
+}
+
+/// H = Hummingbird
+/// 
+/// λabc.abcb
+/// 
+/// [h](crate::h), traced as `H` around `a`, `b` and `c`
+pub fn h<'s, S, A, B, C, X1A, X1B, X2A, X2B, X3A, X3B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C
+) -> Traced<'s, Composition<Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, C, S>, X2A, X2B>, Traced<'s, B, S>, X3A, X3B>, S>
+where
+    S: ?Sized,
+    Traced<'s, B, S>: Copy,
+    Traced<'s, A, S>: Compose<Traced<'s, B, S>, X1A, X1B>,
+    Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>: Compose<Traced<'s, C, S>, X2A, X2B>,
+    Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, C, S>, X2A, X2B>: Compose<Traced<'s, B, S>, X3A, X3B>
+{
+    traced("H", crate::h(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink)), sink)
+}
+
+/// I = Identity Bird aka Idiot
+/// 
+/// λa.a
+/// 
+/// [i](crate::i), traced as `I` around `a`
+pub fn i<'s, S, A>(
+    sink: &'s S,
+    a: A
+) -> Traced<'s, Traced<'s, A, S>, S>
+where
+    S: ?Sized
+{
+    traced("I", crate::i(traced("a", a, sink)), sink)
+}
+
+/// J = Jay
+/// 
+/// λabcd.ab(adc)
+/// 
+/// [j](crate::j), traced as `J` around `a`, `b`, `c` and `d`
+pub fn j<'s, S, A, B, C, D, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> Traced<'s, Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Composition<Composition<Traced<'s, A, S>, Traced<'s, D, S>, X2A, X2B>, Traced<'s, C, S>, X3A, X3B>, X4A, X4B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, B, S>, X1A, X1B> + Compose<Traced<'s, D, S>, X2A, X2B> + Copy,
+    Composition<Traced<'s, A, S>, Traced<'s, D, S>, X2A, X2B>: Compose<Traced<'s, C, S>, X3A, X3B>,
+    Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>: Compose<Composition<Composition<Traced<'s, A, S>, Traced<'s, D, S>, X2A, X2B>, Traced<'s, C, S>, X3A, X3B>, X4A, X4B>
+{
+    traced("J", crate::j(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink), traced("d", d, sink)), sink)
+}
+
+/// K = Kestrel (True)
+/// 
+/// λab.a
+/// 
+/// [k](crate::k), traced as `K` around `a` and `b`
+pub fn k<'s, S, A, B>(
+    sink: &'s S,
+    a: A,
+    b: B
+) -> Traced<'s, Traced<'s, A, S>, S>
+where
+    S: ?Sized
+{
+    traced("K", crate::k(traced("a", a, sink), traced("b", b, sink)), sink)
+}
+
+/// L = Lark
+/// 
+/// λab.a(bb)
+/// 
+/// [l](crate::l), traced as `L` around `a` and `b`
+pub fn l<'s, S, A, B, X1A, X1B, X2A, X2B>(
+    sink: &'s S,
+    a: A,
+    b: B
+) -> Traced<'s, Composition<Traced<'s, A, S>, Composition<Traced<'s, B, S>, Traced<'s, B, S>, X1A, X1B>, X2A, X2B>, S>
+where
+    S: ?Sized,
+    Traced<'s, B, S>: Compose<Traced<'s, B, S>, X1A, X1B> + Copy,
+    Traced<'s, A, S>: Compose<Composition<Traced<'s, B, S>, Traced<'s, B, S>, X1A, X1B>, X2A, X2B>
+{
+    traced("L", crate::l(traced("a", a, sink), traced("b", b, sink)), sink)
+}
+
+/// M = Mockingbird
+/// 
+/// λa.aa
+/// 
+/// [m](crate::m), traced as `M` around `a`
+pub fn m<'s, S, A, X1A, X1B>(
+    sink: &'s S,
+    a: A
+) -> Traced<'s, Composition<Traced<'s, A, S>, Traced<'s, A, S>, X1A, X1B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, A, S>, X1A, X1B> + Copy
+{
+    traced("M", crate::m(traced("a", a, sink)), sink)
+}
+
+/// M² = Double Mockingbird
+/// 
+/// λab.ab(ab)
+/// 
+/// [m2](crate::m2), traced as `M²` around `a` and `b`
+pub fn m2<'s, S, A, B, X1A, X1B, X2A, X2B, X3A, X3B>(
+    sink: &'s S,
+    a: A,
+    b: B
+) -> Traced<'s, Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Composition<Traced<'s, A, S>, Traced<'s, B, S>, X2A, X2B>, X3A, X3B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, B, S>, X1A, X1B> + Compose<Traced<'s, B, S>, X2A, X2B> + Copy,
+    Traced<'s, B, S>: Copy,
+    Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>: Compose<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X2A, X2B>, X3A, X3B>
+{
+    traced("M²", crate::m2(traced("a", a, sink), traced("b", b, sink)), sink)
+}
+
+/// O = Owl
+/// 
+/// λab.b(ab)
+/// 
+/// [o](crate::o), traced as `O` around `a` and `b`
+pub fn o<'s, S, A, B, X1A, X1B, X2A, X2B>(
+    sink: &'s S,
+    a: A,
+    b: B
+) -> Traced<'s, Composition<Traced<'s, B, S>, Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, X2A, X2B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, B, S>, X1A, X1B>,
+    Traced<'s, B, S>: Compose<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, X2A, X2B> + Copy
+{
+    traced("O", crate::o(traced("a", a, sink), traced("b", b, sink)), sink)
+}
+
+/// Q = Queer Bird
+/// 
+/// λabc.b(ac)
+/// 
+/// [q](crate::q), traced as `Q` around `a`, `b` and `c`
+pub fn q<'s, S, A, B, C, X1A, X1B, X2A, X2B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C
+) -> Traced<'s, Composition<Traced<'s, B, S>, Composition<Traced<'s, A, S>, Traced<'s, C, S>, X1A, X1B>, X2A, X2B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, C, S>, X1A, X1B>,
+    Traced<'s, B, S>: Compose<Composition<Traced<'s, A, S>, Traced<'s, C, S>, X1A, X1B>, X2A, X2B>
+{
+    traced("Q", crate::q(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink)), sink)
+}
+
+/// Q¹ = Quixotic Bird
+/// 
+/// λabc.a(cb)
+/// 
+/// [q1](crate::q1), traced as `Q¹` around `a`, `b` and `c`
+pub fn q1<'s, S, A, B, C, X1A, X1B, X2A, X2B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C
+) -> Traced<'s, Composition<Traced<'s, A, S>, Composition<Traced<'s, C, S>, Traced<'s, B, S>, X1A, X1B>, X2A, X2B>, S>
+where
+    S: ?Sized,
+    Traced<'s, C, S>: Compose<Traced<'s, B, S>, X1A, X1B>,
+    Traced<'s, A, S>: Compose<Composition<Traced<'s, C, S>, Traced<'s, B, S>, X1A, X1B>, X2A, X2B>
+{
+    traced("Q¹", crate::q1(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink)), sink)
+}
+
+/// Q² = Quizzical Bird
+/// 
+/// λabc.b(ca)
+/// 
+/// [q2](crate::q2), traced as `Q²` around `a`, `b` and `c`
+pub fn q2<'s, S, A, B, C, X1A, X1B, X2A, X2B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C
+) -> Traced<'s, Composition<Traced<'s, B, S>, Composition<Traced<'s, C, S>, Traced<'s, A, S>, X1A, X1B>, X2A, X2B>, S>
+where
+    S: ?Sized,
+    Traced<'s, C, S>: Compose<Traced<'s, A, S>, X1A, X1B>,
+    Traced<'s, B, S>: Compose<Composition<Traced<'s, C, S>, Traced<'s, A, S>, X1A, X1B>, X2A, X2B>
+{
+    traced("Q²", crate::q2(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink)), sink)
+}
+
+/// Q³ = Quirky Bird
+/// 
+/// λabc.c(ab)
+/// 
+/// [q3](crate::q3), traced as `Q³` around `a`, `b` and `c`
+pub fn q3<'s, S, A, B, C, X1A, X1B, X2A, X2B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C
+) -> Traced<'s, Composition<Traced<'s, C, S>, Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, X2A, X2B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, B, S>, X1A, X1B>,
+    Traced<'s, C, S>: Compose<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, X2A, X2B>
+{
+    traced("Q³", crate::q3(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink)), sink)
+}
+
+/// Q⁴ = Quacky Bird
+/// 
+/// λabc.c(ba)
+/// 
+/// [q4](crate::q4), traced as `Q⁴` around `a`, `b` and `c`
+pub fn q4<'s, S, A, B, C, X1A, X1B, X2A, X2B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C
+) -> Traced<'s, Composition<Traced<'s, C, S>, Composition<Traced<'s, B, S>, Traced<'s, A, S>, X1A, X1B>, X2A, X2B>, S>
+where
+    S: ?Sized,
+    Traced<'s, B, S>: Compose<Traced<'s, A, S>, X1A, X1B>,
+    Traced<'s, C, S>: Compose<Composition<Traced<'s, B, S>, Traced<'s, A, S>, X1A, X1B>, X2A, X2B>
+{
+    traced("Q⁴", crate::q4(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink)), sink)
+}
+
+/// R = Robin
+/// 
+/// λabc.bca
+/// 
+/// [r](crate::r), traced as `R` around `a`, `b` and `c`
+pub fn r<'s, S, A, B, C, X1A, X1B, X2A, X2B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C
+) -> Traced<'s, Composition<Composition<Traced<'s, B, S>, Traced<'s, C, S>, X1A, X1B>, Traced<'s, A, S>, X2A, X2B>, S>
+where
+    S: ?Sized,
+    Traced<'s, B, S>: Compose<Traced<'s, C, S>, X1A, X1B>,
+    Composition<Traced<'s, B, S>, Traced<'s, C, S>, X1A, X1B>: Compose<Traced<'s, A, S>, X2A, X2B>
+{
+    traced("R", crate::r(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink)), sink)
+}
+
+/// S = Starling
+/// 
+/// λabc.ac(bc)
+/// 
+/// [s](crate::s), traced as `S` around `a`, `b` and `c`
+pub fn s<'s, S, A, B, C, X1A, X1B, X2A, X2B, X3A, X3B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C
+) -> Traced<'s, Composition<Composition<Traced<'s, A, S>, Traced<'s, C, S>, X1A, X1B>, Composition<Traced<'s, B, S>, Traced<'s, C, S>, X2A, X2B>, X3A, X3B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, C, S>, X1A, X1B>,
+    Traced<'s, B, S>: Compose<Traced<'s, C, S>, X2A, X2B>,
+    Composition<Traced<'s, A, S>, Traced<'s, C, S>, X1A, X1B>: Compose<Composition<Traced<'s, B, S>, Traced<'s, C, S>, X2A, X2B>, X3A, X3B>,
+    Traced<'s, C, S>: Copy
+{
+    traced("S", crate::s(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink)), sink)
+}
+
+/// T = Thrush
+/// 
+/// λab.ba
+/// 
+/// [t](crate::t), traced as `T` around `a` and `b`
+pub fn t<'s, S, A, B, X1A, X1B>(
+    sink: &'s S,
+    a: A,
+    b: B
+) -> Traced<'s, Composition<Traced<'s, B, S>, Traced<'s, A, S>, X1A, X1B>, S>
+where
+    S: ?Sized,
+    Traced<'s, B, S>: Compose<Traced<'s, A, S>, X1A, X1B>
+{
+    traced("T", crate::t(traced("a", a, sink), traced("b", b, sink)), sink)
+}
+
+/// U = Turing
+/// 
+/// λab.b(aab)
+/// 
+/// [u](crate::u), traced as `U` around `a` and `b`
+pub fn u<'s, S, A, B, X1A, X1B, X2A, X2B, X3A, X3B>(
+    sink: &'s S,
+    a: A,
+    b: B
+) -> Traced<'s, Composition<Traced<'s, B, S>, Composition<Composition<Traced<'s, A, S>, Traced<'s, A, S>, X1A, X1B>, Traced<'s, B, S>, X2A, X2B>, X3A, X3B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, A, S>, X1A, X1B> + Copy,
+    Composition<Traced<'s, A, S>, Traced<'s, A, S>, X1A, X1B>: Compose<Traced<'s, B, S>, X2A, X2B>,
+    Traced<'s, B, S>: Compose<Composition<Composition<Traced<'s, A, S>, Traced<'s, A, S>, X1A, X1B>, Traced<'s, B, S>, X2A, X2B>, X3A, X3B> + Copy
+{
+    traced("U", crate::u(traced("a", a, sink), traced("b", b, sink)), sink)
+}
+
+/// V = Vireo aka Pairing
+/// 
+/// λabc.cab
+/// 
+/// [v](crate::v), traced as `V` around `a`, `b` and `c`
+pub fn v<'s, S, A, B, C, X1A, X1B, X2A, X2B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C
+) -> Traced<'s, Composition<Composition<Traced<'s, C, S>, Traced<'s, A, S>, X1A, X1B>, Traced<'s, B, S>, X2A, X2B>, S>
+where
+    S: ?Sized,
+    Traced<'s, C, S>: Compose<Traced<'s, A, S>, X1A, X1B>,
+    Composition<Traced<'s, C, S>, Traced<'s, A, S>, X1A, X1B>: Compose<Traced<'s, B, S>, X2A, X2B>
+{
+    traced("V", crate::v(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink)), sink)
+}
+
+/// W = Warbler
+/// 
+/// λab.abb
+/// 
+/// [w](crate::w), traced as `W` around `a` and `b`
+pub fn w<'s, S, A, B, X1A, X1B, X2A, X2B>(
+    sink: &'s S,
+    a: A,
+    b: B
+) -> Traced<'s, Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, B, S>, X2A, X2B>, S>
+where
+    S: ?Sized,
+    Traced<'s, B, S>: Copy,
+    Traced<'s, A, S>: Compose<Traced<'s, B, S>, X1A, X1B>,
+    Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>: Compose<Traced<'s, B, S>, X2A, X2B>
+{
+    traced("W", crate::w(traced("a", a, sink), traced("b", b, sink)), sink)
+}
+
+/// W¹ = Converse Warbler
+/// 
+/// λab.baa
+/// 
+/// [w1](crate::w1), traced as `W¹` around `a` and `b`
+pub fn w1<'s, S, A, B, X1A, X1B, X2A, X2B>(
+    sink: &'s S,
+    a: A,
+    b: B
+) -> Traced<'s, Composition<Composition<Traced<'s, B, S>, Traced<'s, A, S>, X1A, X1B>, Traced<'s, A, S>, X2A, X2B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Copy,
+    Traced<'s, B, S>: Compose<Traced<'s, A, S>, X1A, X1B>,
+    Composition<Traced<'s, B, S>, Traced<'s, A, S>, X1A, X1B>: Compose<Traced<'s, A, S>, X2A, X2B>
+{
+    traced("W¹", crate::w1(traced("a", a, sink), traced("b", b, sink)), sink)
+}
+
+/// I* = Identity Bird Once Removed
+/// 
+/// λab.ab
+/// 
+/// [i_star](crate::i_star), traced as `I*` around `a` and `b`
+pub fn i_star<'s, S, A, B, X1A, X1B>(
+    sink: &'s S,
+    a: A,
+    b: B
+) -> Traced<'s, Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, B, S>, X1A, X1B>
+{
+    traced("I*", crate::i_star(traced("a", a, sink), traced("b", b, sink)), sink)
+}
+
+/// W* = Warbled Once Removed
+/// 
+/// λabc.abcc
+/// 
+/// [w_star](crate::w_star), traced as `W*` around `a`, `b` and `c`
+pub fn w_star<'s, S, A, B, C, X1A, X1B, X2A, X2B, X3A, X3B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C
+) -> Traced<'s, Composition<Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, C, S>, X2A, X2B>, Traced<'s, C, S>, X3A, X3B>, S>
+where
+    S: ?Sized,
+    Traced<'s, C, S>: Copy,
+    Traced<'s, A, S>: Compose<Traced<'s, B, S>, X1A, X1B>,
+    Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>: Compose<Traced<'s, C, S>, X2A, X2B>,
+    Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, C, S>, X2A, X2B>: Compose<Traced<'s, C, S>, X3A, X3B>
+{
+    traced("W*", crate::w_star(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink)), sink)
+}
+
+/// C* = Cardinal Once Removed
+/// 
+/// λabcd.abdc
+/// 
+/// [c_star](crate::c_star), traced as `C*` around `a`, `b`, `c` and `d`
+pub fn c_star<'s, S, A, B, C, D, X1A, X1B, X2A, X2B, X3A, X3B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> Traced<'s, Composition<Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, D, S>, X2A, X2B>, Traced<'s, C, S>, X3A, X3B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, B, S>, X1A, X1B>,
+    Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>: Compose<Traced<'s, D, S>, X2A, X2B>,
+    Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, D, S>, X2A, X2B>: Compose<Traced<'s, C, S>, X3A, X3B>
+{
+    traced("C*", crate::c_star(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink), traced("d", d, sink)), sink)
+}
+
+/// R* = Robin Once Removed
+/// 
+/// λabcd.acdb
+/// 
+/// [r_star](crate::r_star), traced as `R*` around `a`, `b`, `c` and `d`
+pub fn r_star<'s, S, A, B, C, D, X1A, X1B, X2A, X2B, X3A, X3B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> Traced<'s, Composition<Composition<Composition<Traced<'s, A, S>, Traced<'s, C, S>, X1A, X1B>, Traced<'s, D, S>, X2A, X2B>, Traced<'s, B, S>, X3A, X3B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, C, S>, X1A, X1B>,
+    Composition<Traced<'s, A, S>, Traced<'s, C, S>, X1A, X1B>: Compose<Traced<'s, D, S>, X2A, X2B>,
+    Composition<Composition<Traced<'s, A, S>, Traced<'s, C, S>, X1A, X1B>, Traced<'s, D, S>, X2A, X2B>: Compose<Traced<'s, B, S>, X3A, X3B>
+{
+    traced("R*", crate::r_star(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink), traced("d", d, sink)), sink)
+}
+
+/// F* = Finch Once Removed
+/// 
+/// λabcd.adcb
+/// 
+/// [f_star](crate::f_star), traced as `F*` around `a`, `b`, `c` and `d`
+pub fn f_star<'s, S, A, B, C, D, X1A, X1B, X2A, X2B, X3A, X3B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> Traced<'s, Composition<Composition<Composition<Traced<'s, A, S>, Traced<'s, D, S>, X1A, X1B>, Traced<'s, C, S>, X2A, X2B>, Traced<'s, B, S>, X3A, X3B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, D, S>, X1A, X1B>,
+    Composition<Traced<'s, A, S>, Traced<'s, D, S>, X1A, X1B>: Compose<Traced<'s, C, S>, X2A, X2B>,
+    Composition<Composition<Traced<'s, A, S>, Traced<'s, D, S>, X1A, X1B>, Traced<'s, C, S>, X2A, X2B>: Compose<Traced<'s, B, S>, X3A, X3B>
+{
+    traced("F*", crate::f_star(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink), traced("d", d, sink)), sink)
+}
+
+/// V* = Vireo Once Removed
+/// 
+/// λabcd.acbd
+/// 
+/// [v_star](crate::v_star), traced as `V*` around `a`, `b`, `c` and `d`
+pub fn v_star<'s, S, A, B, C, D, X1A, X1B, X2A, X2B, X3A, X3B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> Traced<'s, Composition<Composition<Composition<Traced<'s, A, S>, Traced<'s, C, S>, X1A, X1B>, Traced<'s, B, S>, X2A, X2B>, Traced<'s, D, S>, X3A, X3B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, C, S>, X1A, X1B>,
+    Composition<Traced<'s, A, S>, Traced<'s, C, S>, X1A, X1B>: Compose<Traced<'s, B, S>, X2A, X2B>,
+    Composition<Composition<Traced<'s, A, S>, Traced<'s, C, S>, X1A, X1B>, Traced<'s, B, S>, X2A, X2B>: Compose<Traced<'s, D, S>, X3A, X3B>
+{
+    traced("V*", crate::v_star(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink), traced("d", d, sink)), sink)
+}
+
+/// I** = Identity Bird Twice Removed
+/// 
+/// λabc.abc
+/// 
+/// [i_star_star](crate::i_star_star), traced as `I**` around `a`, `b` and `c`
+pub fn i_star_star<'s, S, A, B, C, X1A, X1B, X2A, X2B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C
+) -> Traced<'s, Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, C, S>, X2A, X2B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, B, S>, X1A, X1B>,
+    Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>: Compose<Traced<'s, C, S>, X2A, X2B>
+{
+    traced("I**", crate::i_star_star(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink)), sink)
+}
+
+/// W** = Warbler Twice Removed
+/// 
+/// λabcd.abcdd
+/// 
+/// [w_star_star](crate::w_star_star), traced as `W**` around `a`, `b`, `c` and `d`
+pub fn w_star_star<'s, S, A, B, C, D, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C,
+    d: D
+) -> Traced<'s, Composition<Composition<Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, C, S>, X2A, X2B>, Traced<'s, D, S>, X3A, X3B>, Traced<'s, D, S>, X4A, X4B>, S>
+where
+    S: ?Sized,
+    Traced<'s, D, S>: Copy,
+    Traced<'s, A, S>: Compose<Traced<'s, B, S>, X1A, X1B>,
+    Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>: Compose<Traced<'s, C, S>, X2A, X2B>,
+    Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, C, S>, X2A, X2B>: Compose<Traced<'s, D, S>, X3A, X3B>,
+    Composition<Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, C, S>, X2A, X2B>, Traced<'s, D, S>, X3A, X3B>: Compose<Traced<'s, D, S>, X4A, X4B>
+{
+    traced("W**", crate::w_star_star(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink), traced("d", d, sink)), sink)
+}
+
+/// C** = Cardinal Twice Removed
+/// 
+/// λabcde.abced
+/// 
+/// [c_star_star](crate::c_star_star), traced as `C**` around `a`, `b`, `c`, `d` and `e`
+pub fn c_star_star<'s, S, A, B, C, D, E, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> Traced<'s, Composition<Composition<Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, C, S>, X2A, X2B>, Traced<'s, E, S>, X3A, X3B>, Traced<'s, D, S>, X4A, X4B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, B, S>, X1A, X1B>,
+    Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>: Compose<Traced<'s, C, S>, X2A, X2B>,
+    Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, C, S>, X2A, X2B>: Compose<Traced<'s, E, S>, X3A, X3B>,
+    Composition<Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, C, S>, X2A, X2B>, Traced<'s, E, S>, X3A, X3B>: Compose<Traced<'s, D, S>, X4A, X4B>
+{
+    traced("C**", crate::c_star_star(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink), traced("d", d, sink), traced("e", e, sink)), sink)
+}
+
+/// R** = Robin Twice Removed
+/// 
+/// λabcde.abdec
+/// 
+/// [r_star_star](crate::r_star_star), traced as `R**` around `a`, `b`, `c`, `d` and `e`
+pub fn r_star_star<'s, S, A, B, C, D, E, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> Traced<'s, Composition<Composition<Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, D, S>, X2A, X2B>, Traced<'s, E, S>, X3A, X3B>, Traced<'s, C, S>, X4A, X4B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, B, S>, X1A, X1B>,
+    Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>: Compose<Traced<'s, D, S>, X2A, X2B>,
+    Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, D, S>, X2A, X2B>: Compose<Traced<'s, E, S>, X3A, X3B>,
+    Composition<Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, D, S>, X2A, X2B>, Traced<'s, E, S>, X3A, X3B>: Compose<Traced<'s, C, S>, X4A, X4B>
+{
+    traced("R**", crate::r_star_star(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink), traced("d", d, sink), traced("e", e, sink)), sink)
+}
+
+/// F** = Finch Twice Removed
+/// 
+/// λabcde.abedc
+/// 
+/// [f_star_star](crate::f_star_star), traced as `F**` around `a`, `b`, `c`, `d` and `e`
+pub fn f_star_star<'s, S, A, B, C, D, E, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> Traced<'s, Composition<Composition<Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, E, S>, X2A, X2B>, Traced<'s, D, S>, X3A, X3B>, Traced<'s, C, S>, X4A, X4B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, B, S>, X1A, X1B>,
+    Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>: Compose<Traced<'s, E, S>, X2A, X2B>,
+    Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, E, S>, X2A, X2B>: Compose<Traced<'s, D, S>, X3A, X3B>,
+    Composition<Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, E, S>, X2A, X2B>, Traced<'s, D, S>, X3A, X3B>: Compose<Traced<'s, C, S>, X4A, X4B>
+{
+    traced("F**", crate::f_star_star(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink), traced("d", d, sink), traced("e", e, sink)), sink)
+}
+
+/// V** = Vireo Twice Removed
+/// 
+/// λabcde.abecd
+/// 
+/// [v_star_star](crate::v_star_star), traced as `V**` around `a`, `b`, `c`, `d` and `e`
+pub fn v_star_star<'s, S, A, B, C, D, E, X1A, X1B, X2A, X2B, X3A, X3B, X4A, X4B>(
+    sink: &'s S,
+    a: A,
+    b: B,
+    c: C,
+    d: D,
+    e: E
+) -> Traced<'s, Composition<Composition<Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, E, S>, X2A, X2B>, Traced<'s, C, S>, X3A, X3B>, Traced<'s, D, S>, X4A, X4B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, B, S>, X1A, X1B>,
+    Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>: Compose<Traced<'s, E, S>, X2A, X2B>,
+    Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, E, S>, X2A, X2B>: Compose<Traced<'s, C, S>, X3A, X3B>,
+    Composition<Composition<Composition<Traced<'s, A, S>, Traced<'s, B, S>, X1A, X1B>, Traced<'s, E, S>, X2A, X2B>, Traced<'s, C, S>, X3A, X3B>: Compose<Traced<'s, D, S>, X4A, X4B>
+{
+    traced("V**", crate::v_star_star(traced("a", a, sink), traced("b", b, sink), traced("c", c, sink), traced("d", d, sink), traced("e", e, sink)), sink)
+}
+
+/// KI = Kite (False)
+/// 
+/// λab.b
+/// 
+/// [ki](crate::ki), traced as `KI` around `a` and `b`
+pub fn ki<'s, S, A, B>(
+    sink: &'s S,
+    a: A,
+    b: B
+) -> Traced<'s, Traced<'s, B, S>, S>
+where
+    S: ?Sized
+{
+    traced("KI", crate::ki(traced("a", a, sink), traced("b", b, sink)), sink)
+}
+
+/// KM = Constant Mocker
+/// 
+/// λab.bb
+/// 
+/// [km](crate::km), traced as `KM` around `a` and `b`
+pub fn km<'s, S, A, B, X1A, X1B>(
+    sink: &'s S,
+    a: A,
+    b: B
+) -> Traced<'s, Composition<Traced<'s, B, S>, Traced<'s, B, S>, X1A, X1B>, S>
+where
+    S: ?Sized,
+    Traced<'s, B, S>: Compose<Traced<'s, B, S>, X1A, X1B> + Copy
+{
+    traced("KM", crate::km(traced("a", a, sink), traced("b", b, sink)), sink)
+}
+
+/// C(KM) = Crossed Constant Mocker
+/// 
+/// λab.aa
+/// 
+/// [ckm](crate::ckm), traced as `C(KM)` around `a` and `b`
+pub fn ckm<'s, S, A, B, X1A, X1B>(
+    sink: &'s S,
+    a: A,
+    b: B
+) -> Traced<'s, Composition<Traced<'s, A, S>, Traced<'s, A, S>, X1A, X1B>, S>
+where
+    S: ?Sized,
+    Traced<'s, A, S>: Compose<Traced<'s, A, S>, X1A, X1B> + Copy
+{
+    traced("C(KM)", crate::ckm(traced("a", a, sink), traced("b", b, sink)), sink)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::named::{named, Named};
+
+    use super::*;
+
+    fn log(sink: &RefCell<Vec<Event>>) -> String
+    {
+        sink.take()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_traced()
+    {
+        let sink = RefCell::new(vec![]);
+
+        let inc = traced("inc", |x: u8| x + 1, &sink);
+        let twice = traced("twice", |x: u8| inc(inc(x)), &sink);
+
+        assert_eq!(twice(1), 3);
+        assert_eq!(sink.borrow()[1], Event::Call {name: "inc", depth: 1, args: Some("(1)".to_string())});
+        assert_eq!(log(&sink), "\
+twice(1)
+    inc(1)
+    inc = 2
+    inc(2)
+    inc = 3
+twice = 3");
+
+        // a closure is not Debug, but it can be named
+        let add = traced("add", |x: u8| named("add x", move |y: u8| x + y), &sink);
+        let apply = traced("apply", |f: Named<_>, x: u8| f(x), &sink);
+
+        assert_eq!(apply(add(1), 2), 3);
+        assert_eq!(log(&sink), "\
+add(1)
+add = add x
+apply(add x, 2)
+apply = 3");
+    }
+
+    #[test]
+    fn test_sink()
+    {
+        let count = Cell::new(0);
+        let sink = |event: Event| if let Event::Return {..} = event
+        {
+            count.set(count.get() + 1)
+        };
+
+        let inc = traced("inc", |x: u8| x + 1, &sink);
+
+        for i in 0..=254
+        {
+            assert_eq!(inc(i), i + 1)
+        }
+        assert_eq!(count.get(), 255);
+    }
+
+    #[test]
+    fn test_birds()
+    {
+        let sink = RefCell::new(vec![]);
+
+        let a = |x: u8, y: u8| x + y;
+        let b = |x: u8| x*2;
+        let c = |x: u8| x + 1;
+        let d = |x: u8| x*3;
+        let e = |x: u8| x + 2;
+
+        let f = d2(&sink, a, b, c, d, e);
+
+        assert_eq!(f(1, 2), crate::d2(a, b, c, d, e)(1, 2));
+        assert_eq!(log(&sink), "\
+D²(1, 2)
+    e(2)
+    e = 4
+    d(4)
+    d = 12
+    c(1)
+    c = 2
+    b(2)
+    b = 4
+    a(4, 12)
+    a = 16
+D² = 16");
+
+        // the traced birds are traced functions themselves
+        let f = w(&sink, |x: u8, y: u8| x*y, |x: u8| x + 1);
+
+        assert_eq!(f(2, 3), 12);
+        assert_eq!(sink.take().len(), 8);
+    }
+
+    #[test]
+    fn test_opaque()
+    {
+        /// A value which isn't Debug
+        struct Secret(u8);
+
+        let sink = Opaque(RefCell::new(vec![]));
+
+        let a = |x: u8, y: Secret| x + y.0;
+        let b = |x: u8| Secret(x*2);
+        let c = |x: u8| x + 1;
+
+        let f = s(&sink, a, b, c);
+
+        assert_eq!(f(1, 2), 8);
+        assert_eq!(sink.0.borrow()[0], Event::Call {name: "S", depth: 0, args: None});
+        assert_eq!(log(&sink.0), "\
+S(<opaque>)
+    c(<opaque>)
+    c = <opaque>
+    b(<opaque>)
+    b = <opaque>
+    c(<opaque>)
+    c = <opaque>
+    a(<opaque>)
+    a = <opaque>
+S = <opaque>");
+    }
+}